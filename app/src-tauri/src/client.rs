@@ -1,6 +1,9 @@
+use arrow::json::ArrayWriter;
 use futures::TryStreamExt;
 use prost::Message;
+use serde_json::{Map, Value};
 use tauri::State;
+use unitycatalog_common::api::query::PreviewTableRequest;
 use unitycatalog_common::models::catalogs::v1::{
     CatalogInfo, CreateCatalogRequest, UpdateCatalogRequest,
 };
@@ -345,3 +348,22 @@ pub async fn create_table(
 pub async fn delete_table(state: State<'_, UnityCatalogClient>, full_name: String) -> Result<()> {
     Ok(state.tables().delete(full_name).await?)
 }
+
+#[tauri::command]
+pub async fn preview_table(
+    state: State<'_, UnityCatalogClient>,
+    full_name: String,
+    limit: Option<i32>,
+    columns: Option<Vec<String>>,
+) -> Result<Vec<Map<String, Value>>> {
+    let request = PreviewTableRequest {
+        full_name,
+        limit,
+        columns: columns.unwrap_or_default(),
+    };
+    let batch = state.query().preview_table(&request).await?;
+    let mut writer = ArrayWriter::new(Vec::new());
+    writer.write(&batch)?;
+    writer.finish()?;
+    Ok(serde_json::from_slice(&writer.into_inner())?)
+}
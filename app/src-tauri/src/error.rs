@@ -10,6 +10,12 @@ pub enum Error {
 
     #[error(transparent)]
     Decode(#[from] prost::DecodeError),
+
+    #[error(transparent)]
+    Arrow(#[from] arrow::error::ArrowError),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
 }
 
 // we must manually implement serde::Serialize
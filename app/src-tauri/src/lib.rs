@@ -50,6 +50,7 @@ pub fn run() {
             client::get_table,
             client::create_table,
             client::delete_table,
+            client::preview_table,
         ])
         .setup(|app| {
             app.manage(unity_client);
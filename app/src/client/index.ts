@@ -287,6 +287,26 @@ export async function deleteTable({
   return await tauri.delete_table(catalog, schema, name);
 }
 
+export type PreviewTableOptions = {
+  catalog: string;
+  schema: string;
+  name: string;
+  // The maximum number of rows to return
+  limit?: number;
+  // The columns to include, all columns if not set
+  columns?: string[];
+};
+
+export async function previewTable({
+  catalog,
+  schema,
+  name,
+  limit,
+  columns,
+}: PreviewTableOptions) {
+  return await tauri.preview_table(catalog, schema, name, limit, columns);
+}
+
 export default {
   catalogs: {
     list: listCatalogs,
@@ -336,5 +356,6 @@ export default {
     create: createTable,
     get: getTable,
     delete: deleteTable,
+    preview: previewTable,
   },
 };
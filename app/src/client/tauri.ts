@@ -238,6 +238,20 @@ export async function delete_table(
   });
 }
 
+export async function preview_table(
+  catalog: string,
+  schema: string,
+  name: string,
+  limit?: number,
+  columns?: string[],
+) {
+  return await invoke<Record<string, unknown>[]>("preview_table", {
+    fullName: `${catalog}.${schema}.${name}`,
+    limit,
+    columns,
+  });
+}
+
 export default {
   list_catalogs,
   create_catalog,
@@ -274,4 +288,5 @@ export default {
   create_table,
   get_table,
   delete_table,
+  preview_table,
 };
@@ -1,14 +1,17 @@
 use std::collections::HashMap;
 
+use arrow::pyarrow::PyArrowType;
+use arrow::record_batch::RecordBatch;
 use futures::stream::TryStreamExt;
 use pyo3::prelude::*;
+use unitycatalog_common::api::query::PreviewTableRequest;
 use unitycatalog_common::models::catalogs::v1::{
     CatalogInfo, CreateCatalogRequest, UpdateCatalogRequest,
 };
 use unitycatalog_common::models::credentials::v1::{
     create_credential_request::Credential,
     update_credential_request::Credential as UpdateCredential, AzureManagedIdentity,
-    AzureServicePrincipal, AzureStorageKey, CreateCredentialRequest, CredentialInfo,
+    AzureServicePrincipal, AzureStorageKey, CreateCredentialRequest, CredentialInfo, NoCredential,
     Purpose as CredentialPurpose, UpdateCredentialRequest,
 };
 use unitycatalog_common::models::external_locations::v1::{
@@ -215,7 +218,8 @@ impl PyCredentialsClient {
         skip_validation = false,
         azure_service_principal = None,
         azure_managed_identity = None,
-        azure_storage_key = None
+        azure_storage_key = None,
+        no_credential = false
     ))]
     pub fn create(
         &self,
@@ -227,6 +231,7 @@ impl PyCredentialsClient {
         azure_service_principal: Option<AzureServicePrincipal>,
         azure_managed_identity: Option<AzureManagedIdentity>,
         azure_storage_key: Option<AzureStorageKey>,
+        no_credential: bool,
     ) -> PyUnityCatalogResult<CredentialInfo> {
         let credential = if azure_service_principal.is_some() {
             Credential::AzureServicePrincipal(azure_service_principal.unwrap())
@@ -234,9 +239,11 @@ impl PyCredentialsClient {
             Credential::AzureManagedIdentity(azure_managed_identity.unwrap())
         } else if azure_storage_key.is_some() {
             Credential::AzureStorageKey(azure_storage_key.unwrap())
+        } else if no_credential {
+            Credential::NoCredential(NoCredential {})
         } else {
             return Err(unitycatalog_common::error::Error::invalid_argument(
-                "One of azure_service_principal, azure_managed_identity, or azure_storage_key must be provided"
+                "One of azure_service_principal, azure_managed_identity, azure_storage_key, or no_credential must be provided"
             ).into());
         };
         let request = CreateCredentialRequest {
@@ -264,7 +271,8 @@ impl PyCredentialsClient {
         force = None,
         azure_service_principal = None,
         azure_managed_identity = None,
-        azure_storage_key = None
+        azure_storage_key = None,
+        no_credential = false
     ))]
     pub fn update(
         &self,
@@ -278,6 +286,7 @@ impl PyCredentialsClient {
         azure_service_principal: Option<AzureServicePrincipal>,
         azure_managed_identity: Option<AzureManagedIdentity>,
         azure_storage_key: Option<AzureStorageKey>,
+        no_credential: bool,
     ) -> PyUnityCatalogResult<CredentialInfo> {
         let credential = if azure_service_principal.is_some() {
             Some(UpdateCredential::AzureServicePrincipal(
@@ -291,6 +300,8 @@ impl PyCredentialsClient {
             Some(UpdateCredential::AzureStorageKey(
                 azure_storage_key.unwrap(),
             ))
+        } else if no_credential {
+            Some(UpdateCredential::NoCredential(NoCredential {}))
         } else {
             None
         };
@@ -455,11 +466,12 @@ impl PySchemasClient {
         })
     }
 
-    #[pyo3(signature = (*, comment = None, properties = None))]
+    #[pyo3(signature = (*, comment = None, storage_root = None, properties = None))]
     pub fn create(
         &self,
         py: Python,
         comment: Option<String>,
+        storage_root: Option<String>,
         properties: Option<HashMap<String, String>>,
     ) -> PyUnityCatalogResult<SchemaInfo> {
         let request = CreateSchemaRequest {
@@ -467,6 +479,7 @@ impl PySchemasClient {
             catalog_name: self.catalog_name.clone(),
             comment,
             properties: properties.map(hash_map_to_struct),
+            storage_root,
         };
         let runtime = get_runtime(py)?;
         py.allow_threads(|| {
@@ -604,8 +617,10 @@ impl PyTablesClient {
         storage_location = None,
         comment = None,
         columns = None,
-        properties = None
+        properties = None,
+        view_definition = None
     ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         &self,
         py: Python,
@@ -615,6 +630,7 @@ impl PyTablesClient {
         comment: Option<String>,
         columns: Option<Vec<ColumnInfo>>,
         properties: Option<HashMap<String, String>>,
+        view_definition: Option<String>,
     ) -> PyUnityCatalogResult<TableInfo> {
         let request = CreateTableRequest {
             name: self.name.clone(),
@@ -626,6 +642,7 @@ impl PyTablesClient {
             storage_location,
             comment,
             properties: properties.map(hash_map_to_struct),
+            view_definition,
         };
         let runtime = get_runtime(py)?;
         py.allow_threads(|| {
@@ -643,6 +660,25 @@ impl PyTablesClient {
         })
     }
 
+    #[pyo3(signature = (limit = None, columns = None))]
+    pub fn preview(
+        &self,
+        py: Python,
+        limit: Option<i32>,
+        columns: Option<Vec<String>>,
+    ) -> PyUnityCatalogResult<PyArrowType<RecordBatch>> {
+        let request = PreviewTableRequest {
+            full_name: format!("{}.{}.{}", &self.catalog_name, self.schema_name, self.name),
+            limit,
+            columns: columns.unwrap_or_default(),
+        };
+        let runtime = get_runtime(py)?;
+        py.allow_threads(|| {
+            let batch = runtime.block_on(self.client.query().preview_table(&request))?;
+            Ok::<_, PyUnityCatalogError>(PyArrowType(batch))
+        })
+    }
+
     // pub fn exists(&self, py: Python) -> PyUnityCatalogResult<bool> {
     //     let runtime = get_runtime(py)?;
     //     py.allow_threads(|| {
@@ -14,7 +14,8 @@ use unitycatalog_common::models::shares::v1::{
     Action, DataObject, DataObjectType, DataObjectUpdate, HistoryStatus, ShareInfo,
 };
 use unitycatalog_common::models::tables::v1::{
-    ColumnInfo, ColumnTypeName, DataSourceFormat, TableInfo, TableType,
+    ColumnInfo, ColumnTypeName, DataSourceFormat, DeltaTableMetadata, Dependency, DependencyList,
+    IcebergPartitionField, IcebergTableMetadata, TableDependency, TableInfo, TableType,
 };
 
 mod client;
@@ -46,6 +47,12 @@ fn unitycatalog_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ColumnInfo>()?;
     m.add_class::<ColumnTypeName>()?;
     m.add_class::<DataSourceFormat>()?;
+    m.add_class::<DeltaTableMetadata>()?;
+    m.add_class::<IcebergTableMetadata>()?;
+    m.add_class::<IcebergPartitionField>()?;
+    m.add_class::<DependencyList>()?;
+    m.add_class::<Dependency>()?;
+    m.add_class::<TableDependency>()?;
 
     // service clients
     m.add_class::<PyCatalogClient>()?;
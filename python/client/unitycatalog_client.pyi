@@ -1,6 +1,8 @@
 import enum
 from typing import Literal
 
+import pyarrow
+
 class CatalogInfo:
     id: str | None
     name: str
@@ -50,6 +52,8 @@ class SchemaInfo:
     updated_at: int | None
     updated_by: str | None
     schema_id: str | None
+    storage_root: str | None
+    """Storage root URL for managed tables within the schema."""
 
 class ColumnTypeName(enum.Enum):
     Unspecified = 0
@@ -94,6 +98,7 @@ class TableType(enum.Enum):
     Unspecified = 0
     Managed = 1
     External = 2
+    View = 3
 
 class ColumnInfo:
     name: str
@@ -121,6 +126,8 @@ class TableInfo:
     data_source_format: DataSourceFormat
     columns: list[ColumnInfo]
     storage_location: str | None
+    view_definition: str | None
+    view_dependencies: DependencyList | None
     owner: str | None
     comment: str | None
     properties: dict | None
@@ -132,6 +139,43 @@ class TableInfo:
     updated_by: str | None
     deleted_at: int | None
     table_id: str | None
+    delta_metadata: DeltaTableMetadata | None
+    iceberg_metadata: IcebergTableMetadata | None
+
+class DeltaTableMetadata:
+    version: int
+    min_reader_version: int
+    min_writer_version: int
+    reader_features: list[str]
+    writer_features: list[str]
+    properties: dict[str, str]
+    partition_columns: list[str]
+    num_files: int
+    size_in_bytes: int
+
+class DependencyList:
+    dependencies: list[Dependency]
+
+class Dependency:
+    table: TableDependency | None
+
+class TableDependency:
+    table_full_name: str
+
+class IcebergTableMetadata:
+    metadata_location: str
+    format_version: int
+    table_uuid: str
+    current_snapshot_id: int | None
+    current_schema_id: int
+    default_spec_id: int
+    partition_fields: list[IcebergPartitionField]
+    properties: dict[str, str]
+
+class IcebergPartitionField:
+    name: str
+    source_column: str
+    transform: str
 
 class Purpose(enum.Enum):
     Unspecified = 0
@@ -280,14 +324,23 @@ class TableClient:
         storage_location: str | None = None,
         columns: list[ColumnInfo] | None = None,
         properties: dict[str, str] | None = None,
+        view_definition: str | None = None,
     ) -> TableInfo: ...
+    def preview(
+        self, limit: int | None = None, columns: list[str] | None = None
+    ) -> pyarrow.RecordBatch:
+        """Read the first rows of a delta table.
+
+        Requires `pyarrow` to be installed.
+        """
 
 class SchemaClient:
     def tables(self, name: str) -> TableClient: ...
     def get(self) -> SchemaInfo: ...
     def create(
         self,
-        name: str,
+        comment: str | None = None,
+        storage_root: str | None = None,
         properties: dict[str, str] | None = None,
     ) -> SchemaInfo: ...
     def update(
@@ -329,6 +382,7 @@ class CredentialsClient:
         azure_service_principal: AzureServicePrincipal | None = None,
         azure_managed_identity: AzureManagedIdentity | None = None,
         azure_storage_key: AzureStorageKey | None = None,
+        no_credential: bool = False,
     ) -> CredentialInfo: ...
     def update(
         self,
@@ -341,6 +395,7 @@ class CredentialsClient:
         azure_service_principal: AzureServicePrincipal | None = None,
         azure_managed_identity: AzureManagedIdentity | None = None,
         azure_storage_key: AzureStorageKey | None = None,
+        no_credential: bool = False,
     ) -> CredentialInfo: ...
 
 class ExternalLocationsClient:
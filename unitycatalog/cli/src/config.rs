@@ -1,4 +1,10 @@
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
+use unitycatalog_common::services::{SecretRetention, VaultSecretManager};
+use unitycatalog_postgres::{EncryptionKey, KeyRing};
+
+use crate::error::{Error, Result};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct EnvValue {
@@ -35,6 +41,18 @@ pub struct Config {
 
     #[serde(default)]
     pub secret_backend: Option<SecretBackend>,
+
+    /// Retention policy for secret versions.
+    #[serde(default)]
+    pub secret_retention: Option<SecretRetentionConfig>,
+
+    /// Retention policy for dropped managed tables.
+    #[serde(default)]
+    pub table_retention: Option<TableRetentionConfig>,
+
+    /// Periodic refresh of the stored metadata of delta tables.
+    #[serde(default)]
+    pub table_sync: Option<TableSyncConfig>,
 }
 
 /// Backend configuration for the unity catalog server.
@@ -98,6 +116,11 @@ pub enum SecretBackend {
     /// This is generally only recommended for evaluation purposes.
     /// For production use, it is recommended to use a dedicated secret store.
     Postgres(PostgresSecretConfig),
+
+    /// HashiCorp Vault secret backend.
+    ///
+    /// Secrets are stored in a KV version 2 secrets engine.
+    Vault(VaultConfig),
 }
 
 /// Azure Key Vault secret backend configuration.
@@ -119,9 +142,236 @@ pub struct AzureKeyValut {
     pub federated_token_file: Option<ConfigValue>,
 }
 
+/// HashiCorp Vault secret backend configuration.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VaultConfig {
+    /// The address of the Vault server, e.g. `https://vault.example.com:8200`.
+    pub address: ConfigValue,
+
+    /// The token used to authenticate with Vault.
+    pub token: ConfigValue,
+
+    /// The mount path of the KV secrets engine.
+    ///
+    /// Defaults to `secret`.
+    #[serde(default)]
+    pub mount: Option<ConfigValue>,
+
+    /// The path below which all secrets are stored.
+    #[serde(default)]
+    pub path_prefix: Option<ConfigValue>,
+
+    /// The Vault namespace.
+    #[serde(default)]
+    pub namespace: Option<ConfigValue>,
+}
+
+impl VaultConfig {
+    /// Create a secret manager for the configured Vault server.
+    pub fn secret_manager(&self) -> Result<VaultSecretManager> {
+        let address = self
+            .address
+            .value()
+            .ok_or_else(|| Error::Generic("missing vault address".to_string()))?;
+        let address = address
+            .parse()
+            .map_err(|e| Error::Generic(format!("invalid vault address: {e}")))?;
+        let token = self
+            .token
+            .value()
+            .ok_or_else(|| Error::Generic("missing vault token".to_string()))?;
+        let mut manager = VaultSecretManager::new(address, token);
+        if let Some(mount) = self.mount.as_ref().and_then(|v| v.value()) {
+            manager = manager.with_mount(mount);
+        }
+        if let Some(prefix) = self.path_prefix.as_ref().and_then(|v| v.value()) {
+            manager = manager.with_prefix(prefix);
+        }
+        if let Some(namespace) = self.namespace.as_ref().and_then(|v| v.value()) {
+            manager = manager.with_namespace(namespace);
+        }
+        Ok(manager)
+    }
+}
+
+/// Postgres secret backend configuration.
+///
+/// Secrets are protected with envelope encryption. The configured key is
+/// the key-encryption key used to protect the per-secret data keys.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PostgresSecretConfig {
-    pub encryption_key: ConfigValue,
+    /// The current key-encryption key.
+    #[serde(flatten)]
+    pub key: EncryptionKeyConfig,
+
+    /// Previous key-encryption keys that may still be referenced by stored secrets.
+    ///
+    /// Previous keys are required until all secrets have been re-keyed.
+    #[serde(default)]
+    pub previous_keys: Vec<EncryptionKeyConfig>,
+}
+
+/// A base64 encoded 256-bit encryption key.
+///
+/// The key is either provided directly or read from a file.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EncryptionKeyConfig {
+    /// Identifier of the key, stored with every secret encrypted by it.
+    ///
+    /// Defaults to `default`, the key id used when the key is set via `UC_SECRETS_KEY`.
+    #[serde(default = "default_key_id")]
+    pub key_id: ConfigValue,
+
+    /// The base64 encoded key.
+    #[serde(default)]
+    pub encryption_key: Option<ConfigValue>,
+
+    /// Path to a file containing the base64 encoded key.
+    #[serde(default)]
+    pub encryption_key_file: Option<ConfigValue>,
+}
+
+fn default_key_id() -> ConfigValue {
+    ConfigValue::Value("default".to_string())
+}
+
+impl EncryptionKeyConfig {
+    /// Resolve the configured encryption key.
+    pub fn load(&self) -> Result<EncryptionKey> {
+        let key_id = self
+            .key_id
+            .value()
+            .ok_or_else(|| Error::Generic("missing encryption key id".to_string()))?;
+        match (
+            self.encryption_key.as_ref().and_then(|v| v.value()),
+            self.encryption_key_file.as_ref().and_then(|v| v.value()),
+        ) {
+            (Some(key), _) => Ok(EncryptionKey::from_base64(key_id, &key)?),
+            (None, Some(path)) => Ok(EncryptionKey::from_file(key_id, path)?),
+            (None, None) => Err(Error::Generic(format!(
+                "no value configured for encryption key '{key_id}'"
+            ))),
+        }
+    }
+}
+
+impl PostgresSecretConfig {
+    /// Resolve all configured encryption keys.
+    pub fn key_ring(&self) -> Result<KeyRing> {
+        self.previous_keys
+            .iter()
+            .try_fold(KeyRing::new(self.key.load()?), |keys, key| {
+                Ok(keys.with_previous(key.load()?))
+            })
+    }
+}
+
+/// Retention policy for secret versions.
+///
+/// The current version of a secret is always retained.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SecretRetentionConfig {
+    /// The number of versions to keep per secret, including the current version.
+    #[serde(default)]
+    pub keep_last: Option<usize>,
+
+    /// The maximum age of previous versions, e.g. `30d`.
+    #[serde(default)]
+    pub max_age: Option<String>,
+
+    /// The interval at which the server deletes expired versions, e.g. `1h`.
+    ///
+    /// If not set, expired versions are only deleted by the `prune-secrets` command.
+    #[serde(default)]
+    pub cleanup_interval: Option<String>,
+}
+
+impl SecretRetentionConfig {
+    /// Get the configured retention policy.
+    pub fn policy(&self) -> Result<SecretRetention> {
+        let mut policy = SecretRetention::new();
+        if let Some(keep_last) = self.keep_last {
+            policy = policy.with_keep_last(keep_last);
+        }
+        if let Some(max_age) = &self.max_age {
+            policy = policy.with_max_age(parse_max_age(max_age)?);
+        }
+        Ok(policy)
+    }
+
+    /// Get the configured cleanup interval.
+    pub fn cleanup_interval(&self) -> Result<Option<std::time::Duration>> {
+        self.cleanup_interval
+            .as_deref()
+            .map(|interval| {
+                humantime::parse_duration(interval)
+                    .map_err(|e| Error::Generic(format!("invalid cleanup interval: {e}")))
+            })
+            .transpose()
+    }
+}
+
+/// Retention policy for dropped managed tables.
+///
+/// Dropped managed tables can be restored until their data is purged.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TableRetentionConfig {
+    /// How long dropped tables are retained before their data is deleted, e.g. `7d`.
+    pub retention: String,
+
+    /// The interval at which the server purges expired tables, e.g. `1h`.
+    #[serde(default)]
+    pub cleanup_interval: Option<String>,
+}
+
+impl TableRetentionConfig {
+    /// Get the configured retention period.
+    pub fn retention(&self) -> Result<chrono::Duration> {
+        parse_max_age(&self.retention)
+    }
+
+    /// Get the configured cleanup interval, defaulting to one hour.
+    pub fn cleanup_interval(&self) -> Result<std::time::Duration> {
+        match self.cleanup_interval.as_deref() {
+            Some(interval) => humantime::parse_duration(interval)
+                .map_err(|e| Error::Generic(format!("invalid cleanup interval: {e}"))),
+            None => Ok(std::time::Duration::from_secs(3600)),
+        }
+    }
+}
+
+/// Periodic refresh of the columns and properties of delta tables from their delta logs.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TableSyncConfig {
+    /// The interval at which the server refreshes table metadata, e.g. `10m`.
+    #[serde(default)]
+    pub interval: Option<String>,
+}
+
+impl TableSyncConfig {
+    /// Get the configured sync interval, defaulting to one hour.
+    pub fn interval(&self) -> Result<std::time::Duration> {
+        match self.interval.as_deref() {
+            Some(interval) => humantime::parse_duration(interval)
+                .map_err(|e| Error::Generic(format!("invalid sync interval: {e}"))),
+            None => Ok(std::time::Duration::from_secs(3600)),
+        }
+    }
+}
+
+/// Parse a human readable duration, e.g. `30d`, as a maximum age.
+pub fn parse_max_age(max_age: &str) -> Result<chrono::Duration> {
+    let max_age = humantime::parse_duration(max_age)
+        .map_err(|e| Error::Generic(format!("invalid max age: {e}")))?;
+    chrono::Duration::from_std(max_age).map_err(|e| Error::Generic(format!("invalid max age: {e}")))
+}
+
+impl Config {
+    /// Load the configuration from a yaml file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        serde_yml::from_str(&content).map_err(|e| Error::Generic(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -162,4 +412,110 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_deserialize_secret_backend() {
+        let config = r#"
+            {
+                "engine": "Postgres",
+                "key_id": "2025-q3",
+                "encryption_key": { "env": "UC_SECRETS_KEY" },
+                "previous_keys": [
+                    {
+                        "key_id": "2025-q2",
+                        "encryption_key_file": "/etc/uc/kek-2025-q2"
+                    }
+                ]
+            }
+        "#;
+
+        let backend: SecretBackend = serde_json::from_str(config).unwrap();
+        let SecretBackend::Postgres(backend) = backend else {
+            panic!("expected postgres secret backend");
+        };
+        assert_eq!(backend.key.key_id.value().unwrap(), "2025-q3");
+        assert_eq!(backend.previous_keys.len(), 1);
+        assert!(backend.previous_keys[0].encryption_key.is_none());
+
+        let config = r#"{ "engine": "Postgres", "encryption_key": "a2V5" }"#;
+        let backend: SecretBackend = serde_json::from_str(config).unwrap();
+        let SecretBackend::Postgres(backend) = backend else {
+            panic!("expected postgres secret backend");
+        };
+        assert_eq!(backend.key.key_id.value().unwrap(), "default");
+    }
+
+    #[test]
+    fn test_secret_retention_policy() {
+        let config = r#"
+            {
+                "keep_last": 5,
+                "max_age": "30d",
+                "cleanup_interval": "1h"
+            }
+        "#;
+
+        let retention: SecretRetentionConfig = serde_json::from_str(config).unwrap();
+        assert_eq!(
+            retention.policy().unwrap(),
+            SecretRetention::new()
+                .with_keep_last(5)
+                .with_max_age(chrono::Duration::days(30))
+        );
+        assert_eq!(
+            retention.cleanup_interval().unwrap(),
+            Some(std::time::Duration::from_secs(3600))
+        );
+    }
+
+    #[test]
+    fn test_table_retention() {
+        let config = r#"
+            {
+                "retention": "7d"
+            }
+        "#;
+
+        let retention: TableRetentionConfig = serde_json::from_str(config).unwrap();
+        assert_eq!(retention.retention().unwrap(), chrono::Duration::days(7));
+        assert_eq!(
+            retention.cleanup_interval().unwrap(),
+            std::time::Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn test_table_sync() {
+        let sync: TableSyncConfig = serde_json::from_str(r#"{ "interval": "10m" }"#).unwrap();
+        assert_eq!(
+            sync.interval().unwrap(),
+            std::time::Duration::from_secs(600)
+        );
+        let sync: TableSyncConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            sync.interval().unwrap(),
+            std::time::Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_vault_backend() {
+        let config = r#"
+            {
+                "engine": "Vault",
+                "address": "http://127.0.0.1:8200",
+                "token": { "env": "VAULT_TOKEN" },
+                "mount": "kv",
+                "path_prefix": "unitycatalog"
+            }
+        "#;
+
+        let backend: SecretBackend = serde_json::from_str(config).unwrap();
+        let SecretBackend::Vault(backend) = backend else {
+            panic!("expected vault secret backend");
+        };
+        assert_eq!(backend.address.value().unwrap(), "http://127.0.0.1:8200");
+        assert_eq!(backend.mount.unwrap().value().unwrap(), "kv");
+        assert!(backend.namespace.is_none());
+    }
 }
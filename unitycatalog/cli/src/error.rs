@@ -12,6 +12,9 @@ pub enum Error {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    #[error("Postgres error: {0}")]
+    Postgres(#[from] unitycatalog_postgres::Error),
+
     #[error("Generic error: {0}")]
     Generic(String),
 }
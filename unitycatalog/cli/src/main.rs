@@ -2,7 +2,9 @@ use clap::{Args, Parser, Subcommand};
 
 use crate::client::{ClientCommand, handle_client};
 use crate::error::Result;
-use crate::server::{ServerArgs, handle_server};
+use crate::server::{
+    PruneSecretsArgs, RekeyArgs, ServerArgs, handle_prune_secrets, handle_rekey, handle_server,
+};
 
 mod client;
 mod config;
@@ -44,6 +46,12 @@ enum Commands {
 
     #[clap(about = "run database migrations")]
     Migrate,
+
+    #[clap(about = "re-encrypt stored secrets with the current encryption key")]
+    Rekey(RekeyArgs),
+
+    #[clap(about = "delete expired secret versions")]
+    PruneSecrets(PruneSecretsArgs),
 }
 
 #[derive(Parser)]
@@ -61,6 +69,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             handle_client(client_args, args.global_opts).await?;
         }
         Commands::Migrate => todo!(),
+        Commands::Rekey(rekey_args) => handle_rekey(rekey_args).await?,
+        Commands::PruneSecrets(prune_args) => handle_prune_secrets(prune_args).await?,
     };
     Ok(())
 }
@@ -1,11 +1,18 @@
+use std::path::PathBuf;
 use std::sync::{Arc, LazyLock};
 
 use clap::Parser;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
-use unitycatalog_common::services::{ConstantPolicy, ServerHandler};
+use unitycatalog_common::api::tables::{purge_dropped_tables, sync_delta_tables};
+use unitycatalog_common::services::system::find_shadowed_catalog;
+use unitycatalog_common::services::{
+    ConstantPolicy, LocalStorageConfig, SecretManager, SecretRetention, ServerHandler,
+    prune_credential_secrets,
+};
 use unitycatalog_common::{memory::InMemoryResourceStore, rest::AnonymousAuthenticator};
-use unitycatalog_postgres::GraphStore;
+use unitycatalog_postgres::{EncryptionKey, GraphStore, KeyRing};
 
+use crate::config::{Config, SecretBackend, parse_max_age};
 use crate::error::{Error, Result};
 
 mod run;
@@ -27,72 +34,297 @@ pub struct ServerArgs {
     #[clap(long, help = "expose rest API", default_value_t = true)]
     rest: bool,
 
-    #[clap(long, help = "expose rest gRPC", default_value_t = false)]
+    #[clap(long, help = "expose Flight SQL gRPC API", default_value_t = false)]
     grpc: bool,
+
+    #[clap(
+        long,
+        help = "port of the Flight SQL gRPC API",
+        default_value_t = 50051
+    )]
+    grpc_port: u16,
+
+    #[clap(
+        long = "allow-local-path",
+        help = "base directory that may be used by file:// external locations (repeatable)"
+    )]
+    allowed_local_paths: Vec<PathBuf>,
 }
 
+/// Handle the server command.
+///
+/// This function starts a delta-sharing server using the REST protocol and, if requested,
+/// an Arrow Flight SQL server over the tables in the catalog on a separate port.
 pub async fn handle_server(args: &ServerArgs) -> Result<()> {
-    if args.rest {
-        handle_rest(args).await
-    } else if args.grpc {
-        handle_grpc(args).await
-    } else {
-        Err(Error::Generic("No server protocol specified".to_string()))
+    if !args.rest && !args.grpc {
+        return Err(Error::Generic("No server protocol specified".to_string()));
     }
-}
 
-/// Handle the rest server command.
-///
-/// This function starts a delta-sharing server using the REST protocol.
-async fn handle_rest(args: &ServerArgs) -> Result<()> {
     init_tracing();
 
     println!("{}", WELCOME.as_str());
 
-    if args.use_db {
-        let handler = get_db_handler().await?;
+    let handler = if args.use_db {
+        get_db_handler(&args.config)
+            .await?
+            .with_local_storage(local_storage_config(args))
+    } else {
+        get_memory_handler(args).await?
+    };
+    spawn_secret_cleanup(&args.config, handler.clone())?;
+    spawn_table_cleanup(&args.config, handler.clone())?;
+    spawn_table_sync(&args.config, handler.clone())?;
+    if let Some(catalog) = find_shadowed_catalog(&handler).await? {
+        tracing::warn!(
+            "stored catalog '{}' is hidden by the built-in system catalog, rename it in the store to access its contents",
+            catalog.name
+        );
+    }
+
+    let rest = async {
+        if !args.rest {
+            return Ok(());
+        }
         run::run_server_rest(
             args.host.clone(),
             args.port,
-            handler,
+            handler.clone(),
             AnonymousAuthenticator,
         )
         .await
-        .map_err(|_| Error::Generic("Server failed".to_string()))
-    } else {
-        let handler = get_memory_handler().await?;
-        run::run_server_rest(
+    };
+    let grpc = async {
+        if !args.grpc {
+            return Ok(());
+        }
+        run::run_server_grpc(
             args.host.clone(),
-            args.port,
-            handler,
+            args.grpc_port,
+            handler.clone(),
             AnonymousAuthenticator,
         )
         .await
-        .map_err(|_| Error::Generic("Server failed".to_string()))
+    };
+    tokio::try_join!(rest, grpc).map_err(|_| Error::Generic("Server failed".to_string()))?;
+    Ok(())
+}
+
+/// Periodically delete expired secret versions, if a cleanup interval is configured.
+fn spawn_secret_cleanup(config: &str, handler: ServerHandler) -> Result<()> {
+    let Some(retention) = load_config(config)?.and_then(|config| config.secret_retention) else {
+        return Ok(());
+    };
+    let Some(interval) = retention.cleanup_interval()? else {
+        return Ok(());
+    };
+    let policy = retention.policy()?;
+    if policy.is_unbounded() {
+        return Ok(());
     }
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match prune_credential_secrets(&handler, &policy).await {
+                Ok(count) => tracing::info!("deleted {count} expired secret versions"),
+                Err(e) => tracing::error!("failed to delete expired secret versions: {e}"),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Periodically purge dropped managed tables, if a table retention is configured.
+fn spawn_table_cleanup(config: &str, handler: ServerHandler) -> Result<()> {
+    let Some(retention) = load_config(config)?.and_then(|config| config.table_retention) else {
+        return Ok(());
+    };
+    let interval = retention.cleanup_interval()?;
+    let retention = retention.retention()?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match purge_dropped_tables(&handler, retention).await {
+                Ok(count) => tracing::info!("purged {count} dropped tables"),
+                Err(e) => tracing::error!("failed to purge dropped tables: {e}"),
+            }
+        }
+    });
+    Ok(())
 }
 
-async fn handle_grpc(_args: &ServerArgs) -> Result<()> {
-    unimplemented!()
+/// Periodically refresh the stored metadata of delta tables, if a table sync is configured.
+fn spawn_table_sync(config: &str, handler: ServerHandler) -> Result<()> {
+    let Some(sync) = load_config(config)?.and_then(|config| config.table_sync) else {
+        return Ok(());
+    };
+    let interval = sync.interval()?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match sync_delta_tables(&handler).await {
+                Ok(count) => tracing::info!("synced metadata of {count} tables"),
+                Err(e) => tracing::error!("failed to sync table metadata: {e}"),
+            }
+        }
+    });
+    Ok(())
 }
 
-async fn get_db_handler() -> Result<ServerHandler> {
+/// Arguments for deleting expired secret versions.
+#[derive(Debug, Parser)]
+pub struct PruneSecretsArgs {
+    #[arg(short, long, default_value = "config.yaml")]
+    config: String,
+
+    #[clap(long, help = "number of versions to keep per secret")]
+    keep_last: Option<usize>,
+
+    #[clap(long, help = "maximum age of previous versions, e.g. 30d")]
+    max_age: Option<String>,
+}
+
+/// Delete expired versions of all credential secrets.
+///
+/// The retention policy is read from the configuration file and may be overridden by
+/// command line arguments.
+pub async fn handle_prune_secrets(args: &PruneSecretsArgs) -> Result<()> {
+    let mut policy = match load_config(&args.config)?.and_then(|c| c.secret_retention) {
+        Some(retention) => retention.policy()?,
+        None => SecretRetention::new(),
+    };
+    if let Some(keep_last) = args.keep_last {
+        policy = policy.with_keep_last(keep_last);
+    }
+    if let Some(max_age) = &args.max_age {
+        policy = policy.with_max_age(parse_max_age(max_age)?);
+    }
+    if policy.is_unbounded() {
+        return Err(Error::Generic(
+            "no secret retention policy configured".to_string(),
+        ));
+    }
+    let handler = get_db_handler(&args.config).await?;
+    let count = prune_credential_secrets(&handler, &policy).await?;
+    println!("deleted {count} secret versions");
+    Ok(())
+}
+
+/// Arguments for re-keying the secrets stored in the database.
+#[derive(Debug, Parser)]
+pub struct RekeyArgs {
+    #[arg(short, long, default_value = "config.yaml")]
+    config: String,
+}
+
+/// Re-encrypt all secrets with the current encryption key.
+///
+/// The current and all previous keys are read from the secret backend configuration.
+pub async fn handle_rekey(args: &RekeyArgs) -> Result<()> {
+    let keys = secret_key_ring(secret_backend(&args.config)?.as_ref())?.ok_or_else(|| {
+        Error::Generic(
+            "missing encryption key for secrets, set UC_SECRETS_KEY or configure a secret backend"
+                .to_string(),
+        )
+    })?;
+    let store = connect_graph_store().await?.with_encryption_keys(keys);
+    let count = store.rekey_secrets().await?;
+    println!("re-keyed {count} secret versions");
+    Ok(())
+}
+
+async fn connect_graph_store() -> Result<GraphStore> {
     let db_url = std::env::var("DATABASE_URL")
         .map_err(|_| Error::Generic("missing DATABASE_URL".to_string()))?;
-    let store = Arc::new(GraphStore::connect(&db_url).await.unwrap());
+    let store = GraphStore::connect(&db_url).await?;
+    store.migrate().await?;
+    Ok(store)
+}
+
+/// Read the configuration file, if it exists.
+fn load_config(config: &str) -> Result<Option<Config>> {
+    if !std::path::Path::new(config).exists() {
+        return Ok(None);
+    }
+    Ok(Some(Config::from_file(config)?))
+}
+
+/// Read the secret backend from the configuration file, if it exists.
+fn secret_backend(config: &str) -> Result<Option<SecretBackend>> {
+    Ok(load_config(config)?.and_then(|config| config.secret_backend))
+}
+
+/// Resolve the encryption keys for secrets stored in the database.
+///
+/// Keys are read from the Postgres secret backend configuration, if present.
+/// Otherwise the key is read from `UC_SECRETS_KEY` or the file at `UC_SECRETS_KEY_FILE`,
+/// with the key id taken from `UC_SECRETS_KEY_ID`. Returns `None` if no key is configured.
+fn secret_key_ring(backend: Option<&SecretBackend>) -> Result<Option<KeyRing>> {
+    match backend {
+        Some(SecretBackend::Postgres(backend)) => return backend.key_ring().map(Some),
+        Some(_) => {
+            return Err(Error::Generic(
+                "configured secret backend does not store secrets in the database".to_string(),
+            ));
+        }
+        None => {}
+    }
+    let key_id = std::env::var("UC_SECRETS_KEY_ID").unwrap_or_else(|_| "default".to_string());
+    let key = match (
+        std::env::var("UC_SECRETS_KEY"),
+        std::env::var("UC_SECRETS_KEY_FILE"),
+    ) {
+        (Ok(key), _) => EncryptionKey::from_base64(key_id, &key)?,
+        (Err(_), Ok(path)) => EncryptionKey::from_file(key_id, path)?,
+        _ => return Ok(None),
+    };
+    Ok(Some(KeyRing::new(key)))
+}
+
+async fn get_db_handler(config: &str) -> Result<ServerHandler> {
+    let store = connect_graph_store().await?;
     let policy = Arc::new(ConstantPolicy::default());
-    store.migrate().await.unwrap();
-    let handler = ServerHandler::try_new_tokio(policy, store.clone(), store)?;
+    let handler = match secret_backend(config)? {
+        Some(SecretBackend::Vault(vault)) => {
+            let secrets = Arc::new(vault.secret_manager()?);
+            ServerHandler::try_new_tokio(policy, Arc::new(store), secrets)?
+        }
+        backend => {
+            let store = match secret_key_ring(backend.as_ref())? {
+                Some(keys) => store.with_encryption_keys(keys),
+                None => {
+                    // keep deployments from before envelope encryption running until a key is set.
+                    tracing::warn!(
+                        "no encryption key configured for secrets, existing secrets are read only; set UC_SECRETS_KEY and run `rekey` to encrypt them"
+                    );
+                    store
+                }
+            };
+            let store = Arc::new(store);
+            ServerHandler::try_new_tokio(policy, store.clone(), store)?
+        }
+    };
     Ok(handler)
 }
 
-async fn get_memory_handler() -> Result<ServerHandler> {
+async fn get_memory_handler(args: &ServerArgs) -> Result<ServerHandler> {
     let store = Arc::new(InMemoryResourceStore::new());
     let policy = Arc::new(ConstantPolicy::default());
-    let handler = ServerHandler::try_new_tokio(policy, store.clone(), store)?;
+    let secrets: Arc<dyn SecretManager> = match secret_backend(&args.config)? {
+        Some(SecretBackend::Vault(vault)) => Arc::new(vault.secret_manager()?),
+        _ => store.clone(),
+    };
+    let handler = ServerHandler::try_new_tokio(policy, store, secrets)?
+        .with_local_storage(local_storage_config(args));
     Ok(handler)
 }
 
+fn local_storage_config(args: &ServerArgs) -> LocalStorageConfig {
+    LocalStorageConfig::new(args.allowed_local_paths.iter().cloned())
+}
+
 fn init_tracing() {
     tracing_subscriber::registry()
         .with(
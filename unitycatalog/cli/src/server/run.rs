@@ -8,17 +8,22 @@ use tracing::Level;
 use unitycatalog_common::api::catalogs::CatalogHandler;
 use unitycatalog_common::api::credentials::CredentialsHandler;
 use unitycatalog_common::api::external_locations::ExternalLocationsHandler;
+use unitycatalog_common::api::iceberg::IcebergCatalogHandler;
+use unitycatalog_common::api::providers::ProvidersHandler;
+use unitycatalog_common::api::query::QueryHandler;
 use unitycatalog_common::api::recipients::RecipientsHandler;
 use unitycatalog_common::api::schemas::SchemasHandler;
 use unitycatalog_common::api::shares::SharesHandler;
 use unitycatalog_common::api::sharing::{SharingDiscoveryHandler, SharingQueryHandler};
 use unitycatalog_common::api::tables::TablesHandler;
+use unitycatalog_common::flight::FlightSqlServer;
 use unitycatalog_common::rest::{
     AuthenticationLayer, Authenticator, get_catalog_router, get_credentials_router,
-    get_external_locations_router, get_recipients_router, get_schemas_router, get_shares_router,
-    get_tables_router,
+    get_external_locations_router, get_iceberg_router, get_providers_router, get_query_router,
+    get_recipients_router, get_schemas_router, get_shares_router, get_tables_router,
 };
-use unitycatalog_common::{Error, Result};
+use unitycatalog_common::services::policy::Policy;
+use unitycatalog_common::{Error, ResourceStore, Result};
 
 pub async fn run_server_rest<T, A>(
     host: impl AsRef<str>,
@@ -36,6 +41,9 @@ where
         + TablesHandler
         + ExternalLocationsHandler
         + RecipientsHandler
+        + ProvidersHandler
+        + IcebergCatalogHandler
+        + QueryHandler
         + Clone,
     A: Authenticator + Clone,
 {
@@ -50,7 +58,10 @@ where
         .merge(get_credentials_router(handler.clone()))
         .merge(get_external_locations_router(handler.clone()))
         .merge(get_recipients_router(handler.clone()))
-        .merge(get_shares_router(handler.clone()));
+        .merge(get_providers_router(handler.clone()))
+        .merge(get_shares_router(handler.clone()))
+        .merge(get_query_router(handler.clone()))
+        .nest("/iceberg", get_iceberg_router(handler.clone()));
 
     let router = Router::new().nest("/api/2.1/unity-catalog", api_routes);
     let server = router.layer(AuthenticationLayer::new(authenticator));
@@ -58,6 +69,30 @@ where
     run(server, host, port, api_def).await
 }
 
+pub async fn run_server_grpc<T, A>(
+    host: impl AsRef<str>,
+    port: u16,
+    handler: T,
+    authenticator: A,
+) -> Result<()>
+where
+    T: QueryHandler + ResourceStore + Policy,
+    A: Authenticator,
+{
+    let addr = format!("{}:{}", host.as_ref(), port)
+        .parse()
+        .map_err(|e: std::net::AddrParseError| Error::Generic(e.to_string()))?;
+    let service = FlightSqlServer::new(handler, authenticator).into_service();
+    tracing::info!("Listening on: {}", addr);
+    tonic::transport::Server::builder()
+        .add_service(service)
+        .serve_with_shutdown(addr, shutdown_signal())
+        .await
+        .map_err(|e| Error::Generic(e.to_string()))?;
+
+    Ok(())
+}
+
 async fn run<S: Into<String> + Clone>(
     router: axum::Router,
     host: impl AsRef<str>,
@@ -77,7 +112,7 @@ async fn run<S: Into<String> + Clone>(
     let listener = TcpListener::bind(format!("{}:{}", host.as_ref(), port))
         .await
         .map_err(|e| Error::Generic(e.to_string()))?;
-    tracing::info!("Listning on: {}", listener.local_addr().unwrap());
+    tracing::info!("Listening on: {}", listener.local_addr().unwrap());
     axum::serve(listener, router)
         .with_graceful_shutdown(shutdown_signal())
         .await
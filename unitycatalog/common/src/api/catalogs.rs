@@ -2,10 +2,18 @@ use itertools::Itertools;
 use unitycatalog_derive::rest_handlers;
 
 use super::{RequestContext, SecuredAction};
-use crate::models::ObjectLabel;
+use crate::api::tables::schema_to_columns;
 use crate::models::catalogs::v1::*;
-use crate::resources::{ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::models::schemas::v1::SchemaInfo;
+use crate::models::tables::v1::{DataSourceFormat, TableInfo, TableType};
+use crate::models::{ObjectLabel, Resource};
+use crate::resources::{ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::services::delta_sharing::provider_client;
 use crate::services::policy::{Permission, Policy, Recipient, process_resources};
+use crate::services::secrets::SecretManager;
+use crate::services::system::{
+    ensure_not_reserved, ensure_writable, is_system_catalog, system_catalog_info,
+};
 use crate::{Error, Result};
 
 rest_handlers!(
@@ -47,6 +55,8 @@ pub trait CatalogHandler: Send + Sync + 'static {
     ///
     /// Creates a new catalog instance in the parent metastore if the caller
     /// is a metastore admin or has the CREATE_CATALOG privilege.
+    ///
+    /// Catalogs created from a share of a provider mirror the schemas and tables of the share.
     async fn create_catalog(
         &self,
         request: CreateCatalogRequest,
@@ -85,13 +95,19 @@ pub trait CatalogHandler: Send + Sync + 'static {
 }
 
 #[async_trait::async_trait]
-impl<T: ResourceStore + Policy> CatalogHandler for T {
+impl<T: ResourceStore + Policy + SecretManager> CatalogHandler for T {
     async fn create_catalog(
         &self,
         request: CreateCatalogRequest,
         context: RequestContext,
     ) -> Result<CatalogInfo> {
+        ensure_not_reserved(&request.name)?;
         self.check_required(&request, context.as_ref()).await?;
+        if request.share_name.is_some() && request.provider_name.is_none() {
+            return Err(Error::invalid_argument(
+                "provider_name is required when creating a catalog from a share",
+            ));
+        }
         let catalog_type = if request.provider_name.is_some() {
             CatalogType::DeltasharingCatalog
         } else {
@@ -107,6 +123,9 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
             catalog_type: Some(catalog_type as i32),
             ..Default::default()
         };
+        if catalog_type == CatalogType::DeltasharingCatalog {
+            return create_shared_catalog(self, resource, context.recipient()).await;
+        }
         let info = self.create(resource.into()).await?.0.try_into()?;
 
         // TODO:
@@ -121,6 +140,7 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
         request: DeleteCatalogRequest,
         context: RequestContext,
     ) -> Result<()> {
+        ensure_writable(&request.name)?;
         self.check_required(&request, context.as_ref()).await?;
         self.delete(&request.resource()).await
     }
@@ -131,6 +151,9 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
         context: RequestContext,
     ) -> Result<CatalogInfo> {
         self.check_required(&request, context.recipient()).await?;
+        if is_system_catalog(&request.name) {
+            return Ok(system_catalog_info());
+        }
         self.get(&request.resource()).await?.0.try_into()
     }
 
@@ -160,12 +183,20 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
         request: UpdateCatalogRequest,
         context: RequestContext,
     ) -> Result<CatalogInfo> {
+        ensure_writable(&request.name)?;
+        ensure_not_reserved(&request.new_name)?;
         self.check_required(&request, context.as_ref()).await?;
         let ident = request.resource();
+        // the storage root and catalog type are fixed at creation time and must survive updates.
+        let current: CatalogInfo = self.get(&ident).await?.0.try_into()?;
         let resource = CatalogInfo {
             name: request.new_name,
             comment: request.comment,
             properties: request.properties,
+            storage_root: current.storage_root,
+            catalog_type: current.catalog_type,
+            provider_name: current.provider_name,
+            share_name: current.share_name,
             ..Default::default()
         };
         // TODO:
@@ -174,3 +205,87 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
         self.update(&ident, resource.into()).await?.0.try_into()
     }
 }
+
+/// Create a catalog mirroring the schemas and tables of a share of a provider.
+///
+/// The tables are registered with the schema reported by the sharing server,
+/// their data is read through its query API.
+///
+/// The schemas and tables are a one-time snapshot of the share taken at creation.
+/// Schemas or tables added to the share later are not picked up, the catalog has
+/// to be recreated to reflect them. If any object fails to be created, all objects
+/// created so far are removed again, so that creating the catalog can be retried.
+async fn create_shared_catalog<T: ResourceStore + Policy + SecretManager>(
+    handler: &T,
+    resource: CatalogInfo,
+    recipient: &Recipient,
+) -> Result<CatalogInfo> {
+    let (Some(provider_name), Some(share_name)) = (
+        resource.provider_name.as_deref(),
+        resource.share_name.as_deref(),
+    ) else {
+        return Err(Error::invalid_argument(
+            "share_name is required when creating a catalog from a provider",
+        ));
+    };
+    let provider = ResourceIdent::provider(ResourceName::new([provider_name]));
+    handler
+        .authorize_checked(&provider, &Permission::Read, recipient)
+        .await?;
+    handler.get(&provider).await?;
+
+    // discover the whole share before creating anything, so that unreachable
+    // providers or unknown shares do not leave behind empty catalogs.
+    let client = provider_client(handler, provider_name).await?;
+    let schemas = client.list_schemas(share_name).await?;
+    let mut tables = Vec::new();
+    for table in client.list_share_tables(share_name).await? {
+        let metadata = client.table_metadata(&table).await?;
+        tables.push((table, metadata));
+    }
+
+    let catalog_name = resource.name.clone();
+    let mut resources: Vec<Resource> = vec![resource.into()];
+    for schema in schemas {
+        let resource = SchemaInfo {
+            full_name: Some(format!("{}.{}", catalog_name, schema.name)),
+            name: schema.name,
+            catalog_name: catalog_name.clone(),
+            ..Default::default()
+        };
+        resources.push(resource.into());
+    }
+    for (table, metadata) in tables {
+        let resource = TableInfo {
+            name: table.name,
+            schema_name: table.schema,
+            catalog_name: catalog_name.clone(),
+            table_type: TableType::External as i32,
+            data_source_format: DataSourceFormat::Deltasharing as i32,
+            comment: metadata.description.clone(),
+            columns: schema_to_columns(&metadata.schema()?, &metadata.partition_columns)?,
+            ..Default::default()
+        };
+        resources.push(resource.into());
+    }
+
+    let mut created = Vec::with_capacity(resources.len());
+    for resource in resources {
+        match handler.create(resource).await {
+            Ok((resource, id)) => created.push((resource, id)),
+            Err(error) => {
+                // children are removed before their parents.
+                for (resource, id) in created.into_iter().rev() {
+                    let ident = resource.resource_label().to_ident(id);
+                    if let Err(e) = handler.delete(&ident).await {
+                        tracing::error!(
+                            "failed to remove {ident:?} of partially created catalog '{catalog_name}': {e}"
+                        );
+                    }
+                }
+                return Err(error);
+            }
+        }
+    }
+    created.swap_remove(0).0.try_into()
+}
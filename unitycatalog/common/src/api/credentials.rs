@@ -3,11 +3,14 @@ use serde::{Deserialize, Serialize};
 use unitycatalog_derive::rest_handlers;
 
 use super::{RequestContext, SecuredAction};
-use crate::models::ObjectLabel;
 use crate::models::credentials::v1::*;
+use crate::models::{ExternalLocationInfo, ObjectLabel};
 use crate::resources::{ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::services::kernel::engine::{ProvidesObjectStoreCache, build_object_store};
 use crate::services::policy::{Permission, Policy, Recipient, process_resources};
 use crate::services::secrets::SecretManager;
+use crate::services::validation::validate_location;
+use crate::services::{ProvidesLocalStorage, StorageLocationUrl};
 use crate::{Error, Result};
 
 rest_handlers!(
@@ -26,6 +29,9 @@ rest_handlers!(
         DeleteCredentialRequest, Credential, Manage with [
             name: path as String,
         ];
+        ValidateCredentialRequest, Credential, Read, ValidateCredentialResponse with [
+            credential_name: body as String,
+        ] at "validate-credentials";
     ]
 );
 
@@ -76,6 +82,16 @@ pub trait CredentialsHandler: Send + Sync + 'static {
         request: DeleteCredentialRequest,
         context: RequestContext,
     ) -> Result<()>;
+
+    /// Validate a credential against a storage location.
+    ///
+    /// Probes list, read and - unless the location is read-only - write and delete
+    /// operations and reports the outcome of each probe.
+    async fn validate_credential(
+        &self,
+        request: ValidateCredentialRequest,
+        context: RequestContext,
+    ) -> Result<ValidateCredentialResponse>;
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -83,6 +99,7 @@ struct CredentialContainer {
     pub azure_sp: Option<AzureServicePrincipal>,
     pub azure_msi: Option<AzureManagedIdentity>,
     pub azure_key: Option<AzureStorageKey>,
+    pub no_credential: Option<NoCredential>,
 }
 
 impl CredentialContainer {
@@ -92,16 +109,25 @@ impl CredentialContainer {
                 azure_sp: Some(azure_sp),
                 azure_msi: None,
                 azure_key: None,
+                no_credential: None,
             },
             create_credential_request::Credential::AzureManagedIdentity(azure_msi) => Self {
                 azure_sp: None,
                 azure_msi: Some(azure_msi),
                 azure_key: None,
+                no_credential: None,
             },
             create_credential_request::Credential::AzureStorageKey(azure_key) => Self {
                 azure_sp: None,
                 azure_msi: None,
                 azure_key: Some(azure_key),
+                no_credential: None,
+            },
+            create_credential_request::Credential::NoCredential(no_credential) => Self {
+                azure_sp: None,
+                azure_msi: None,
+                azure_key: None,
+                no_credential: Some(no_credential),
             },
         }
     }
@@ -112,16 +138,25 @@ impl CredentialContainer {
                 azure_sp: Some(azure_sp),
                 azure_msi: None,
                 azure_key: None,
+                no_credential: None,
             },
             update_credential_request::Credential::AzureManagedIdentity(azure_msi) => Self {
                 azure_sp: None,
                 azure_msi: Some(azure_msi),
                 azure_key: None,
+                no_credential: None,
             },
             update_credential_request::Credential::AzureStorageKey(azure_key) => Self {
                 azure_sp: None,
                 azure_msi: None,
                 azure_key: Some(azure_key),
+                no_credential: None,
+            },
+            update_credential_request::Credential::NoCredential(no_credential) => Self {
+                azure_sp: None,
+                azure_msi: None,
+                azure_key: None,
+                no_credential: Some(no_credential),
             },
         }
     }
@@ -133,6 +168,8 @@ impl CredentialContainer {
             Ok(credential_info::Credential::AzureManagedIdentity(azure_msi))
         } else if let Some(azure_key) = self.azure_key {
             Ok(credential_info::Credential::AzureStorageKey(azure_key))
+        } else if let Some(no_credential) = self.no_credential {
+            Ok(credential_info::Credential::NoCredential(no_credential))
         } else {
             Err(Error::invalid_argument("credential is required"))
         }
@@ -144,7 +181,10 @@ impl CredentialContainer {
 }
 
 #[async_trait::async_trait]
-impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
+impl<T> CredentialsHandler for T
+where
+    T: ResourceStore + Policy + SecretManager + ProvidesLocalStorage + ProvidesObjectStoreCache,
+{
     async fn list_credentials(
         &self,
         request: ListCredentialsRequest,
@@ -187,11 +227,11 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
             read_only: request.read_only.unwrap_or(false),
             used_for_managed_storage: false,
             id: "".to_string(),
-            created_at: None,
+            created_at: Some(chrono::Utc::now().timestamp_millis()),
             updated_at: None,
             credential: None,
             owner: None,
-            created_by: None,
+            created_by: context.recipient().user_name().map(ToString::to_string),
             updated_by: None,
         };
         self.create(cred.into()).await?.0.try_into()
@@ -223,42 +263,46 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
         context: RequestContext,
     ) -> Result<CredentialInfo> {
         self.check_required(&request, context.recipient()).await?;
+        let (current, _) = self.get(&request.resource()).await?;
+        let curr_ident = current.resource_ident();
+        let mut cred: CredentialInfo = current.try_into()?;
+        if request
+            .new_name
+            .as_ref()
+            .is_some_and(|name| name != &cred.name)
+        {
+            // secrets and external locations reference credentials by name.
+            return Err(Error::invalid_argument(
+                "renaming credentials is not supported",
+            ));
+        }
+
         if let Some(credential) = request.credential {
+            // rotation adds a new secret version, readers holding
+            // the previous version keep working until they refresh.
             self.update_secret(
-                &request.name,
-                CredentialContainer::from_update(credential.clone())
+                &cred.name,
+                CredentialContainer::from_update(credential)
                     .to_vec()?
                     .into(),
             )
             .await?;
+            self.object_store_cache().invalidate_credential(&cred.name);
         }
-        let curr = self
-            .get_credential(
-                GetCredentialRequest {
-                    name: request.name.clone(),
-                },
-                context.clone(),
-            )
-            .await?;
-        let cred = CredentialInfo {
-            name: request.name.clone(),
-            full_name: Some(request.name),
-            comment: request.comment,
-            purpose: curr.purpose,
-            read_only: request.read_only.unwrap_or(false),
-            used_for_managed_storage: false,
-            id: "".to_string(),
-            created_at: None,
-            updated_at: None,
-            credential: None,
-            owner: None,
-            created_by: None,
-            updated_by: None,
-        };
-        self.update(&curr.resource_ident(), cred.into())
-            .await?
-            .0
-            .try_into()
+        if let Some(comment) = request.comment {
+            cred.comment = Some(comment);
+        }
+        if let Some(read_only) = request.read_only {
+            cred.read_only = read_only;
+        }
+        if let Some(owner) = request.owner {
+            cred.owner = Some(owner);
+        }
+        cred.updated_at = Some(chrono::Utc::now().timestamp_millis());
+        cred.updated_by = context.recipient().user_name().map(ToString::to_string);
+        cred.credential = None;
+
+        self.update(&curr_ident, cred.into()).await?.0.try_into()
     }
 
     async fn delete_credential(
@@ -274,4 +318,40 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
             Err(e) => Err(e),
         }
     }
+
+    async fn validate_credential(
+        &self,
+        request: ValidateCredentialRequest,
+        context: RequestContext,
+    ) -> Result<ValidateCredentialResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        let (url, read_only) = match (request.url, request.external_location_name) {
+            (Some(url), _) => (url, request.read_only.unwrap_or(false)),
+            (None, Some(name)) => {
+                let ident = ResourceIdent::external_location(ResourceName::new([name]));
+                self.authorize_checked(&ident, &Permission::Read, context.recipient())
+                    .await?;
+                let location: ExternalLocationInfo = self.get(&ident).await?.0.try_into()?;
+                (
+                    location.url,
+                    request.read_only.unwrap_or(location.read_only),
+                )
+            }
+            (None, None) => {
+                return Err(Error::invalid_argument(
+                    "either url or external_location_name is required",
+                ));
+            }
+        };
+        let credential = self
+            .get_credential_internal(GetCredentialRequest {
+                name: request.credential_name,
+            })
+            .await?
+            .credential
+            .ok_or_else(|| Error::invalid_argument("credential is required"))?;
+        let location = StorageLocationUrl::parse(&url)?;
+        let store = build_object_store(&location, credential, self.local_storage())?;
+        validate_location(store.as_ref(), &location, read_only).await
+    }
 }
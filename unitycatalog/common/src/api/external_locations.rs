@@ -1,13 +1,21 @@
 use itertools::Itertools;
 use unitycatalog_derive::rest_handlers;
 
-use super::{RequestContext, SecuredAction};
-use crate::models::ObjectLabel;
+use super::{CredentialsHandler, RequestContext, SecuredAction};
+use crate::models::credentials::v1::GetCredentialRequest;
 use crate::models::external_locations::v1::*;
+use crate::models::tables::v1::TableInfo;
+use crate::models::{ObjectLabel, Resource};
 use crate::resources::{ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::services::kernel::engine::{ProvidesObjectStoreCache, build_object_store};
 use crate::services::policy::{Permission, Policy, Recipient, process_resources};
+use crate::services::validation::{ensure_valid, validate_location};
+use crate::services::{ProvidesLocalStorage, StorageLocationUrl};
 use crate::{Error, Result};
 
+/// Upper bound for `max_results` in external location listings.
+const MAX_RESULTS_EXTERNAL_LOCATIONS: usize = 999;
+
 rest_handlers!(
     ExternalLocationsHandler, "external-locations",
     [
@@ -25,6 +33,11 @@ rest_handlers!(
             name: path as String,
             force: query as Option<bool>,
         ];
+        ListExternalLocationTablesRequest, ExternalLocation, Read, ListExternalLocationTablesResponse with [
+            name: path as String,
+            max_results: query as Option<i32>,
+            page_token: query as Option<String>,
+        ] at "external-locations/{name}/tables";
     ]
 );
 
@@ -64,16 +77,120 @@ pub trait ExternalLocationsHandler: Send + Sync + 'static {
         request: UpdateExternalLocationRequest,
         context: RequestContext,
     ) -> Result<ExternalLocationInfo>;
+
+    /// List tables stored below an external location.
+    async fn list_external_location_tables(
+        &self,
+        request: ListExternalLocationTablesRequest,
+        context: RequestContext,
+    ) -> Result<ListExternalLocationTablesResponse>;
+}
+
+/// Check that the location can be accessed with its storage credential.
+async fn validate_location_access<T>(handler: &T, location: &ExternalLocationInfo) -> Result<()>
+where
+    T: CredentialsHandler + ProvidesLocalStorage,
+{
+    let credential = handler
+        .get_credential_internal(GetCredentialRequest {
+            name: location.credential_name.clone(),
+        })
+        .await?
+        .credential
+        .ok_or_else(|| Error::invalid_argument("credential is required"))?;
+    let url = StorageLocationUrl::parse(&location.url)?;
+    let store = build_object_store(&url, credential, handler.local_storage())?;
+    ensure_valid(&validate_location(store.as_ref(), &url, location.read_only).await?)
+}
+
+/// Check that the url does not overlap with any other external location.
+async fn ensure_no_overlap<T: ResourceStore>(handler: &T, name: &str, url: &str) -> Result<()> {
+    let url = StorageLocationUrl::parse(url)?;
+    let mut page_token = None;
+    loop {
+        let (locations, next_page_token) = handler
+            .list(&ObjectLabel::ExternalLocationInfo, None, None, page_token)
+            .await?;
+        for location in locations {
+            let location: ExternalLocationInfo = location.try_into()?;
+            if location.name != name && StorageLocationUrl::parse(&location.url)?.overlaps(&url) {
+                return Err(Error::invalid_argument(format!(
+                    "url '{}' overlaps with external location '{}'",
+                    url.raw(),
+                    location.name
+                )));
+            }
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Clamp the requested page size to the bounds accepted by the listing endpoints.
+fn max_results(max_results: Option<i32>) -> Option<usize> {
+    max_results.map(|v| v.clamp(1, MAX_RESULTS_EXTERNAL_LOCATIONS as i32) as usize)
+}
+
+/// Find tables whose storage location is contained in the given location.
+///
+/// Pages of the table listing are scanned until `max_results` matching tables
+/// are found. The returned token continues the scan after the last inspected table.
+async fn tables_in_location<T: ResourceStore>(
+    handler: &T,
+    location: &StorageLocationUrl,
+    max_results: Option<usize>,
+    mut page_token: Option<String>,
+) -> Result<(Vec<Resource>, Option<String>)> {
+    let mut tables = Vec::new();
+    loop {
+        let remaining = max_results.map(|max| max - tables.len());
+        let (resources, next_page_token) = handler
+            .list(&ObjectLabel::TableInfo, None, remaining, page_token)
+            .await?;
+        for resource in resources {
+            let table: TableInfo = resource.clone().try_into()?;
+            let Some(storage_location) = table.storage_location.as_deref() else {
+                continue;
+            };
+            let table_location = match StorageLocationUrl::parse(storage_location) {
+                Ok(table_location) => table_location,
+                Err(e) => {
+                    tracing::warn!(
+                        "skipping table '{}' with invalid storage location: {e}",
+                        table.full_name.as_deref().unwrap_or(&table.name)
+                    );
+                    continue;
+                }
+            };
+            if location.contains(&table_location) {
+                tables.push(resource);
+            }
+        }
+        page_token = next_page_token;
+        if page_token.is_none() || max_results.is_some_and(|max| tables.len() >= max) {
+            return Ok((tables, page_token));
+        }
+    }
 }
 
 #[async_trait::async_trait]
-impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
+impl<T> ExternalLocationsHandler for T
+where
+    T: ResourceStore
+        + Policy
+        + CredentialsHandler
+        + ProvidesLocalStorage
+        + ProvidesObjectStoreCache,
+{
     async fn create_external_location(
         &self,
         request: CreateExternalLocationRequest,
         context: RequestContext,
     ) -> Result<ExternalLocationInfo> {
         self.check_required(&request, context.as_ref()).await?;
+        let skip_validation = request.skip_validation.unwrap_or(false);
         let mut resource = ExternalLocationInfo {
             name: request.name,
             url: request.url,
@@ -90,7 +207,10 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
             resource.credential_id = uuid.hyphenated().to_string();
         }
 
-        // TODO: validate we can access the url with the provide credential
+        ensure_no_overlap(self, &resource.name, &resource.url).await?;
+        if !skip_validation {
+            validate_location_access(self, &resource).await?;
+        }
 
         let info = self.create(resource.into()).await?.0.try_into()?;
         Ok(info)
@@ -102,8 +222,25 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
         context: RequestContext,
     ) -> Result<()> {
         self.check_required(&request, context.as_ref()).await?;
-        // TODO: check if the location is used by any resources
-        self.delete(&request.resource()).await
+        let (location, location_ref) = self.get(&request.resource()).await?;
+        if !request.force.unwrap_or(false) {
+            let location: ExternalLocationInfo = location.try_into()?;
+            let url = StorageLocationUrl::parse(&location.url)?;
+            let (tables, _) = tables_in_location(self, &url, Some(1), None).await?;
+            if let Some(table) = tables.into_iter().next() {
+                let table: TableInfo = table.try_into()?;
+                return Err(Error::invalid_argument(format!(
+                    "external location '{}' is used by table '{}'; use force to delete it anyway",
+                    location.name,
+                    table.full_name.unwrap_or(table.name)
+                )));
+            }
+        }
+        self.delete(&request.resource()).await?;
+        if let ResourceRef::Uuid(location_id) = location_ref {
+            self.object_store_cache().invalidate_location(&location_id);
+        }
+        Ok(())
     }
 
     async fn get_external_location(
@@ -128,7 +265,7 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
             .list(
                 &ObjectLabel::ExternalLocationInfo,
                 None,
-                request.max_results.map(|v| v as usize),
+                max_results(request.max_results),
                 request.page_token,
             )
             .await?;
@@ -146,14 +283,18 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
     ) -> Result<ExternalLocationInfo> {
         self.check_required(&request, context.as_ref()).await?;
 
-        let (current, _) = self.get(&request.resource()).await?;
+        let (current, current_ref) = self.get(&request.resource()).await?;
         let curr_ident = current.resource_ident();
         let mut current: ExternalLocationInfo = current.try_into()?;
+        let requires_validation = request.url.is_some()
+            || request.credential_name.is_some()
+            || request.read_only == Some(false);
 
+        let location_name = current.name.clone();
         if let Some(name) = request.new_name {
             current.name = name;
         }
-        if let Some(url) = request.url {
+        if let Some(url) = request.url.clone() {
             current.url = url;
         }
         if let Some(credential_name) = request.credential_name {
@@ -166,10 +307,43 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
             current.comment = Some(comment);
         }
 
+        if request.url.is_some() {
+            ensure_no_overlap(self, &location_name, &current.url).await?;
+        }
+        if requires_validation && !request.skip_validation.unwrap_or(false) {
+            validate_location_access(self, &current).await?;
+        }
+
         // TODO:
         // - add update_* relations
         // - update owner if necessary
 
-        self.update(&curr_ident, current.into()).await?.0.try_into()
+        let (updated, _) = self.update(&curr_ident, current.into()).await?;
+        if let ResourceRef::Uuid(location_id) = current_ref {
+            self.object_store_cache().invalidate_location(&location_id);
+        }
+        updated.try_into()
+    }
+
+    async fn list_external_location_tables(
+        &self,
+        request: ListExternalLocationTablesRequest,
+        context: RequestContext,
+    ) -> Result<ListExternalLocationTablesResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        let location: ExternalLocationInfo = self.get(&request.resource()).await?.0.try_into()?;
+        let url = StorageLocationUrl::parse(&location.url)?;
+        let (mut resources, next_page_token) = tables_in_location(
+            self,
+            &url,
+            max_results(request.max_results),
+            request.page_token,
+        )
+        .await?;
+        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        Ok(ListExternalLocationTablesResponse {
+            tables: resources.into_iter().map(|r| r.try_into()).try_collect()?,
+            next_page_token,
+        })
     }
 }
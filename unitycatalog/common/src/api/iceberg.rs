@@ -0,0 +1,350 @@
+//! Iceberg REST catalog over the catalogs, schemas and tables in the store.
+//!
+//! Catalogs are exposed as single level namespaces, schemas as two level namespaces
+//! below their catalog. Tables can be loaded if they are stored as iceberg tables or
+//! are delta tables with iceberg metadata enabled via UniForm.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::tables::get_active_table;
+use super::{RequestContext, SecuredAction};
+use crate::models::ObjectLabel;
+use crate::models::catalogs::v1::CatalogInfo;
+use crate::models::google::protobuf::Struct;
+use crate::models::google::protobuf::value::Kind;
+use crate::models::schemas::v1::SchemaInfo;
+use crate::models::tables::v1::{DataSourceFormat, TableInfo};
+use crate::resources::{ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::services::StorageLocationUrl;
+use crate::services::kernel::TableManager;
+use crate::services::policy::{Permission, Policy, process_resources};
+use crate::{Error, Result};
+
+/// Delta table property listing the additional formats written by UniForm.
+const UNIVERSAL_FORMATS: &str = "delta.universalFormat.enabledFormats";
+
+/// Upper bound for the page size of namespace and table listings.
+const MAX_PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CatalogConfig {
+    pub defaults: HashMap<String, String>,
+    pub overrides: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListNamespacesRequest {
+    /// List the namespaces below this namespace, or the top level namespaces if empty.
+    pub parent: Vec<String>,
+    pub page_size: Option<usize>,
+    pub page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListNamespacesResponse {
+    pub namespaces: Vec<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GetNamespaceRequest {
+    pub namespace: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct GetNamespaceResponse {
+    pub namespace: Vec<String>,
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListIcebergTablesRequest {
+    pub namespace: Vec<String>,
+    pub page_size: Option<usize>,
+    pub page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableIdentifier {
+    pub namespace: Vec<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListIcebergTablesResponse {
+    pub identifiers: Vec<TableIdentifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadIcebergTableRequest {
+    pub namespace: Vec<String>,
+    pub table: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadTableResult {
+    pub metadata_location: String,
+    pub metadata: serde_json::Value,
+    pub config: HashMap<String, String>,
+}
+
+impl SecuredAction for ListNamespacesRequest {
+    fn resource(&self) -> ResourceIdent {
+        match self.parent.as_slice() {
+            [] => ResourceIdent::catalog(ResourceRef::Undefined),
+            parent => ResourceIdent::catalog(ResourceName::new(parent)),
+        }
+    }
+
+    fn permission(&self) -> &'static Permission {
+        &Permission::Read
+    }
+}
+
+impl SecuredAction for GetNamespaceRequest {
+    fn resource(&self) -> ResourceIdent {
+        namespace_ident(&self.namespace)
+    }
+
+    fn permission(&self) -> &'static Permission {
+        &Permission::Read
+    }
+}
+
+impl SecuredAction for ListIcebergTablesRequest {
+    fn resource(&self) -> ResourceIdent {
+        namespace_ident(&self.namespace)
+    }
+
+    fn permission(&self) -> &'static Permission {
+        &Permission::Read
+    }
+}
+
+impl SecuredAction for LoadIcebergTableRequest {
+    fn resource(&self) -> ResourceIdent {
+        ResourceIdent::table(ResourceName::new(
+            self.namespace.iter().chain(std::iter::once(&self.table)),
+        ))
+    }
+
+    fn permission(&self) -> &'static Permission {
+        &Permission::Read
+    }
+}
+
+/// Clamp the requested page size to the bounds accepted by the listing endpoints.
+fn page_size(page_size: Option<usize>) -> Option<usize> {
+    page_size.map(|v| v.clamp(1, MAX_PAGE_SIZE))
+}
+
+fn namespace_ident(namespace: &[String]) -> ResourceIdent {
+    match namespace {
+        [catalog] => ResourceIdent::catalog(ResourceName::new([catalog])),
+        namespace => ResourceIdent::schema(ResourceName::new(namespace)),
+    }
+}
+
+#[async_trait::async_trait]
+pub trait IcebergCatalogHandler: Send + Sync + 'static {
+    /// List catalogs, or the schemas of a catalog.
+    async fn list_namespaces(
+        &self,
+        request: ListNamespacesRequest,
+        context: RequestContext,
+    ) -> Result<ListNamespacesResponse>;
+
+    /// Get the properties of a catalog or schema.
+    async fn get_namespace(
+        &self,
+        request: GetNamespaceRequest,
+        context: RequestContext,
+    ) -> Result<GetNamespaceResponse>;
+
+    /// List the tables of a schema that can be read as iceberg tables.
+    async fn list_iceberg_tables(
+        &self,
+        request: ListIcebergTablesRequest,
+        context: RequestContext,
+    ) -> Result<ListIcebergTablesResponse>;
+
+    /// Load the current metadata of an iceberg or UniForm table.
+    async fn load_iceberg_table(
+        &self,
+        request: LoadIcebergTableRequest,
+        context: RequestContext,
+    ) -> Result<LoadTableResult>;
+}
+
+#[async_trait::async_trait]
+impl<T: ResourceStore + Policy + TableManager> IcebergCatalogHandler for T {
+    async fn list_namespaces(
+        &self,
+        request: ListNamespacesRequest,
+        context: RequestContext,
+    ) -> Result<ListNamespacesResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        let (label, namespace) = match request.parent.as_slice() {
+            [] => (ObjectLabel::CatalogInfo, None),
+            [catalog] => {
+                // listing the schemas of a missing catalog is an error, not an empty list.
+                self.get(&ResourceIdent::catalog(ResourceName::new([catalog])))
+                    .await?;
+                (ObjectLabel::SchemaInfo, Some(ResourceName::new([catalog])))
+            }
+            // schemas are the deepest namespaces.
+            _ => return Ok(ListNamespacesResponse::default()),
+        };
+        let (mut resources, next_page_token) = self
+            .list(
+                &label,
+                namespace.as_ref(),
+                page_size(request.page_size),
+                request.page_token,
+            )
+            .await?;
+        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        Ok(ListNamespacesResponse {
+            namespaces: resources
+                .iter()
+                .map(|r| r.resource_name().into_inner())
+                .collect(),
+            next_page_token,
+        })
+    }
+
+    async fn get_namespace(
+        &self,
+        request: GetNamespaceRequest,
+        context: RequestContext,
+    ) -> Result<GetNamespaceResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        let (properties, comment, location) = match request.namespace.as_slice() {
+            [_] => {
+                let info: CatalogInfo = self.get(&request.resource()).await?.0.try_into()?;
+                (info.properties, info.comment, info.storage_root)
+            }
+            [_, _] => {
+                let info: SchemaInfo = self.get(&request.resource()).await?.0.try_into()?;
+                (info.properties, info.comment, info.storage_root)
+            }
+            _ => return Err(Error::NotFound),
+        };
+        let mut properties = string_properties(properties.as_ref());
+        properties.extend(comment.map(|c| ("comment".to_string(), c)));
+        properties.extend(location.map(|l| ("location".to_string(), l)));
+        Ok(GetNamespaceResponse {
+            namespace: request.namespace,
+            properties,
+        })
+    }
+
+    async fn list_iceberg_tables(
+        &self,
+        request: ListIcebergTablesRequest,
+        context: RequestContext,
+    ) -> Result<ListIcebergTablesResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        if !matches!(request.namespace.len(), 1 | 2) {
+            return Err(Error::NotFound);
+        }
+        // listing the tables of a missing namespace is an error, not an empty list.
+        self.get(&request.resource()).await?;
+        // only schemas contain tables.
+        if request.namespace.len() == 1 {
+            return Ok(ListIcebergTablesResponse::default());
+        }
+        let (mut resources, next_page_token) = self
+            .list(
+                &ObjectLabel::TableInfo,
+                Some(&ResourceName::new(&request.namespace)),
+                page_size(request.page_size),
+                request.page_token,
+            )
+            .await?;
+        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let mut identifiers = Vec::with_capacity(resources.len());
+        for resource in resources {
+            let info: TableInfo = resource.try_into()?;
+            if info.deleted_at.is_none() && has_iceberg_metadata(&info) {
+                identifiers.push(TableIdentifier {
+                    namespace: request.namespace.clone(),
+                    name: info.name,
+                });
+            }
+        }
+        Ok(ListIcebergTablesResponse {
+            identifiers,
+            next_page_token,
+        })
+    }
+
+    async fn load_iceberg_table(
+        &self,
+        request: LoadIcebergTableRequest,
+        context: RequestContext,
+    ) -> Result<LoadTableResult> {
+        self.check_required(&request, context.as_ref()).await?;
+        let info = get_active_table(self, &request.resource()).await?;
+        if !has_iceberg_metadata(&info) {
+            return Err(Error::invalid_argument(format!(
+                "table '{}' cannot be read as iceberg table",
+                request.table
+            )));
+        }
+        let Some(location) = info.storage_location.as_deref() else {
+            return Err(Error::invalid_argument("missing storage location"));
+        };
+        let table = self
+            .read_iceberg_table(&StorageLocationUrl::parse(location)?)
+            .await?;
+        Ok(LoadTableResult {
+            metadata_location: table.metadata_location.clone(),
+            metadata: table.metadata_json().clone(),
+            config: HashMap::new(),
+        })
+    }
+}
+
+/// Check if iceberg metadata is maintained for a table.
+///
+/// This is the case for iceberg tables and delta tables with UniForm enabled for iceberg.
+fn has_iceberg_metadata(info: &TableInfo) -> bool {
+    if info.data_source_format == DataSourceFormat::Iceberg as i32 {
+        return true;
+    }
+    info.data_source_format == DataSourceFormat::Delta as i32
+        && string_properties(info.properties.as_ref())
+            .get(UNIVERSAL_FORMATS)
+            .is_some_and(|formats| formats.split(',').any(|f| f.trim() == "iceberg"))
+}
+
+/// Collect the properties that can be represented as strings.
+fn string_properties(properties: Option<&Struct>) -> HashMap<String, String> {
+    let Some(properties) = properties else {
+        return HashMap::new();
+    };
+    properties
+        .fields
+        .iter()
+        .filter_map(|(key, value)| {
+            let value = match value.kind.as_ref()? {
+                Kind::StringValue(value) => value.clone(),
+                Kind::NumberValue(value) => value.to_string(),
+                Kind::BoolValue(value) => value.to_string(),
+                _ => return None,
+            };
+            Some((key.clone(), value))
+        })
+        .collect()
+}
@@ -1,6 +1,9 @@
 pub mod catalogs;
 pub mod credentials;
 pub mod external_locations;
+pub mod iceberg;
+pub mod providers;
+pub mod query;
 pub mod recipients;
 pub mod schemas;
 pub mod shares;
@@ -10,6 +13,9 @@ pub mod tables;
 pub use catalogs::CatalogHandler;
 pub use credentials::CredentialsHandler;
 pub use external_locations::ExternalLocationsHandler;
+pub use iceberg::IcebergCatalogHandler;
+pub use providers::ProvidersHandler;
+pub use query::QueryHandler;
 pub use recipients::RecipientsHandler;
 pub use schemas::SchemasHandler;
 pub use shares::SharesHandler;
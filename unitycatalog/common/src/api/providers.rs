@@ -0,0 +1,273 @@
+use itertools::Itertools;
+use unitycatalog_derive::rest_handlers;
+
+use super::{RequestContext, SecuredAction};
+use crate::models::catalogs::v1::CatalogInfo;
+use crate::models::providers::v1::*;
+use crate::models::recipients::v1::AuthenticationType;
+use crate::models::{ObjectLabel, Profile};
+use crate::resources::{ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::services::delta_sharing::{parse_profile, profile_secret_name, provider_client};
+use crate::services::policy::{Permission, Policy, Recipient, process_resources};
+use crate::services::secrets::SecretManager;
+use crate::{Error, Result};
+
+rest_handlers!(
+    ProvidersHandler, "providers", [
+        CreateProviderRequest, Provider, Create, ProviderInfo;
+        ListProvidersRequest, Provider, Read, ListProvidersResponse;
+        GetProviderRequest, Provider, Read, ProviderInfo with [
+            name: path as String,
+        ];
+        UpdateProviderRequest, Provider, Manage, ProviderInfo with [
+            name: path as String,
+        ];
+        DeleteProviderRequest, Provider, Manage with [
+            name: path as String
+        ];
+        ListProviderSharesRequest, Provider, Read, ListProviderSharesResponse with [
+            name: path as String,
+            max_results: query as Option<i32>,
+            page_token: query as Option<String>,
+        ] at "providers/{name}/shares";
+    ]
+);
+
+/// A provider is a remote Delta Sharing server that shares data with this catalog.
+///
+/// The sharing profile issued by the provider is stored as a secret, only its
+/// endpoint is exposed on the provider.
+#[async_trait::async_trait]
+pub trait ProvidersHandler: Send + Sync + 'static {
+    /// List providers.
+    async fn list_providers(
+        &self,
+        request: ListProvidersRequest,
+        context: RequestContext,
+    ) -> Result<ListProvidersResponse>;
+
+    /// Create a new provider.
+    async fn create_provider(
+        &self,
+        request: CreateProviderRequest,
+        context: RequestContext,
+    ) -> Result<ProviderInfo>;
+
+    /// Get a provider.
+    async fn get_provider(
+        &self,
+        request: GetProviderRequest,
+        context: RequestContext,
+    ) -> Result<ProviderInfo>;
+
+    /// Update a provider.
+    async fn update_provider(
+        &self,
+        request: UpdateProviderRequest,
+        context: RequestContext,
+    ) -> Result<ProviderInfo>;
+
+    /// Delete a provider.
+    async fn delete_provider(
+        &self,
+        request: DeleteProviderRequest,
+        context: RequestContext,
+    ) -> Result<()>;
+
+    /// List the shares offered by a provider.
+    async fn list_provider_shares(
+        &self,
+        request: ListProviderSharesRequest,
+        context: RequestContext,
+    ) -> Result<ListProviderSharesResponse>;
+}
+
+/// The parts of a sharing profile that are safe to return.
+fn recipient_profile(profile: &Profile) -> RecipientProfile {
+    RecipientProfile {
+        endpoint: profile.endpoint.clone(),
+        share_credentials_version: profile.share_credentials_version,
+        expiration_time: profile.expiration_time.clone(),
+    }
+}
+
+/// Names of the catalogs created from the shares of a provider.
+async fn shared_catalogs<T: ResourceStore>(
+    handler: &T,
+    provider_name: &str,
+) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (catalogs, next_page_token) = handler
+            .list(&ObjectLabel::CatalogInfo, None, None, page_token)
+            .await?;
+        for catalog in catalogs {
+            let info: CatalogInfo = catalog.try_into()?;
+            if info.provider_name.as_deref() == Some(provider_name) {
+                names.push(info.name);
+            }
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(names),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: ResourceStore + Policy + SecretManager> ProvidersHandler for T {
+    async fn create_provider(
+        &self,
+        request: CreateProviderRequest,
+        context: RequestContext,
+    ) -> Result<ProviderInfo> {
+        self.check_required(&request, context.as_ref()).await?;
+        if request.authentication_type() == AuthenticationType::OauthClientCredentials {
+            return Err(Error::invalid_argument(
+                "only token authentication is supported for providers",
+            ));
+        }
+        let profile = parse_profile(&request.recipient_profile_str)?;
+        let resource = ProviderInfo {
+            name: request.name,
+            authentication_type: AuthenticationType::Token as i32,
+            comment: request.comment,
+            properties: request.properties,
+            recipient_profile: Some(recipient_profile(&profile)),
+            created_at: Some(chrono::Utc::now().timestamp_millis()),
+            created_by: context.recipient().user_name().map(ToString::to_string),
+            ..Default::default()
+        };
+        let secret_name = profile_secret_name(&resource.name);
+        let secret = serde_json::to_vec(&profile)?;
+        // the provider is created first, so a name that is taken fails before the
+        // secret of the existing provider is touched.
+        let (resource, _) = self.create(resource.into()).await?;
+        let stored = match self
+            .create_secret(&secret_name, secret.clone().into())
+            .await
+        {
+            // a secret left behind by a failed attempt is overwritten.
+            Err(Error::AlreadyExists) => self.update_secret(&secret_name, secret.into()).await,
+            result => result,
+        };
+        if let Err(e) = stored {
+            if let Err(cleanup) = self.delete(&resource.resource_ident()).await {
+                tracing::error!("failed to remove provider without a stored profile: {cleanup}");
+            }
+            return Err(e);
+        }
+        resource.try_into()
+    }
+
+    async fn delete_provider(
+        &self,
+        request: DeleteProviderRequest,
+        context: RequestContext,
+    ) -> Result<()> {
+        self.check_required(&request, context.as_ref()).await?;
+        let catalogs = shared_catalogs(self, &request.name).await?;
+        if !catalogs.is_empty() {
+            return Err(Error::invalid_argument(format!(
+                "provider '{}' is used by catalogs: {}",
+                request.name,
+                catalogs.join(", ")
+            )));
+        }
+        match self
+            .delete_secret(&profile_secret_name(&request.name))
+            .await
+        {
+            // Delete the resource even if the secret is not found to allow cleanup
+            // when the secret is deleted manually.
+            Ok(_) | Err(Error::NotFound) => self.delete(&request.resource()).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_provider(
+        &self,
+        request: GetProviderRequest,
+        context: RequestContext,
+    ) -> Result<ProviderInfo> {
+        self.check_required(&request, context.recipient()).await?;
+        self.get(&request.resource()).await?.0.try_into()
+    }
+
+    async fn list_providers(
+        &self,
+        request: ListProvidersRequest,
+        context: RequestContext,
+    ) -> Result<ListProvidersResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        let (mut resources, next_page_token) = self
+            .list(
+                &ObjectLabel::ProviderInfo,
+                None,
+                request.max_results.map(|v| v as usize),
+                request.page_token,
+            )
+            .await?;
+        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        Ok(ListProvidersResponse {
+            providers: resources.into_iter().map(|r| r.try_into()).try_collect()?,
+            next_page_token,
+        })
+    }
+
+    async fn update_provider(
+        &self,
+        request: UpdateProviderRequest,
+        context: RequestContext,
+    ) -> Result<ProviderInfo> {
+        self.check_required(&request, context.as_ref()).await?;
+        let (current, _) = self.get(&request.resource()).await?;
+        let ident = current.resource_ident();
+        let mut info: ProviderInfo = current.try_into()?;
+        if let Some(profile) = request.recipient_profile_str {
+            // providers rotate their tokens by issuing a new profile.
+            let profile = parse_profile(&profile)?;
+            self.update_secret(
+                &profile_secret_name(&info.name),
+                serde_json::to_vec(&profile)?.into(),
+            )
+            .await?;
+            info.recipient_profile = Some(recipient_profile(&profile));
+        }
+        if let Some(comment) = request.comment {
+            info.comment = Some(comment);
+        }
+        if let Some(properties) = request.properties {
+            info.properties = Some(properties);
+        }
+        if let Some(owner) = request.owner {
+            info.owner = Some(owner);
+        }
+        info.updated_at = Some(chrono::Utc::now().timestamp_millis());
+        info.updated_by = context.recipient().user_name().map(ToString::to_string);
+        self.update(&ident, info.into()).await?.0.try_into()
+    }
+
+    async fn list_provider_shares(
+        &self,
+        request: ListProviderSharesRequest,
+        context: RequestContext,
+    ) -> Result<ListProviderSharesResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        // make sure the provider exists, the secret alone may be a leftover.
+        self.get(&request.resource()).await?;
+        let client = provider_client(self, &request.name).await?;
+        let page = client
+            .list_shares(request.max_results, request.page_token)
+            .await?;
+        Ok(ListProviderSharesResponse {
+            shares: page
+                .items
+                .into_iter()
+                .map(|share| ProviderShare { name: share.name })
+                .collect(),
+            next_page_token: page.next_page_token,
+        })
+    }
+}
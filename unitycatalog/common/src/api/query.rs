@@ -0,0 +1,155 @@
+//! Ad-hoc SQL queries over the tables in the catalog.
+use std::time::Duration;
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::compute::concat_batches;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::reader::StreamReader;
+use datafusion::error::DataFusionError;
+use datafusion::execution::SendableRecordBatchStream;
+use reqwest::header::ACCEPT;
+
+use super::{RequestContext, SecuredAction};
+pub use crate::models::query::v1::{PreviewTableRequest, QueryRequest};
+use crate::resources::{ResourceIdent, ResourceName};
+use crate::services::policy::Permission;
+use crate::{Error, Result};
+
+/// Maximum number of rows returned by a query.
+pub const MAX_QUERY_ROWS: usize = 10_000;
+
+/// Time limit of queries that do not specify one.
+pub const DEFAULT_QUERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum time limit of a query.
+pub const MAX_QUERY_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Number of rows in a table preview that does not specify a limit.
+pub const DEFAULT_PREVIEW_ROWS: usize = 100;
+
+/// Maximum number of rows in a table preview.
+pub const MAX_PREVIEW_ROWS: usize = 1_000;
+
+/// Media type of results encoded in the Arrow IPC streaming format.
+pub const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+/// Media type of results encoded as one JSON object per row.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+impl QueryRequest {
+    /// The effective row limit of the query, at most [`MAX_QUERY_ROWS`].
+    pub fn row_limit(&self) -> usize {
+        self.max_rows
+            .and_then(|rows| usize::try_from(rows).ok())
+            .unwrap_or(MAX_QUERY_ROWS)
+            .min(MAX_QUERY_ROWS)
+    }
+
+    /// The effective time limit of the query, at most [`MAX_QUERY_TIMEOUT`].
+    pub fn time_limit(&self) -> Duration {
+        self.timeout_seconds
+            .and_then(|seconds| u64::try_from(seconds).ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_QUERY_TIMEOUT)
+            .min(MAX_QUERY_TIMEOUT)
+    }
+}
+
+impl PreviewTableRequest {
+    /// The effective row limit of the preview, at most [`MAX_PREVIEW_ROWS`].
+    pub fn row_limit(&self) -> usize {
+        self.limit
+            .and_then(|rows| usize::try_from(rows).ok())
+            .unwrap_or(DEFAULT_PREVIEW_ROWS)
+            .min(MAX_PREVIEW_ROWS)
+    }
+}
+
+impl SecuredAction for PreviewTableRequest {
+    fn resource(&self) -> ResourceIdent {
+        ResourceIdent::table(ResourceName::from_naive_str_split(&self.full_name))
+    }
+
+    fn permission(&self) -> &'static Permission {
+        &Permission::Select
+    }
+}
+
+#[async_trait::async_trait]
+pub trait QueryHandler: Send + Sync + 'static {
+    /// Execute a query and stream its results.
+    ///
+    /// The recipient must be granted `Select` on every table referenced in the query.
+    /// The returned stream ends with an error if the time limit of the query is exceeded.
+    async fn execute_query(
+        &self,
+        request: QueryRequest,
+        context: RequestContext,
+    ) -> Result<SendableRecordBatchStream>;
+
+    /// Plan a query without executing it, and return the schema of its results.
+    ///
+    /// Access to the referenced tables is checked as for [`execute_query`](Self::execute_query).
+    async fn query_schema(
+        &self,
+        request: QueryRequest,
+        context: RequestContext,
+    ) -> Result<SchemaRef>;
+
+    /// Read the first rows of a delta table.
+    ///
+    /// The rows are read from the latest snapshot of the table, without planning a query.
+    /// The recipient must be granted `Select` on the table.
+    async fn preview_table(
+        &self,
+        request: PreviewTableRequest,
+        context: RequestContext,
+    ) -> Result<SendableRecordBatchStream>;
+}
+
+/// Client for the query endpoints, which stream results instead of returning JSON.
+#[derive(Clone)]
+pub struct QueryClient {
+    client: ::cloud_client::CloudClient,
+    base_url: ::url::Url,
+}
+
+impl QueryClient {
+    pub fn new(client: ::cloud_client::CloudClient, base_url: ::url::Url) -> Self {
+        Self { client, base_url }
+    }
+
+    /// Read the first rows of a delta table.
+    ///
+    /// The rows are requested in the Arrow IPC streaming format and returned as a single batch.
+    pub async fn preview_table(&self, req: &PreviewTableRequest) -> Result<RecordBatch> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .map_err(|_| Error::invalid_argument("base url cannot have path segments"))?
+            .pop_if_empty()
+            .extend(["tables", &req.full_name, "preview"]);
+        if let Some(limit) = req.limit {
+            url.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+        if !req.columns.is_empty() {
+            url.query_pairs_mut()
+                .append_pair("columns", &req.columns.join(","));
+        }
+        let result = self
+            .client
+            .get(url)
+            .header(ACCEPT, ARROW_STREAM_CONTENT_TYPE)
+            .send()
+            .await?;
+        result.error_for_status_ref()?;
+        let result = result.bytes().await?;
+        let reader = StreamReader::try_new(std::io::Cursor::new(result), None)
+            .map_err(DataFusionError::from)?;
+        let schema = reader.schema();
+        let batches = reader
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(DataFusionError::from)?;
+        Ok(concat_batches(&schema, &batches).map_err(DataFusionError::from)?)
+    }
+}
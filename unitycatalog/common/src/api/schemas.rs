@@ -6,6 +6,10 @@ use crate::models::ObjectLabel;
 use crate::models::schemas::v1::*;
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef, ResourceStore};
 use crate::services::policy::{Permission, Policy, Recipient, process_resources};
+use crate::services::system::{
+    INFORMATION_SCHEMA, SYSTEM_CATALOG, catalog_name, ensure_writable, information_schema_info,
+    is_system_catalog,
+};
 use crate::{Error, Result};
 
 rest_handlers!(
@@ -73,6 +77,7 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
         request: CreateSchemaRequest,
         context: RequestContext,
     ) -> Result<SchemaInfo> {
+        ensure_writable(&request.catalog_name)?;
         self.check_required(&request, context.as_ref()).await?;
         let resource = SchemaInfo {
             full_name: Some(format!("{}.{}", request.catalog_name, request.name)),
@@ -80,6 +85,7 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
             catalog_name: request.catalog_name,
             comment: request.comment,
             properties: request.properties,
+            storage_root: request.storage_root,
             ..Default::default()
         };
         // TODO:
@@ -93,6 +99,7 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
         request: DeleteSchemaRequest,
         context: RequestContext,
     ) -> Result<()> {
+        ensure_writable(catalog_name(&request.full_name))?;
         self.check_required(&request, context.as_ref()).await?;
         self.delete(&request.resource()).await
     }
@@ -103,6 +110,12 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
         context: RequestContext,
     ) -> Result<ListSchemasResponse> {
         self.check_required(&request, context.as_ref()).await?;
+        if is_system_catalog(&request.catalog_name) {
+            return Ok(ListSchemasResponse {
+                schemas: vec![information_schema_info()],
+                next_page_token: None,
+            });
+        }
         let (mut resources, next_page_token) = self
             .list(
                 &ObjectLabel::SchemaInfo,
@@ -124,6 +137,9 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
         context: RequestContext,
     ) -> Result<SchemaInfo> {
         self.check_required(&request, context.as_ref()).await?;
+        if request.full_name == format!("{SYSTEM_CATALOG}.{INFORMATION_SCHEMA}") {
+            return Ok(information_schema_info());
+        }
         self.get(&request.resource()).await?.0.try_into()
     }
 
@@ -132,6 +148,7 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
         request: UpdateSchemaRequest,
         context: RequestContext,
     ) -> Result<SchemaInfo> {
+        ensure_writable(catalog_name(&request.full_name))?;
         self.check_required(&request, context.as_ref()).await?;
         let ident = request.resource();
         let name = ResourceName::from_naive_str_split(request.full_name);
@@ -140,11 +157,14 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
                 "Invalid schema name - expected <catalog_name>.<schema_name>",
             ));
         };
+        // the storage root is fixed at creation time and must survive updates.
+        let current: SchemaInfo = self.get(&ident).await?.0.try_into()?;
         let resource = SchemaInfo {
             name: request.new_name.clone(),
             comment: request.comment,
             properties: request.properties,
             catalog_name: catalog_name.to_owned(),
+            storage_root: current.storage_root,
             full_name: Some(format!("{}.{}", catalog_name, request.new_name)),
             ..Default::default()
         };
@@ -1,18 +1,47 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use delta_kernel::Version;
 use delta_kernel::schema::{DataType, PrimitiveType, Schema, StructField};
+use delta_kernel_datafusion::TableSnapshot;
+use futures_util::{StreamExt, stream};
 use itertools::Itertools;
 use unitycatalog_derive::rest_handlers;
 
 use super::{RequestContext, SecuredAction};
-use crate::models::ObjectLabel;
+use crate::models::catalogs::v1::CatalogInfo;
+use crate::models::google::protobuf::Value;
+use crate::models::google::protobuf::value::Kind;
+use crate::models::schemas::v1::SchemaInfo;
 use crate::models::tables::v1::*;
-use crate::resources::{ResourceIdent, ResourceName, ResourceRef, ResourceStore};
+use crate::models::{AssociationLabel, ObjectLabel};
+use crate::resources::{
+    NamePattern, ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore,
+};
 use crate::services::StorageLocationUrl;
 use crate::services::kernel::TableManager;
 use crate::services::policy::{Permission, Policy, Recipient, process_resources};
+use crate::services::sql::SqlQuery;
+use crate::services::system::{
+    INFORMATION_SCHEMA, catalog_name, ensure_writable, find_system_table, is_system_catalog,
+    system_tables,
+};
 use crate::{Error, Result};
 
 const MAX_RESULTS_TABLES: usize = 50;
 
+/// Prefix of the table properties that mirror the configuration of a delta table.
+const DELTA_PROPERTY_PREFIX: &str = "delta.";
+
+/// Table property holding the delta table version the stored metadata was taken from.
+const LAST_UPDATE_VERSION: &str = "delta.lastUpdateVersion";
+
+/// Maximum number of commits returned per history request.
+const MAX_RESULTS_HISTORY: usize = 100;
+
+/// Maximum number of delta logs read concurrently when listing tables.
+const MAX_CONCURRENT_SNAPSHOTS: usize = 8;
+
 rest_handlers!(
     TablesHandler, "tables", [
         ListTableSummariesRequest, Table, Read, ListTableSummariesResponse with [
@@ -20,7 +49,7 @@ rest_handlers!(
             schema_name_pattern: query as Option<String>,
             table_name_pattern: query as Option<String>,
             include_manifest_capabilities: query as Option<bool>,
-        ];
+        ] at "table-summaries";
         ListTablesRequest, Table, Read, ListTablesResponse with [
             catalog_name: query as String,
             schema_name: query as String,
@@ -44,6 +73,18 @@ rest_handlers!(
         DeleteTableRequest, Table, Write with [
             full_name: path as String,
         ];
+        UndropTableRequest, Table, Write, TableInfo with [
+            full_name: path as String,
+        ] at "tables/{full_name}/undrop";
+        GetTableHistoryRequest, Table, Read, GetTableHistoryResponse with [
+            full_name: path as String,
+            start_version: query as Option<i64>,
+            end_version: query as Option<i64>,
+            max_results: query as Option<i32>,
+        ] at "tables/{full_name}/history";
+        GetTableDetailRequest, Table, Read, TableDetail with [
+            full_name: path as String,
+        ] at "tables/{full_name}/detail";
     ]
 );
 
@@ -90,6 +131,30 @@ pub trait TablesHandler: Send + Sync + 'static {
         request: DeleteTableRequest,
         context: RequestContext,
     ) -> Result<()>;
+
+    /// Restore a dropped managed table.
+    ///
+    /// Managed tables can be restored until their data is purged,
+    /// see [`purge_dropped_tables`].
+    async fn undrop_table(
+        &self,
+        request: UndropTableRequest,
+        context: RequestContext,
+    ) -> Result<TableInfo>;
+
+    /// Get the commit history of a delta table, latest commit first.
+    async fn get_table_history(
+        &self,
+        request: GetTableHistoryRequest,
+        context: RequestContext,
+    ) -> Result<GetTableHistoryResponse>;
+
+    /// Get statistics about the data files in the latest version of a delta table.
+    async fn get_table_detail(
+        &self,
+        request: GetTableDetailRequest,
+        context: RequestContext,
+    ) -> Result<TableDetail>;
 }
 
 #[async_trait::async_trait]
@@ -100,11 +165,35 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
         context: RequestContext,
     ) -> Result<ListTableSummariesResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        // TODO: handle like operators for schema and table name
+        let pattern = |p: Option<&String>| p.filter(|p| !p.is_empty()).map(NamePattern::new);
+        if is_system_catalog(&request.catalog_name) {
+            let matches = |pattern: &Option<NamePattern>, name: &str| {
+                pattern.as_ref().is_none_or(|pattern| pattern.matches(name))
+            };
+            let schema_pattern = pattern(request.schema_name_pattern.as_ref());
+            let table_pattern = pattern(request.table_name_pattern.as_ref());
+            return Ok(ListTableSummariesResponse {
+                tables: system_tables()?
+                    .into_iter()
+                    .filter(|info| {
+                        matches(&schema_pattern, &info.schema_name)
+                            && matches(&table_pattern, &info.name)
+                    })
+                    .map(|info| info.into())
+                    .collect(),
+                next_page_token: None,
+            });
+        }
+        let patterns = [
+            None,
+            pattern(request.schema_name_pattern.as_ref()),
+            pattern(request.table_name_pattern.as_ref()),
+        ];
         let (mut resources, next_page_token) = self
-            .list(
+            .list_matching(
                 &ObjectLabel::TableInfo,
                 Some(&ResourceName::new([&request.catalog_name])),
+                &patterns,
                 request.max_results.map(|v| v as usize),
                 request.page_token,
             )
@@ -112,7 +201,11 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
         process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
         let infos: Vec<TableInfo> = resources.into_iter().map(|r| r.try_into()).try_collect()?;
         Ok(ListTableSummariesResponse {
-            tables: infos.into_iter().map(|r| r.into()).collect(),
+            tables: infos
+                .into_iter()
+                .filter(|info| info.deleted_at.is_none())
+                .map(|r| r.into())
+                .collect(),
             next_page_token,
         })
     }
@@ -124,7 +217,17 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
     ) -> Result<ListTablesResponse> {
         // TODO: assert max_results is within bounds <= 50
         self.check_required(&request, context.as_ref()).await?;
-        // TODO: handle like operators for schema and table name
+        if is_system_catalog(&request.catalog_name) {
+            let tables = if request.schema_name == INFORMATION_SCHEMA {
+                system_tables()?
+            } else {
+                Vec::new()
+            };
+            return Ok(ListTablesResponse {
+                tables,
+                next_page_token: None,
+            });
+        }
         let (mut resources, next_page_token) = self
             .list(
                 &ObjectLabel::TableInfo,
@@ -139,8 +242,26 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
             )
             .await?;
         process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let infos: Vec<TableInfo> = resources.into_iter().map(|r| r.try_into()).try_collect()?;
+        let mut tables: Vec<TableInfo> = infos
+            .into_iter()
+            .filter(|info| info.deleted_at.is_none())
+            .collect();
+        if request.include_delta_metadata() {
+            tables = stream::iter(tables)
+                .map(|mut info| async move {
+                    if let Some(snapshot) = latest_delta_snapshot(self, &info).await {
+                        info.delta_metadata =
+                            delta_table_metadata(self, &info, snapshot.as_ref()).await;
+                    }
+                    info
+                })
+                .buffered(MAX_CONCURRENT_SNAPSHOTS)
+                .collect()
+                .await;
+        }
         Ok(ListTablesResponse {
-            tables: resources.into_iter().map(|r| r.try_into()).try_collect()?,
+            tables,
             next_page_token,
         })
     }
@@ -150,16 +271,26 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
         request: CreateTableRequest,
         context: RequestContext,
     ) -> Result<TableInfo> {
+        ensure_writable(&request.catalog_name)?;
         self.check_required(&request, context.as_ref()).await?;
+        if request.table_type == TableType::View as i32 {
+            return create_view(self, request, context.as_ref()).await;
+        }
+        if request.view_definition.is_some() {
+            return Err(Error::invalid_argument(
+                "only views can specify a view definition",
+            ));
+        }
+        if request.table_type == TableType::Managed as i32 {
+            return create_managed_table(self, request).await;
+        }
         let info = if request.table_type == TableType::External as i32 {
             let Some(location) = request.storage_location.as_ref() else {
                 return Err(Error::invalid_argument("missing storage location"));
             };
             let location = StorageLocationUrl::parse(location)?;
-            let snapshot = self
-                .read_snapshot(&location, &request.data_source_format(), None)
-                .await?;
-            TableInfo {
+            let format = request.data_source_format();
+            let mut info = TableInfo {
                 name: request.name,
                 catalog_name: request.catalog_name,
                 schema_name: request.schema_name,
@@ -168,12 +299,24 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
                 properties: request.properties,
                 storage_location: request.storage_location,
                 comment: request.comment,
-                columns: schema_to_columns(
-                    snapshot.schema().as_ref(),
-                    snapshot.metadata().partition_columns(),
-                )?,
                 ..Default::default()
+            };
+            match format {
+                DataSourceFormat::Parquet | DataSourceFormat::Csv | DataSourceFormat::Json => {
+                    let schema = self.infer_schema(&location, &format).await?;
+                    info.columns = schema_to_columns(&schema, &[])?;
+                }
+                DataSourceFormat::Iceberg => {
+                    let table = self.read_iceberg_table(&location).await?;
+                    info.columns =
+                        schema_to_columns(&table.schema()?, &table.partition_columns()?)?;
+                }
+                _ => {
+                    let snapshot = self.read_snapshot(&location, &format, None).await?;
+                    apply_snapshot(&mut info, snapshot.as_ref())?;
+                }
             }
+            info
         } else {
             TableInfo {
                 name: request.name,
@@ -199,8 +342,24 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
         context: RequestContext,
     ) -> Result<TableInfo> {
         self.check_required(&request, context.as_ref()).await?;
-        // TODO: get columns etc ...
-        self.get(&request.resource()).await?.0.try_into()
+        if let Some(table) = find_system_table(&request.full_name) {
+            return table.info();
+        }
+        let mut info = get_active_table(self, &request.resource()).await?;
+        if info.data_source_format == DataSourceFormat::Iceberg as i32 {
+            return Ok(with_iceberg_metadata(self, info).await);
+        }
+        // stored metadata is refreshed by `sync_delta_tables`, the delta log is only
+        // read when live metadata is requested.
+        if !request.include_delta_metadata() {
+            return Ok(info);
+        }
+        let Some(snapshot) = latest_delta_snapshot(self, &info).await else {
+            return Ok(info);
+        };
+        apply_snapshot(&mut info, snapshot.as_ref())?;
+        info.delta_metadata = delta_table_metadata(self, &info, snapshot.as_ref()).await;
+        Ok(info)
     }
 
     async fn get_table_exists(
@@ -209,7 +368,10 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
         context: RequestContext,
     ) -> Result<GetTableExistsResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        match self.get(&request.resource()).await {
+        if find_system_table(&request.full_name).is_some() {
+            return Ok(GetTableExistsResponse { table_exists: true });
+        }
+        match get_active_table(self, &request.resource()).await {
             Ok(_) => Ok(GetTableExistsResponse { table_exists: true }),
             Err(Error::NotFound) => Ok(GetTableExistsResponse {
                 table_exists: false,
@@ -218,14 +380,699 @@ impl<T: ResourceStore + Policy + TableManager> TablesHandler for T {
         }
     }
 
+    async fn get_table_history(
+        &self,
+        request: GetTableHistoryRequest,
+        context: RequestContext,
+    ) -> Result<GetTableHistoryResponse> {
+        self.check_required(&request, context.as_ref()).await?;
+        let version = |v: Option<i64>| {
+            v.map(|v| {
+                Version::try_from(v)
+                    .map_err(|_| Error::invalid_argument("versions must not be negative"))
+            })
+            .transpose()
+        };
+        let start_version = version(request.start_version)?.unwrap_or(0);
+        let end_version = version(request.end_version)?;
+        let max_results = request.max_results.map_or(MAX_RESULTS_HISTORY, |v| {
+            v.clamp(1, MAX_RESULTS_HISTORY as i32) as usize
+        });
+
+        let info = get_active_table(self, &request.resource()).await?;
+        if info.data_source_format() != DataSourceFormat::Delta {
+            return Err(Error::invalid_argument(
+                "history is only available for delta tables",
+            ));
+        }
+        let Some(location) = info.storage_location.as_deref() else {
+            return Err(Error::invalid_argument("missing storage location"));
+        };
+        let location = StorageLocationUrl::parse(location)?;
+        let snapshot = self
+            .read_snapshot(&location, &DataSourceFormat::Delta, None)
+            .await?;
+        let end_version = end_version.map_or(snapshot.version(), |v| v.min(snapshot.version()));
+        if start_version > end_version {
+            return Ok(GetTableHistoryResponse::default());
+        }
+
+        let versions: Vec<Version> = (start_version..=end_version)
+            .rev()
+            .take(max_results)
+            .collect();
+        let next_end_version = versions
+            .last()
+            .filter(|v| **v > start_version)
+            .map(|v| (v - 1) as i64);
+        Ok(GetTableHistoryResponse {
+            commits: self.read_commits(&location, &versions).await?,
+            next_end_version,
+        })
+    }
+
+    async fn get_table_detail(
+        &self,
+        request: GetTableDetailRequest,
+        context: RequestContext,
+    ) -> Result<TableDetail> {
+        self.check_required(&request, context.as_ref()).await?;
+        let info = get_active_table(self, &request.resource()).await?;
+        if info.data_source_format() != DataSourceFormat::Delta {
+            return Err(Error::invalid_argument(
+                "table details are only available for delta tables",
+            ));
+        }
+        let Some(location) = info.storage_location.as_deref() else {
+            return Err(Error::invalid_argument("missing storage location"));
+        };
+        let location = StorageLocationUrl::parse(location)?;
+        let snapshot = self
+            .read_snapshot(&location, &DataSourceFormat::Delta, None)
+            .await?;
+        let detail = self
+            .read_table_detail(&location, snapshot.version())
+            .await?;
+        Ok(TableDetail {
+            full_name: request.full_name,
+            ..detail
+        })
+    }
+
     async fn delete_table(
         &self,
         request: DeleteTableRequest,
         context: RequestContext,
     ) -> Result<()> {
+        ensure_writable(catalog_name(&request.full_name))?;
+        self.check_required(&request, context.as_ref()).await?;
+        let ident = request.resource();
+        let mut info = get_active_table(self, &ident).await?;
+        if info.table_type != TableType::Managed as i32 {
+            return self.delete(&ident).await;
+        }
+        // managed tables are only marked as dropped and purged after the retention period.
+        info.deleted_at = Some(Utc::now().timestamp_millis());
+        self.update(&ident, info.into()).await?;
+        Ok(())
+    }
+
+    async fn undrop_table(
+        &self,
+        request: UndropTableRequest,
+        context: RequestContext,
+    ) -> Result<TableInfo> {
+        ensure_writable(catalog_name(&request.full_name))?;
         self.check_required(&request, context.as_ref()).await?;
-        self.delete(&request.resource()).await
+        let ident = request.resource();
+        match get_active_table(self, &ident).await {
+            Ok(_) => {
+                return Err(Error::invalid_argument(format!(
+                    "table '{}' is not dropped",
+                    request.full_name
+                )));
+            }
+            Err(Error::NotFound) => {}
+            Err(e) => return Err(e),
+        }
+        let mut info = find_dropped_table(self, &request.full_name).await?;
+        let dropped = ResourceIdent::table(info.resource_ref());
+        info.deleted_at = None;
+        self.update(&dropped, info.into()).await?.0.try_into()
+    }
+}
+
+/// Get a table, treating dropped tables as not found.
+pub(crate) async fn get_active_table<T: ResourceStore>(
+    handler: &T,
+    ident: &ResourceIdent,
+) -> Result<TableInfo> {
+    let info: TableInfo = handler.get(ident).await?.0.try_into()?;
+    if info.deleted_at.is_some() {
+        return Err(Error::NotFound);
+    }
+    Ok(info)
+}
+
+/// Find the most recently dropped table that was registered under `full_name`.
+async fn find_dropped_table<T: ResourceStore>(handler: &T, full_name: &str) -> Result<TableInfo> {
+    let parts: Vec<_> = full_name.splitn(3, '.').collect();
+    let [catalog_name, schema_name, name] = parts.as_slice() else {
+        return Err(Error::invalid_argument(format!(
+            "invalid table name '{full_name}'"
+        )));
+    };
+    let parent = ResourceName::new([catalog_name, schema_name]);
+    let mut dropped: Option<TableInfo> = None;
+    let mut page_token = None;
+    loop {
+        let (tables, next_page_token) = handler
+            .list(&ObjectLabel::TableInfo, Some(&parent), None, page_token)
+            .await?;
+        for table in tables {
+            let info: TableInfo = table.try_into()?;
+            let Some(deleted_at) = info.deleted_at else {
+                continue;
+            };
+            if info.name.eq_ignore_ascii_case(name)
+                && dropped
+                    .as_ref()
+                    .is_none_or(|d| d.deleted_at.unwrap_or_default() < deleted_at)
+            {
+                dropped = Some(info);
+            }
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return dropped.ok_or(Error::NotFound),
+        }
+    }
+}
+
+/// Read the latest snapshot of a delta table.
+///
+/// Returns `None` for tables that are not stored as delta tables or whose
+/// delta log cannot be read.
+async fn latest_delta_snapshot<T: TableManager>(
+    handler: &T,
+    info: &TableInfo,
+) -> Option<Arc<dyn TableSnapshot>> {
+    if info.data_source_format != DataSourceFormat::Delta as i32 {
+        return None;
+    }
+    let location = info.storage_location.as_deref()?;
+    let snapshot = match StorageLocationUrl::parse(location) {
+        Ok(location) => {
+            handler
+                .read_snapshot(&location, &DataSourceFormat::Delta, None)
+                .await
+        }
+        Err(e) => Err(e),
+    };
+    snapshot
+        .inspect_err(|e| {
+            tracing::warn!(
+                "failed to read snapshot of table '{}.{}.{}': {e}",
+                info.catalog_name,
+                info.schema_name,
+                info.name
+            )
+        })
+        .ok()
+}
+
+/// Set the columns and iceberg metadata of a table from its current metadata file.
+///
+/// If the metadata cannot be read, the table is returned as stored.
+async fn with_iceberg_metadata<T: TableManager>(handler: &T, mut info: TableInfo) -> TableInfo {
+    let result = async {
+        let location = info
+            .storage_location
+            .as_deref()
+            .ok_or_else(|| Error::invalid_argument("missing storage location"))?;
+        let table = handler
+            .read_iceberg_table(&StorageLocationUrl::parse(location)?)
+            .await?;
+        let columns = schema_to_columns(&table.schema()?, &table.partition_columns()?)?;
+        Ok::<_, Error>((columns, table.table_metadata()?))
+    };
+    match result.await {
+        Ok((columns, metadata)) => {
+            info.columns = columns;
+            info.iceberg_metadata = Some(metadata);
+        }
+        Err(e) => tracing::warn!(
+            "failed to read iceberg metadata of table '{}.{}.{}': {e}",
+            info.catalog_name,
+            info.schema_name,
+            info.name
+        ),
+    }
+    info
+}
+
+/// Describe the state of a delta table at the version of the given snapshot.
+///
+/// Returns `None` if the data files of the table cannot be listed.
+async fn delta_table_metadata<T: TableManager>(
+    handler: &T,
+    info: &TableInfo,
+    snapshot: &dyn TableSnapshot,
+) -> Option<DeltaTableMetadata> {
+    let location = StorageLocationUrl::parse(info.storage_location.as_deref()?).ok()?;
+    let files = handler
+        .read_file_stats(&location, snapshot.version())
+        .await
+        .inspect_err(|e| {
+            tracing::warn!(
+                "failed to read file statistics of table '{}.{}.{}': {e}",
+                info.catalog_name,
+                info.schema_name,
+                info.name
+            )
+        })
+        .ok()?;
+    let protocol = snapshot.protocol();
+    let metadata = snapshot.metadata();
+    Some(DeltaTableMetadata {
+        version: snapshot.version() as i64,
+        min_reader_version: protocol.min_reader_version(),
+        min_writer_version: protocol.min_writer_version(),
+        reader_features: feature_names(protocol.reader_features()),
+        writer_features: feature_names(protocol.writer_features()),
+        properties: metadata.configuration().clone(),
+        partition_columns: metadata.partition_columns().to_vec(),
+        num_files: files.num_files,
+        size_in_bytes: files.size_in_bytes,
+    })
+}
+
+fn feature_names<F: ToString>(features: Option<&[F]>) -> Vec<String> {
+    features
+        .unwrap_or_default()
+        .iter()
+        .map(|f| f.to_string())
+        .collect()
+}
+
+/// Set the columns and delta properties of a table from a snapshot.
+///
+/// All `delta.*` properties are replaced by the configuration of the snapshot,
+/// so properties removed from the delta log are removed from the table as well.
+/// The version of the snapshot is recorded as `delta.lastUpdateVersion`.
+fn apply_snapshot(info: &mut TableInfo, snapshot: &dyn TableSnapshot) -> Result<()> {
+    info.columns = schema_to_columns(
+        snapshot.schema().as_ref(),
+        snapshot.metadata().partition_columns(),
+    )?;
+    if let Some(properties) = info.properties.as_mut() {
+        properties
+            .fields
+            .retain(|key, _| !key.starts_with(DELTA_PROPERTY_PREFIX));
+    }
+    let version = (
+        LAST_UPDATE_VERSION.to_string(),
+        snapshot.version().to_string(),
+    );
+    let configuration = snapshot.metadata().configuration().clone();
+    let properties = info.properties.get_or_insert_default();
+    for (key, value) in configuration.into_iter().chain([version]) {
+        properties.fields.insert(
+            key,
+            Value {
+                kind: Some(Kind::StringValue(value)),
+            },
+        );
+    }
+    Ok(())
+}
+
+/// The delta table version the stored metadata of a table was taken from.
+fn last_update_version(info: &TableInfo) -> Option<Version> {
+    let value = info.properties.as_ref()?.fields.get(LAST_UPDATE_VERSION)?;
+    match &value.kind {
+        Some(Kind::StringValue(version)) => version.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Refresh the stored columns and delta properties of all delta tables from their
+/// latest snapshots.
+///
+/// Tables are only written if their latest version differs from the version their
+/// metadata was taken from. Failures for individual tables are logged and do not
+/// abort the sync.
+///
+/// # Returns
+/// The number of updated tables.
+pub async fn sync_delta_tables<T: ResourceStore + TableManager>(handler: &T) -> Result<usize> {
+    let mut updated = 0;
+    let mut page_token = None;
+    loop {
+        let (tables, next_page_token) = handler
+            .list(&ObjectLabel::TableInfo, None, None, page_token)
+            .await?;
+        for table in tables {
+            let info: TableInfo = table.try_into()?;
+            if info.deleted_at.is_some() {
+                continue;
+            }
+            let Some(snapshot) = latest_delta_snapshot(handler, &info).await else {
+                continue;
+            };
+            if last_update_version(&info) == Some(snapshot.version()) {
+                continue;
+            }
+            let mut synced = info.clone();
+            apply_snapshot(&mut synced, snapshot.as_ref())?;
+            let ident = ResourceIdent::table(ResourceName::new([
+                &info.catalog_name,
+                &info.schema_name,
+                &info.name,
+            ]));
+            match handler.update(&ident, synced.into()).await {
+                Ok(_) => updated += 1,
+                Err(e) => tracing::warn!(
+                    "failed to sync metadata of table '{}.{}.{}': {e}",
+                    info.catalog_name,
+                    info.schema_name,
+                    info.name
+                ),
+            }
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(updated),
+        }
+    }
+}
+
+/// Delete the data and catalog entries of managed tables that were dropped
+/// longer than `retention` ago.
+///
+/// Failures for individual tables are logged and do not abort the cleanup.
+///
+/// # Returns
+/// The number of purged tables.
+pub async fn purge_dropped_tables<T: ResourceStore + TableManager>(
+    handler: &T,
+    retention: Duration,
+) -> Result<usize> {
+    let cutoff = (Utc::now() - retention).timestamp_millis();
+    let mut purged = 0;
+    let mut page_token = None;
+    loop {
+        let (tables, next_page_token) = handler
+            .list(&ObjectLabel::TableInfo, None, None, page_token)
+            .await?;
+        for table in tables {
+            let info: TableInfo = table.try_into()?;
+            if info.deleted_at.is_none_or(|deleted_at| deleted_at > cutoff) {
+                continue;
+            }
+            match purge_table(handler, &info).await {
+                Ok(()) => purged += 1,
+                Err(e) => tracing::warn!(
+                    "failed to purge dropped table '{}.{}.{}': {e}",
+                    info.catalog_name,
+                    info.schema_name,
+                    info.name
+                ),
+            }
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(purged),
+        }
+    }
+}
+
+async fn purge_table<T: ResourceStore + TableManager>(handler: &T, info: &TableInfo) -> Result<()> {
+    if let Some(location) = &info.storage_location {
+        handler
+            .delete_table_data(&StorageLocationUrl::parse(location)?)
+            .await?;
+    }
+    handler
+        .delete(&ResourceIdent::table(info.resource_ref()))
+        .await
+}
+
+/// Create a managed table below the storage root of its schema or catalog.
+///
+/// The table is registered first to obtain its id, which determines the storage
+/// location `<root>/tables/<table_id>`. If the table cannot be initialised in storage,
+/// the registration is rolled back.
+async fn create_managed_table<T: ResourceStore + TableManager>(
+    handler: &T,
+    request: CreateTableRequest,
+) -> Result<TableInfo> {
+    if request.storage_location.is_some() {
+        return Err(Error::invalid_argument(
+            "managed tables must not specify a storage location",
+        ));
+    }
+    if !matches!(
+        request.data_source_format(),
+        DataSourceFormat::Delta | DataSourceFormat::Unspecified
+    ) {
+        return Err(Error::invalid_argument(
+            "managed tables must use the DELTA data source format",
+        ));
+    }
+    let root = managed_storage_root(handler, &request.catalog_name, &request.schema_name).await?;
+    let schema = columns_to_schema(&request.columns)?;
+    let partition_columns: Vec<String> = request
+        .columns
+        .iter()
+        .filter_map(|c| c.partition_index.map(|idx| (idx, c.name.clone())))
+        .sorted_by_key(|(idx, _)| *idx)
+        .map(|(_, name)| name)
+        .collect();
+
+    let info = TableInfo {
+        name: request.name,
+        catalog_name: request.catalog_name,
+        schema_name: request.schema_name,
+        table_type: request.table_type,
+        data_source_format: DataSourceFormat::Delta as i32,
+        properties: request.properties,
+        comment: request.comment,
+        ..Default::default()
+    };
+    let (resource, reference) = handler.create(info.into()).await?;
+    let ResourceRef::Uuid(table_id) = reference else {
+        return Err(Error::generic("table must be referenced by id"));
+    };
+    let ident = ResourceIdent::table(table_id);
+    let storage_location = format!("{}/tables/{}", root, table_id);
+
+    let initialize = async {
+        let mut info: TableInfo = resource.try_into()?;
+        let location = StorageLocationUrl::parse(&storage_location)?;
+        let snapshot = handler
+            .initialize_delta_table(&location, &schema, &partition_columns)
+            .await?;
+        info.table_id = Some(table_id.to_string());
+        info.storage_location = Some(storage_location);
+        apply_snapshot(&mut info, snapshot.as_ref())?;
+        handler.update(&ident, info.into()).await?.0.try_into()
+    };
+    match initialize.await {
+        Ok(info) => Ok(info),
+        Err(err) => {
+            // the location is unique to this table, so anything written there is ours.
+            if let Ok(location) = StorageLocationUrl::parse(&storage_location) {
+                if let Err(cleanup) = handler.delete_table_data(&location).await {
+                    tracing::error!(
+                        "failed to remove data of managed table {}: {}",
+                        table_id,
+                        cleanup
+                    );
+                }
+            }
+            if let Err(cleanup) = handler.delete(&ident).await {
+                tracing::error!(
+                    "failed to roll back managed table {}: {}",
+                    table_id,
+                    cleanup
+                );
+            }
+            Err(err)
+        }
+    }
+}
+
+/// Create a view after planning its definition against the tables it references.
+///
+/// The recipient must be allowed to read all referenced tables. The columns of the view
+/// are derived from the query, and each referenced table is recorded as a dependency.
+async fn create_view<T: ResourceStore + Policy>(
+    handler: &T,
+    request: CreateTableRequest,
+    recipient: &Recipient,
+) -> Result<TableInfo> {
+    let Some(definition) = request.view_definition.as_deref() else {
+        return Err(Error::invalid_argument("views require a view definition"));
+    };
+    if request.storage_location.is_some() {
+        return Err(Error::invalid_argument(
+            "views must not specify a storage location",
+        ));
+    }
+    let query = SqlQuery::parse(definition, &request.catalog_name, &request.schema_name)?;
+    let mut tables = Vec::new();
+    let mut dependencies = Vec::new();
+    for name in query.table_references()? {
+        let ident = ResourceIdent::table(name.clone());
+        handler
+            .authorize_checked(&ident, &Permission::Read, recipient)
+            .await?;
+        let table = match get_active_table(handler, &ident).await {
+            Err(Error::NotFound) => {
+                return Err(Error::invalid_argument(format!(
+                    "table '{name}' referenced by view does not exist"
+                )));
+            }
+            result => result?,
+        };
+        if table.columns.is_empty() {
+            return Err(Error::invalid_argument(format!(
+                "columns of table '{name}' referenced by view are unknown"
+            )));
+        }
+        tables.push((name.clone(), columns_to_schema(&table.columns)?));
+        dependencies.push(Dependency {
+            dependency: Some(dependency::Dependency::Table(TableDependency {
+                table_full_name: format!(
+                    "{}.{}.{}",
+                    table.catalog_name, table.schema_name, table.name
+                ),
+            })),
+        });
+    }
+    let schema = query.output_schema(&tables).await?;
+
+    let info = TableInfo {
+        name: request.name,
+        catalog_name: request.catalog_name,
+        schema_name: request.schema_name,
+        table_type: TableType::View as i32,
+        data_source_format: request.data_source_format,
+        columns: schema_to_columns(&schema, &[])?,
+        properties: request.properties,
+        comment: request.comment,
+        view_definition: request.view_definition,
+        view_dependencies: Some(DependencyList { dependencies }),
+        ..Default::default()
+    };
+    let (resource, reference) = handler.create(info.into()).await?;
+    let view = ResourceIdent::table(reference);
+    let associate = async {
+        for (name, _) in &tables {
+            handler
+                .add_association(
+                    &view,
+                    &ResourceIdent::table(name.clone()),
+                    &AssociationLabel::DependsOn,
+                    None,
+                )
+                .await?;
+        }
+        Ok::<_, Error>(())
+    };
+    if let Err(err) = associate.await {
+        if let Err(cleanup) = handler.delete(&view).await {
+            tracing::error!("failed to roll back view {}: {}", view, cleanup);
+        }
+        return Err(err);
     }
+    resource.try_into()
+}
+
+/// Resolve the storage root for managed tables in a schema.
+///
+/// The schema's storage root takes precedence over the one of its catalog.
+async fn managed_storage_root<T: ResourceStore>(
+    handler: &T,
+    catalog_name: &str,
+    schema_name: &str,
+) -> Result<String> {
+    let schema: SchemaInfo = handler
+        .get(&ResourceIdent::schema(ResourceName::new([
+            catalog_name,
+            schema_name,
+        ])))
+        .await?
+        .0
+        .try_into()?;
+    let root = match schema.storage_root {
+        Some(root) => root,
+        None => {
+            let catalog: CatalogInfo = handler
+                .get(&ResourceIdent::catalog(ResourceName::new([catalog_name])))
+                .await?
+                .0
+                .try_into()?;
+            catalog.storage_root.ok_or_else(|| {
+                Error::invalid_argument(format!(
+                    "no storage root configured for schema '{}.{}' or its catalog",
+                    catalog_name, schema_name
+                ))
+            })?
+        }
+    };
+    Ok(root.trim_end_matches('/').to_string())
+}
+
+/// Build a delta schema from the column definitions of a create table request.
+pub(crate) fn columns_to_schema(columns: &[ColumnInfo]) -> Result<Schema> {
+    if columns.is_empty() {
+        return Err(Error::invalid_argument(
+            "managed tables require at least one column",
+        ));
+    }
+    let fields: Vec<StructField> = columns
+        .iter()
+        .sorted_by_key(|c| c.position.unwrap_or(i32::MAX))
+        .map(|c| {
+            Ok::<_, Error>(StructField::new(
+                c.name.clone(),
+                column_data_type(c)?,
+                c.nullable.unwrap_or(true),
+            ))
+        })
+        .try_collect()?;
+    Ok(Schema::new(fields))
+}
+
+fn column_data_type(column: &ColumnInfo) -> Result<DataType> {
+    if !column.type_json.is_empty() {
+        // type_json is either a plain delta type or a full delta struct field.
+        if let Ok(data_type) = serde_json::from_str::<DataType>(&column.type_json) {
+            return Ok(data_type);
+        }
+        let field: StructField = serde_json::from_str(&column.type_json)?;
+        return Ok(field.data_type().clone());
+    }
+    let data_type = match column.type_name() {
+        ColumnTypeName::String | ColumnTypeName::Char => DataType::STRING,
+        ColumnTypeName::Long => DataType::LONG,
+        ColumnTypeName::Int => DataType::INTEGER,
+        ColumnTypeName::Short => DataType::SHORT,
+        ColumnTypeName::Byte => DataType::BYTE,
+        ColumnTypeName::Double => DataType::DOUBLE,
+        ColumnTypeName::Float => DataType::FLOAT,
+        ColumnTypeName::Boolean => DataType::BOOLEAN,
+        ColumnTypeName::Binary => DataType::BINARY,
+        ColumnTypeName::Date => DataType::DATE,
+        ColumnTypeName::Timestamp => DataType::TIMESTAMP,
+        ColumnTypeName::TimestampNtz => DataType::TIMESTAMP_NTZ,
+        ColumnTypeName::Decimal => {
+            let (Some(precision), Some(scale)) = (column.type_precision, column.type_scale) else {
+                return Err(Error::invalid_argument(format!(
+                    "decimal column '{}' requires precision and scale",
+                    column.name
+                )));
+            };
+            let invalid = || {
+                Error::invalid_argument(format!(
+                    "invalid precision {precision} or scale {scale} for decimal column '{}'",
+                    column.name
+                ))
+            };
+            let precision = u8::try_from(precision).map_err(|_| invalid())?;
+            let scale = u8::try_from(scale).map_err(|_| invalid())?;
+            DataType::decimal(precision, scale).map_err(|_| invalid())?
+        }
+        other => {
+            return Err(Error::invalid_argument(format!(
+                "column '{}' of type {:?} requires type_json",
+                column.name, other
+            )));
+        }
+    };
+    Ok(data_type)
 }
 
 trait FieldExt {
@@ -283,7 +1130,10 @@ impl FieldExt for StructField {
     }
 }
 
-fn schema_to_columns(schema: &Schema, partition_columns: &[String]) -> Result<Vec<ColumnInfo>> {
+pub(crate) fn schema_to_columns(
+    schema: &Schema,
+    partition_columns: &[String],
+) -> Result<Vec<ColumnInfo>> {
     let partition_index = |name: &str| partition_columns.iter().position(|n| n == name);
     schema
         .fields()
@@ -38,6 +38,12 @@ pub enum Error {
     #[error("Generic error: {0}")]
     Generic(String),
 
+    #[error("Deadline exceeded: {0}")]
+    DeadlineExceeded(String),
+
+    #[error("Upstream error: {0}")]
+    Upstream(String),
+
     #[error("Failed to extract recipient from request")]
     MissingRecipient,
 
@@ -77,6 +83,14 @@ impl Error {
         Self::InvalidArgument(msg.into())
     }
 
+    pub fn deadline_exceeded(msg: impl Into<String>) -> Self {
+        Self::DeadlineExceeded(msg.into())
+    }
+
+    pub fn upstream(msg: impl Into<String>) -> Self {
+        Self::Upstream(msg.into())
+    }
+
     pub fn invalid_predicate(msg: impl Into<String>) -> Self {
         Self::InvalidPredicate(msg.into())
     }
@@ -107,6 +121,8 @@ impl From<Error> for Status {
             Error::InvalidIdentifier(_) => Status::internal("Invalid uuid identifier"),
             Error::InvalidArgument(message) => Status::invalid_argument(message),
             Error::Generic(message) => Status::internal(message),
+            Error::DeadlineExceeded(message) => Status::deadline_exceeded(message),
+            Error::Upstream(message) => Status::unavailable(message),
             Error::Client(error) => Status::internal(error.to_string()),
             Error::InvalidUrl(_) => Status::internal("Invalid url"),
             Error::ObjectStore(_) => Status::internal("ObjectStore error"),
@@ -206,6 +222,20 @@ mod server {
                     error!("Generic error: {}", message);
                     INTERNAL_ERROR
                 }
+                Error::DeadlineExceeded(message) => {
+                    error!("Deadline exceeded: {}", message);
+                    (
+                        StatusCode::GATEWAY_TIMEOUT,
+                        "The request did not complete within its time limit.",
+                    )
+                }
+                Error::Upstream(message) => {
+                    error!("Upstream error: {}", message);
+                    (
+                        StatusCode::BAD_GATEWAY,
+                        "A remote server failed to fulfill the request.",
+                    )
+                }
                 Error::DataFusion(error) => {
                     let message = format!("DataFusion error: {}", error);
                     error!("{}", message);
@@ -0,0 +1,436 @@
+//! Arrow Flight SQL service over the catalogs, schemas and tables in the store.
+//!
+//! Metadata requests are answered from the resource store, queries are executed by the
+//! [`QueryHandler`]. Requests are authenticated by the same [`Authenticator`] as the
+//! REST API, based on the headers of the request.
+use std::pin::Pin;
+use std::sync::Arc;
+
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::sql::metadata::{SqlInfoData, SqlInfoDataBuilder};
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::{
+    CommandGetCatalogs, CommandGetDbSchemas, CommandGetSqlInfo, CommandGetTables,
+    CommandStatementQuery, ProstMessageExt, SqlInfo, TicketStatementQuery,
+};
+use arrow_flight::{
+    FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse,
+    Ticket,
+};
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::{Schema as ArrowSchema, SchemaRef};
+use datafusion::arrow::error::ArrowError;
+use delta_kernel::engine::arrow_conversion::TryFromKernel as _;
+use futures_util::{Stream, TryStreamExt};
+use prost::Message;
+use tonic::{Request, Response, Status, Streaming};
+
+pub use arrow_flight::flight_service_server::FlightServiceServer;
+
+use crate::api::RequestContext;
+use crate::api::query::{QueryHandler, QueryRequest};
+use crate::api::tables::columns_to_schema;
+use crate::models::ObjectLabel;
+use crate::models::catalogs::v1::CatalogInfo;
+use crate::models::schemas::v1::SchemaInfo;
+use crate::models::tables::v1::{TableInfo, TableType};
+use crate::rest::Authenticator;
+use crate::services::catalog::list_all;
+use crate::services::policy::Policy;
+use crate::{ResourceName, ResourceStore};
+
+type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
+
+/// Flight SQL server exposing the catalog and executing read-only queries.
+pub struct FlightSqlServer<T, A> {
+    handler: T,
+    authenticator: A,
+    sql_info: SqlInfoData,
+}
+
+impl<T, A> FlightSqlServer<T, A> {
+    pub fn new(handler: T, authenticator: A) -> Self {
+        let mut builder = SqlInfoDataBuilder::new();
+        builder.append(SqlInfo::FlightSqlServerName, "unitycatalog");
+        builder.append(SqlInfo::FlightSqlServerVersion, env!("CARGO_PKG_VERSION"));
+        builder.append(SqlInfo::FlightSqlServerSql, true);
+        builder.append(SqlInfo::FlightSqlServerReadOnly, true);
+        Self {
+            handler,
+            authenticator,
+            // all values are static and of supported types.
+            sql_info: builder.build().expect("valid sql info"),
+        }
+    }
+
+    /// Wrap the server in a tonic service.
+    pub fn into_service(self) -> FlightServiceServer<Self>
+    where
+        Self: FlightSqlService,
+    {
+        FlightServiceServer::new(self)
+    }
+}
+
+impl<T, A: Authenticator> FlightSqlServer<T, A> {
+    /// Authenticate a request based on its metadata.
+    fn authenticate<R>(&self, request: &Request<R>) -> Result<RequestContext, Status> {
+        let mut http_request = axum::extract::Request::new(axum::body::Body::empty());
+        *http_request.headers_mut() = request.metadata().clone().into_headers();
+        let recipient = self.authenticator.authenticate(&http_request)?;
+        Ok(RequestContext { recipient })
+    }
+}
+
+#[tonic::async_trait]
+impl<T, A> FlightSqlService for FlightSqlServer<T, A>
+where
+    T: QueryHandler + ResourceStore + Policy,
+    A: Authenticator,
+{
+    type FlightService = Self;
+
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        self.authenticate(&request)?;
+        let response = HandshakeResponse {
+            protocol_version: 0,
+            payload: Default::default(),
+        };
+        Ok(Response::new(Box::pin(futures_util::stream::iter([Ok(
+            response,
+        )]))))
+    }
+
+    async fn get_flight_info_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.authenticate(&request)?;
+        let schema = query.clone().into_builder().schema();
+        flight_info(&query, &schema, request.into_inner())
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        let context = self.authenticate(&request)?;
+        let mut builder = query.into_builder();
+        let catalogs = list_all(
+            &self.handler,
+            context.as_ref(),
+            &ObjectLabel::CatalogInfo,
+            None,
+        )
+        .await?;
+        for catalog in catalogs {
+            let info: CatalogInfo = catalog.try_into()?;
+            builder.append(info.name);
+        }
+        Ok(batch_response(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.authenticate(&request)?;
+        let schema = query.clone().into_builder().schema();
+        flight_info(&query, &schema, request.into_inner())
+    }
+
+    async fn do_get_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        let context = self.authenticate(&request)?;
+        let namespace = query.catalog.as_ref().map(|c| ResourceName::new([c]));
+        let mut builder = query.into_builder();
+        let schemas = list_all(
+            &self.handler,
+            context.as_ref(),
+            &ObjectLabel::SchemaInfo,
+            namespace.as_ref(),
+        )
+        .await?;
+        for schema in schemas {
+            let info: SchemaInfo = schema.try_into()?;
+            builder.append(info.catalog_name, info.name);
+        }
+        Ok(batch_response(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.authenticate(&request)?;
+        let schema = query.clone().into_builder().schema();
+        flight_info(&query, &schema, request.into_inner())
+    }
+
+    async fn do_get_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        let context = self.authenticate(&request)?;
+        let namespace = query.catalog.as_ref().map(|c| ResourceName::new([c]));
+        let mut builder = query.into_builder();
+        let tables = list_all(
+            &self.handler,
+            context.as_ref(),
+            &ObjectLabel::TableInfo,
+            namespace.as_ref(),
+        )
+        .await?;
+        for table in tables {
+            let info: TableInfo = table.try_into()?;
+            if info.deleted_at.is_some() {
+                continue;
+            }
+            let table_type = if info.table_type == TableType::View as i32 {
+                "VIEW"
+            } else {
+                "TABLE"
+            };
+            // tables whose columns are unknown are listed without columns.
+            let schema = columns_to_schema(&info.columns)
+                .ok()
+                .and_then(|schema| ArrowSchema::try_from_kernel(&schema).ok())
+                .unwrap_or_else(ArrowSchema::empty);
+            builder
+                .append(
+                    &info.catalog_name,
+                    &info.schema_name,
+                    &info.name,
+                    table_type,
+                    &schema,
+                )
+                .map_err(flight_status)?;
+        }
+        Ok(batch_response(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_sql_info(
+        &self,
+        query: CommandGetSqlInfo,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.authenticate(&request)?;
+        flight_info(&query, SqlInfoData::schema(), request.into_inner())
+    }
+
+    async fn do_get_sql_info(
+        &self,
+        query: CommandGetSqlInfo,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        self.authenticate(&request)?;
+        let schema = Arc::new(SqlInfoData::schema().clone());
+        Ok(batch_response(
+            schema,
+            self.sql_info.record_batch(query.info),
+        ))
+    }
+
+    /// Plan a query to be executed when its ticket is redeemed.
+    ///
+    /// The query is planned, but not executed, to describe the schema of its results.
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let context = self.authenticate(&request)?;
+        let statement = QueryRequest {
+            statement: query.query,
+            ..Default::default()
+        };
+        let schema = self
+            .handler
+            .query_schema(statement.clone(), context)
+            .await?;
+        let ticket = TicketStatementQuery {
+            statement_handle: statement.encode_to_vec().into(),
+        };
+        let endpoint =
+            FlightEndpoint::new().with_ticket(Ticket::new(ticket.as_any().encode_to_vec()));
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(flight_status)?
+            .with_endpoint(endpoint)
+            .with_descriptor(request.into_inner());
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        let context = self.authenticate(&request)?;
+        let statement = QueryRequest::decode(ticket.statement_handle.as_ref())
+            .map_err(|_| Status::invalid_argument("invalid statement handle"))?;
+        let stream = self.handler.execute_query(statement, context).await?;
+        let schema = stream.schema();
+        let stream = FlightDataEncoderBuilder::new()
+            .with_schema(schema)
+            .build(stream.map_err(|e| FlightError::ExternalError(Box::new(e))))
+            .map_err(Status::from);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+/// Describe a flight whose ticket is the command itself.
+fn flight_info(
+    command: &impl ProstMessageExt,
+    schema: &ArrowSchema,
+    descriptor: FlightDescriptor,
+) -> Result<Response<FlightInfo>, Status> {
+    let ticket = Ticket::new(command.as_any().encode_to_vec());
+    let info = FlightInfo::new()
+        .try_with_schema(schema)
+        .map_err(flight_status)?
+        .with_endpoint(FlightEndpoint::new().with_ticket(ticket))
+        .with_descriptor(descriptor);
+    Ok(Response::new(info))
+}
+
+fn batch_response(
+    schema: SchemaRef,
+    batch: Result<RecordBatch, ArrowError>,
+) -> Response<DoGetStream> {
+    let batches = futures_util::stream::once(async move { batch.map_err(FlightError::from) });
+    let stream = FlightDataEncoderBuilder::new()
+        .with_schema(schema)
+        .build(batches)
+        .map_err(Status::from);
+    Response::new(Box::pin(stream))
+}
+
+fn flight_status(error: ArrowError) -> Status {
+    Status::internal(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_flight::decode::FlightRecordBatchStream;
+    use datafusion::arrow::array::{Array, AsArray};
+
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+    use crate::rest::AnonymousAuthenticator;
+    use crate::services::ServerHandler;
+    use crate::services::policy::ConstantPolicy;
+
+    async fn collect(response: Response<DoGetStream>) -> Vec<RecordBatch> {
+        let stream = response.into_inner().map_err(FlightError::from);
+        FlightRecordBatchStream::new_from_flight_data(stream)
+            .try_collect()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_flight_sql() {
+        let store = InMemoryResourceStore::new();
+        store
+            .create(
+                CatalogInfo {
+                    name: "main".to_string(),
+                    ..Default::default()
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+        store
+            .create(
+                SchemaInfo {
+                    name: "default".to_string(),
+                    catalog_name: "main".to_string(),
+                    full_name: Some("main.default".to_string()),
+                    ..Default::default()
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+        let handler = ServerHandler::try_new_tokio(
+            Arc::new(ConstantPolicy::default()),
+            Arc::new(store.clone()),
+            Arc::new(store),
+        )
+        .unwrap();
+        let server = FlightSqlServer::new(handler, AnonymousAuthenticator);
+
+        let response = server
+            .do_get_catalogs(CommandGetCatalogs {}, Request::new(Ticket::default()))
+            .await
+            .unwrap();
+        let batches = collect(response).await;
+        let catalogs = batches[0].column(0).as_string::<i32>();
+        assert_eq!(catalogs.len(), 1);
+        assert_eq!(catalogs.value(0), "main");
+
+        let query = CommandGetDbSchemas {
+            catalog: Some("main".to_string()),
+            db_schema_filter_pattern: None,
+        };
+        let response = server
+            .do_get_schemas(query, Request::new(Ticket::default()))
+            .await
+            .unwrap();
+        let batches = collect(response).await;
+        let schemas = batches[0].column(1).as_string::<i32>();
+        assert_eq!(schemas.value(0), "default");
+
+        let query = CommandStatementQuery {
+            query: "SELECT 1 AS one".to_string(),
+            transaction_id: None,
+        };
+        let info = server
+            .get_flight_info_statement(query, Request::new(FlightDescriptor::default()))
+            .await
+            .unwrap()
+            .into_inner();
+        let schema = info.clone().try_decode_schema().unwrap();
+        assert_eq!(schema.field(0).name(), "one");
+        let ticket = info.endpoint[0].ticket.clone().unwrap();
+        let ticket = TicketStatementQuery::decode(
+            arrow_flight::sql::Any::decode(ticket.ticket).unwrap().value,
+        )
+        .unwrap();
+        let response = server
+            .do_get_statement(ticket, Request::new(Ticket::default()))
+            .await
+            .unwrap();
+        let batches = collect(response).await;
+        assert_eq!(batches[0].num_rows(), 1);
+
+        let query = CommandStatementQuery {
+            query: "DROP TABLE main.default.events".to_string(),
+            transaction_id: None,
+        };
+        let status = server
+            .get_flight_info_statement(query, Request::new(FlightDescriptor::default()))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
@@ -64,6 +64,13 @@ pub struct AzureStorageKey {
     #[prost(string, tag="2")]
     pub account_key: ::prost::alloc::string::String,
 }
+/// Marker for storage locations that are accessed without any credential.
+///
+/// Only valid for local file system (`file://`) and in-memory (`memory://`) locations.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NoCredential {}
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -110,7 +117,7 @@ pub struct CredentialInfo {
     /// The full name of the credential.
     #[prost(string, optional, tag="12")]
     pub full_name: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(oneof="credential_info::Credential", tags="100, 101, 102")]
+    #[prost(oneof="credential_info::Credential", tags="100, 101, 102, 103")]
     pub credential: ::core::option::Option<credential_info::Credential>,
 }
 /// Nested message and enum types in `CredentialInfo`.
@@ -125,8 +132,24 @@ pub mod credential_info {
         AzureManagedIdentity(super::AzureManagedIdentity),
         #[prost(message, tag="102")]
         AzureStorageKey(super::AzureStorageKey),
+        #[prost(message, tag="103")]
+        NoCredential(super::NoCredential),
     }
 }
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CredentialValidationResult {
+    /// The operation that was probed.
+    #[prost(enumeration="ValidationOperation", tag="1")]
+    pub operation: i32,
+    /// The outcome of the probe.
+    #[prost(enumeration="ValidationResultStatus", tag="2")]
+    pub result: i32,
+    /// Error message or reason the operation was skipped.
+    #[prost(string, optional, tag="3")]
+    pub message: ::core::option::Option<::prost::alloc::string::String>,
+}
 #[cfg_attr(feature = "python", ::pyo3::pyclass)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -157,6 +180,84 @@ impl Purpose {
         }
     }
 }
+/// A storage operation that is probed when validating a credential.
+#[cfg_attr(feature = "python", ::pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValidationOperation {
+    Unspecified = 0,
+    /// List objects below the location.
+    List = 1,
+    /// Read an object below the location.
+    Read = 2,
+    /// Write a probe object below the location.
+    Write = 3,
+    /// Delete the probe object written during validation.
+    Delete = 4,
+}
+impl ValidationOperation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ValidationOperation::Unspecified => "VALIDATION_OPERATION_UNSPECIFIED",
+            ValidationOperation::List => "LIST",
+            ValidationOperation::Read => "READ",
+            ValidationOperation::Write => "WRITE",
+            ValidationOperation::Delete => "DELETE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALIDATION_OPERATION_UNSPECIFIED" => Some(ValidationOperation::Unspecified),
+            "LIST" => Some(ValidationOperation::List),
+            "READ" => Some(ValidationOperation::Read),
+            "WRITE" => Some(ValidationOperation::Write),
+            "DELETE" => Some(ValidationOperation::Delete),
+            _ => None,
+        }
+    }
+}
+/// The outcome of a single validation probe.
+#[cfg_attr(feature = "python", ::pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValidationResultStatus {
+    Unspecified = 0,
+    /// The operation succeeded.
+    Pass = 1,
+    /// The operation failed.
+    Fail = 2,
+    /// The operation was not attempted.
+    Skip = 3,
+}
+impl ValidationResultStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ValidationResultStatus::Unspecified => "VALIDATION_RESULT_STATUS_UNSPECIFIED",
+            ValidationResultStatus::Pass => "PASS",
+            ValidationResultStatus::Fail => "FAIL",
+            ValidationResultStatus::Skip => "SKIP",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALIDATION_RESULT_STATUS_UNSPECIFIED" => Some(ValidationResultStatus::Unspecified),
+            "PASS" => Some(ValidationResultStatus::Pass),
+            "FAIL" => Some(ValidationResultStatus::Fail),
+            "SKIP" => Some(ValidationResultStatus::Skip),
+            _ => None,
+        }
+    }
+}
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -202,7 +303,7 @@ pub struct CreateCredentialRequest {
     /// Supplying true to this argument skips validation of the created set of credentials.
     #[prost(bool, tag="5")]
     pub skip_validation: bool,
-    #[prost(oneof="create_credential_request::Credential", tags="100, 101, 102")]
+    #[prost(oneof="create_credential_request::Credential", tags="100, 101, 102, 103")]
     pub credential: ::core::option::Option<create_credential_request::Credential>,
 }
 /// Nested message and enum types in `CreateCredentialRequest`.
@@ -217,6 +318,8 @@ pub mod create_credential_request {
         AzureManagedIdentity(super::AzureManagedIdentity),
         #[prost(message, tag="102")]
         AzureStorageKey(super::AzureStorageKey),
+        #[prost(message, tag="103")]
+        NoCredential(super::NoCredential),
     }
 }
 /// Get a credential
@@ -255,7 +358,7 @@ pub struct UpdateCredentialRequest {
     /// or dependent external locations and external tables (when purpose is STORAGE).
     #[prost(bool, optional, tag="7")]
     pub force: ::core::option::Option<bool>,
-    #[prost(oneof="update_credential_request::Credential", tags="100, 101, 102")]
+    #[prost(oneof="update_credential_request::Credential", tags="100, 101, 102, 103")]
     pub credential: ::core::option::Option<update_credential_request::Credential>,
 }
 /// Nested message and enum types in `UpdateCredentialRequest`.
@@ -270,6 +373,8 @@ pub mod update_credential_request {
         AzureManagedIdentity(super::AzureManagedIdentity),
         #[prost(message, tag="102")]
         AzureStorageKey(super::AzureStorageKey),
+        #[prost(message, tag="103")]
+        NoCredential(super::NoCredential),
     }
 }
 /// Delete a credential
@@ -281,5 +386,38 @@ pub struct DeleteCredentialRequest {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
 }
+/// Validate a credential against a storage location
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateCredentialRequest {
+    /// Name of the credential to validate.
+    #[prost(string, tag="1")]
+    pub credential_name: ::prost::alloc::string::String,
+    /// Name of an existing external location to validate.
+    ///
+    /// Either url or external_location_name must be provided.
+    #[prost(string, optional, tag="2")]
+    pub external_location_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// The storage location url to validate.
+    #[prost(string, optional, tag="3")]
+    pub url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the location is only used for read operations.
+    ///
+    /// If set, the write and delete probes are skipped.
+    #[prost(bool, optional, tag="4")]
+    pub read_only: ::core::option::Option<bool>,
+}
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateCredentialResponse {
+    /// Whether the tested location is a directory in cloud storage.
+    #[prost(bool, optional, tag="1")]
+    pub is_dir: ::core::option::Option<bool>,
+    /// The results of the individual validation probes.
+    #[prost(message, repeated, tag="2")]
+    pub results: ::prost::alloc::vec::Vec<CredentialValidationResult>,
+}
 include!("unitycatalog.credentials.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
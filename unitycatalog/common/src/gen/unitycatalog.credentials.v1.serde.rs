@@ -443,6 +443,9 @@ impl serde::Serialize for CreateCredentialRequest {
                 create_credential_request::Credential::AzureStorageKey(v) => {
                     struct_ser.serialize_field("azureStorageKey", v)?;
                 }
+                create_credential_request::Credential::NoCredential(v) => {
+                    struct_ser.serialize_field("noCredential", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -468,6 +471,8 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
             "azureManagedIdentity",
             "azure_storage_key",
             "azureStorageKey",
+            "no_credential",
+            "noCredential",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -480,6 +485,7 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
             AzureServicePrincipal,
             AzureManagedIdentity,
             AzureStorageKey,
+            NoCredential,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -510,6 +516,7 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
                             "azureServicePrincipal" | "azure_service_principal" => Ok(GeneratedField::AzureServicePrincipal),
                             "azureManagedIdentity" | "azure_managed_identity" => Ok(GeneratedField::AzureManagedIdentity),
                             "azureStorageKey" | "azure_storage_key" => Ok(GeneratedField::AzureStorageKey),
+                            "noCredential" | "no_credential" => Ok(GeneratedField::NoCredential),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -586,6 +593,13 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
                                 return Err(serde::de::Error::duplicate_field("azureStorageKey"));
                             }
                             credential__ = map_.next_value::<::std::option::Option<_>>()?.map(create_credential_request::Credential::AzureStorageKey)
+;
+                        }
+                        GeneratedField::NoCredential => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noCredential"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(create_credential_request::Credential::NoCredential)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -707,6 +721,9 @@ impl serde::Serialize for CredentialInfo {
                 credential_info::Credential::AzureStorageKey(v) => {
                     struct_ser.serialize_field("azureStorageKey", v)?;
                 }
+                credential_info::Credential::NoCredential(v) => {
+                    struct_ser.serialize_field("noCredential", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -744,6 +761,8 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
             "azureManagedIdentity",
             "azure_storage_key",
             "azureStorageKey",
+            "no_credential",
+            "noCredential",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -763,6 +782,7 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
             AzureServicePrincipal,
             AzureManagedIdentity,
             AzureStorageKey,
+            NoCredential,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -800,6 +820,7 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                             "azureServicePrincipal" | "azure_service_principal" => Ok(GeneratedField::AzureServicePrincipal),
                             "azureManagedIdentity" | "azure_managed_identity" => Ok(GeneratedField::AzureManagedIdentity),
                             "azureStorageKey" | "azure_storage_key" => Ok(GeneratedField::AzureStorageKey),
+                            "noCredential" | "no_credential" => Ok(GeneratedField::NoCredential),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -929,6 +950,13 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                                 return Err(serde::de::Error::duplicate_field("azureStorageKey"));
                             }
                             credential__ = map_.next_value::<::std::option::Option<_>>()?.map(credential_info::Credential::AzureStorageKey)
+;
+                        }
+                        GeneratedField::NoCredential => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noCredential"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(credential_info::Credential::NoCredential)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -956,6 +984,139 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
         deserializer.deserialize_struct("unitycatalog.credentials.v1.CredentialInfo", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CredentialValidationResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.operation != 0 {
+            len += 1;
+        }
+        if self.result != 0 {
+            len += 1;
+        }
+        if self.message.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.credentials.v1.CredentialValidationResult", len)?;
+        if self.operation != 0 {
+            let v = ValidationOperation::try_from(self.operation)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.operation)))?;
+            struct_ser.serialize_field("operation", &v)?;
+        }
+        if self.result != 0 {
+            let v = ValidationResultStatus::try_from(self.result)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.result)))?;
+            struct_ser.serialize_field("result", &v)?;
+        }
+        if let Some(v) = self.message.as_ref() {
+            struct_ser.serialize_field("message", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CredentialValidationResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "operation",
+            "result",
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Operation,
+            Result,
+            Message,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "operation" => Ok(GeneratedField::Operation),
+                            "result" => Ok(GeneratedField::Result),
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CredentialValidationResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.credentials.v1.CredentialValidationResult")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CredentialValidationResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut operation__ = None;
+                let mut result__ = None;
+                let mut message__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value::<ValidationOperation>()? as i32);
+                        }
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = Some(map_.next_value::<ValidationResultStatus>()? as i32);
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CredentialValidationResult {
+                    operation: operation__.unwrap_or_default(),
+                    result: result__.unwrap_or_default(),
+                    message: message__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.credentials.v1.CredentialValidationResult", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DeleteCredentialRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1394,6 +1555,78 @@ impl<'de> serde::Deserialize<'de> for ListCredentialsResponse {
         deserializer.deserialize_struct("unitycatalog.credentials.v1.ListCredentialsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for NoCredential {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("unitycatalog.credentials.v1.NoCredential", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NoCredential {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoCredential;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.credentials.v1.NoCredential")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoCredential, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(NoCredential {
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.credentials.v1.NoCredential", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Purpose {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1533,6 +1766,9 @@ impl serde::Serialize for UpdateCredentialRequest {
                 update_credential_request::Credential::AzureStorageKey(v) => {
                     struct_ser.serialize_field("azureStorageKey", v)?;
                 }
+                update_credential_request::Credential::NoCredential(v) => {
+                    struct_ser.serialize_field("noCredential", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1561,6 +1797,8 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
             "azureManagedIdentity",
             "azure_storage_key",
             "azureStorageKey",
+            "no_credential",
+            "noCredential",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1575,6 +1813,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
             AzureServicePrincipal,
             AzureManagedIdentity,
             AzureStorageKey,
+            NoCredential,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1607,6 +1846,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                             "azureServicePrincipal" | "azure_service_principal" => Ok(GeneratedField::AzureServicePrincipal),
                             "azureManagedIdentity" | "azure_managed_identity" => Ok(GeneratedField::AzureManagedIdentity),
                             "azureStorageKey" | "azure_storage_key" => Ok(GeneratedField::AzureStorageKey),
+                            "noCredential" | "no_credential" => Ok(GeneratedField::NoCredential),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1697,6 +1937,13 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                                 return Err(serde::de::Error::duplicate_field("azureStorageKey"));
                             }
                             credential__ = map_.next_value::<::std::option::Option<_>>()?.map(update_credential_request::Credential::AzureStorageKey)
+;
+                        }
+                        GeneratedField::NoCredential => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noCredential"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(update_credential_request::Credential::NoCredential)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1719,3 +1966,422 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
         deserializer.deserialize_struct("unitycatalog.credentials.v1.UpdateCredentialRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidateCredentialRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.credential_name.is_empty() {
+            len += 1;
+        }
+        if self.external_location_name.is_some() {
+            len += 1;
+        }
+        if self.url.is_some() {
+            len += 1;
+        }
+        if self.read_only.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.credentials.v1.ValidateCredentialRequest", len)?;
+        if !self.credential_name.is_empty() {
+            struct_ser.serialize_field("credentialName", &self.credential_name)?;
+        }
+        if let Some(v) = self.external_location_name.as_ref() {
+            struct_ser.serialize_field("externalLocationName", v)?;
+        }
+        if let Some(v) = self.url.as_ref() {
+            struct_ser.serialize_field("url", v)?;
+        }
+        if let Some(v) = self.read_only.as_ref() {
+            struct_ser.serialize_field("readOnly", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidateCredentialRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "credential_name",
+            "credentialName",
+            "external_location_name",
+            "externalLocationName",
+            "url",
+            "read_only",
+            "readOnly",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CredentialName,
+            ExternalLocationName,
+            Url,
+            ReadOnly,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "credentialName" | "credential_name" => Ok(GeneratedField::CredentialName),
+                            "externalLocationName" | "external_location_name" => Ok(GeneratedField::ExternalLocationName),
+                            "url" => Ok(GeneratedField::Url),
+                            "readOnly" | "read_only" => Ok(GeneratedField::ReadOnly),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidateCredentialRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.credentials.v1.ValidateCredentialRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidateCredentialRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut credential_name__ = None;
+                let mut external_location_name__ = None;
+                let mut url__ = None;
+                let mut read_only__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CredentialName => {
+                            if credential_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("credentialName"));
+                            }
+                            credential_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExternalLocationName => {
+                            if external_location_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalLocationName"));
+                            }
+                            external_location_name__ = map_.next_value()?;
+                        }
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReadOnly => {
+                            if read_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readOnly"));
+                            }
+                            read_only__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidateCredentialRequest {
+                    credential_name: credential_name__.unwrap_or_default(),
+                    external_location_name: external_location_name__,
+                    url: url__,
+                    read_only: read_only__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.credentials.v1.ValidateCredentialRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidateCredentialResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.is_dir.is_some() {
+            len += 1;
+        }
+        if !self.results.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.credentials.v1.ValidateCredentialResponse", len)?;
+        if let Some(v) = self.is_dir.as_ref() {
+            struct_ser.serialize_field("isDir", v)?;
+        }
+        if !self.results.is_empty() {
+            struct_ser.serialize_field("results", &self.results)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidateCredentialResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "is_dir",
+            "isDir",
+            "results",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IsDir,
+            Results,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "isDir" | "is_dir" => Ok(GeneratedField::IsDir),
+                            "results" => Ok(GeneratedField::Results),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidateCredentialResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.credentials.v1.ValidateCredentialResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidateCredentialResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut is_dir__ = None;
+                let mut results__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IsDir => {
+                            if is_dir__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isDir"));
+                            }
+                            is_dir__ = map_.next_value()?;
+                        }
+                        GeneratedField::Results => {
+                            if results__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("results"));
+                            }
+                            results__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidateCredentialResponse {
+                    is_dir: is_dir__,
+                    results: results__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.credentials.v1.ValidateCredentialResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidationOperation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "VALIDATION_OPERATION_UNSPECIFIED",
+            Self::List => "LIST",
+            Self::Read => "READ",
+            Self::Write => "WRITE",
+            Self::Delete => "DELETE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidationOperation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "VALIDATION_OPERATION_UNSPECIFIED",
+            "LIST",
+            "READ",
+            "WRITE",
+            "DELETE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidationOperation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "VALIDATION_OPERATION_UNSPECIFIED" => Ok(ValidationOperation::Unspecified),
+                    "LIST" => Ok(ValidationOperation::List),
+                    "READ" => Ok(ValidationOperation::Read),
+                    "WRITE" => Ok(ValidationOperation::Write),
+                    "DELETE" => Ok(ValidationOperation::Delete),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidationResultStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "VALIDATION_RESULT_STATUS_UNSPECIFIED",
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidationResultStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "VALIDATION_RESULT_STATUS_UNSPECIFIED",
+            "PASS",
+            "FAIL",
+            "SKIP",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidationResultStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "VALIDATION_RESULT_STATUS_UNSPECIFIED" => Ok(ValidationResultStatus::Unspecified),
+                    "PASS" => Ok(ValidationResultStatus::Pass),
+                    "FAIL" => Ok(ValidationResultStatus::Fail),
+                    "SKIP" => Ok(ValidationResultStatus::Skip),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
@@ -152,5 +152,32 @@ pub struct DeleteExternalLocationRequest {
     #[prost(bool, optional, tag="2")]
     pub force: ::core::option::Option<bool>,
 }
+/// List tables stored below an external location
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListExternalLocationTablesRequest {
+    /// Name of external location.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// The maximum number of results per page that should be returned.
+    #[prost(int32, optional, tag="2")]
+    pub max_results: ::core::option::Option<i32>,
+    /// Opaque pagination token to go to next page based on previous query.
+    #[prost(string, optional, tag="3")]
+    pub page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// List tables stored below an external location response.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListExternalLocationTablesResponse {
+    /// The tables whose storage location is below the external location.
+    #[prost(message, repeated, tag="1")]
+    pub tables: ::prost::alloc::vec::Vec<super::super::tables::v1::TableInfo>,
+    /// The next_page_token value to include in the next List request.
+    #[prost(string, optional, tag="2")]
+    pub next_page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
 include!("unitycatalog.external_locations.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
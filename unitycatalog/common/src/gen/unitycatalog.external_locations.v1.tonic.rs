@@ -48,6 +48,14 @@ pub mod external_locations_service_server {
             &self,
             request: tonic::Request<super::DeleteExternalLocationRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// List tables stored below an external location
+        async fn list_external_location_tables(
+            &self,
+            request: tonic::Request<super::ListExternalLocationTablesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListExternalLocationTablesResponse>,
+            tonic::Status,
+        >;
     }
     ///
     #[derive(Debug)]
@@ -382,6 +390,60 @@ pub mod external_locations_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/unitycatalog.external_locations.v1.ExternalLocationsService/ListExternalLocationTables" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListExternalLocationTablesSvc<T: ExternalLocationsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExternalLocationsService,
+                    > tonic::server::UnaryService<
+                        super::ListExternalLocationTablesRequest,
+                    > for ListExternalLocationTablesSvc<T> {
+                        type Response = super::ListExternalLocationTablesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListExternalLocationTablesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExternalLocationsService>::list_external_location_tables(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListExternalLocationTablesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -5,7 +5,7 @@
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Resource {
-    #[prost(oneof="resource::Resource", tags="1, 2, 3, 4, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof="resource::Resource", tags="1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12")]
     pub resource: ::core::option::Option<resource::Resource>,
 }
 /// Nested message and enum types in `Resource`.
@@ -44,6 +44,8 @@ pub mod resource {
         ExternalLocationInfo(super::super::external_locations::v1::ExternalLocationInfo),
         #[prost(message, tag="11")]
         RecipientInfo(super::super::recipients::v1::RecipientInfo),
+        #[prost(message, tag="12")]
+        ProviderInfo(super::super::providers::v1::ProviderInfo),
     }
 }
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
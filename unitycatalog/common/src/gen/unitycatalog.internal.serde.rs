@@ -174,6 +174,9 @@ impl serde::Serialize for Resource {
                 resource::Resource::RecipientInfo(v) => {
                     struct_ser.serialize_field("recipientInfo", v)?;
                 }
+                resource::Resource::ProviderInfo(v) => {
+                    struct_ser.serialize_field("providerInfo", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -206,6 +209,8 @@ impl<'de> serde::Deserialize<'de> for Resource {
             "externalLocationInfo",
             "recipient_info",
             "recipientInfo",
+            "provider_info",
+            "providerInfo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -220,6 +225,7 @@ impl<'de> serde::Deserialize<'de> for Resource {
             ColumnInfo,
             ExternalLocationInfo,
             RecipientInfo,
+            ProviderInfo,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -252,6 +258,7 @@ impl<'de> serde::Deserialize<'de> for Resource {
                             "columnInfo" | "column_info" => Ok(GeneratedField::ColumnInfo),
                             "externalLocationInfo" | "external_location_info" => Ok(GeneratedField::ExternalLocationInfo),
                             "recipientInfo" | "recipient_info" => Ok(GeneratedField::RecipientInfo),
+                            "providerInfo" | "provider_info" => Ok(GeneratedField::ProviderInfo),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -342,6 +349,13 @@ impl<'de> serde::Deserialize<'de> for Resource {
                                 return Err(serde::de::Error::duplicate_field("recipientInfo"));
                             }
                             resource__ = map_.next_value::<::std::option::Option<_>>()?.map(resource::Resource::RecipientInfo)
+;
+                        }
+                        GeneratedField::ProviderInfo => {
+                            if resource__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerInfo"));
+                            }
+                            resource__ = map_.next_value::<::std::option::Option<_>>()?.map(resource::Resource::ProviderInfo)
 ;
                         }
                         GeneratedField::__SkipField__ => {
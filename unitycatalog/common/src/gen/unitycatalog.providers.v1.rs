@@ -0,0 +1,182 @@
+// @generated
+// This file is @generated by prost-build.
+/// The sharing profile of a provider, without its bearer token.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecipientProfile {
+    /// The url of the sharing server.
+    #[prost(string, tag="1")]
+    pub endpoint: ::prost::alloc::string::String,
+    /// The file format version of the profile file.
+    #[prost(int32, tag="2")]
+    pub share_credentials_version: i32,
+    /// The expiration time of the bearer token in ISO 8601 format.
+    #[prost(string, optional, tag="3")]
+    pub expiration_time: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProviderInfo {
+    /// Unique ID of the provider.
+    #[prost(string, optional, tag="100")]
+    pub id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The name of the provider.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// The delta sharing authentication type.
+    #[prost(enumeration="super::super::recipients::v1::AuthenticationType", tag="2")]
+    pub authentication_type: i32,
+    /// Username of the provider owner.
+    #[prost(string, optional, tag="3")]
+    pub owner: ::core::option::Option<::prost::alloc::string::String>,
+    /// Description about the provider.
+    #[prost(string, optional, tag="4")]
+    pub comment: ::core::option::Option<::prost::alloc::string::String>,
+    /// A map of key-value properties attached to the securable.
+    #[prost(message, optional, tag="5")]
+    pub properties: ::core::option::Option<super::super::super::google::protobuf::Struct>,
+    /// The profile used to access the sharing server of the provider.
+    ///
+    /// The bearer token is stored as a secret and never returned.
+    #[prost(message, optional, tag="6")]
+    pub recipient_profile: ::core::option::Option<RecipientProfile>,
+    /// Time at which this provider was created, in epoch milliseconds.
+    #[prost(int64, optional, tag="7")]
+    pub created_at: ::core::option::Option<i64>,
+    /// Username of the creator of the provider.
+    #[prost(string, optional, tag="8")]
+    pub created_by: ::core::option::Option<::prost::alloc::string::String>,
+    /// Time at which this provider was updated, in epoch milliseconds.
+    #[prost(int64, optional, tag="9")]
+    pub updated_at: ::core::option::Option<i64>,
+    /// Username of provider updater.
+    #[prost(string, optional, tag="10")]
+    pub updated_by: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// A share offered by a provider.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProviderShare {
+    /// The name of the share.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Request to list providers.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProvidersRequest {
+    /// The maximum number of results per page that should be returned.
+    #[prost(int32, optional, tag="1")]
+    pub max_results: ::core::option::Option<i32>,
+    /// Opaque pagination token to go to next page based on previous query.
+    #[prost(string, optional, tag="2")]
+    pub page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response to list providers.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProvidersResponse {
+    /// List of providers.
+    #[prost(message, repeated, tag="1")]
+    pub providers: ::prost::alloc::vec::Vec<ProviderInfo>,
+    /// Opaque pagination token to go to next page based on previous query.
+    #[prost(string, optional, tag="2")]
+    pub next_page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Creates a new provider
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateProviderRequest {
+    /// Name of the provider.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// The delta sharing authentication type.
+    #[prost(enumeration="super::super::recipients::v1::AuthenticationType", tag="2")]
+    pub authentication_type: i32,
+    /// The contents of the sharing profile file issued by the provider.
+    #[prost(string, tag="3")]
+    pub recipient_profile_str: ::prost::alloc::string::String,
+    /// Description about the provider.
+    #[prost(string, optional, tag="4")]
+    pub comment: ::core::option::Option<::prost::alloc::string::String>,
+    /// Provider properties as map of string key-value pairs.
+    #[prost(message, optional, tag="5")]
+    pub properties: ::core::option::Option<super::super::super::google::protobuf::Struct>,
+}
+/// Get a provider by name.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProviderRequest {
+    /// Name of the provider.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Update a provider
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateProviderRequest {
+    /// Name of the provider.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Username of the provider owner.
+    #[prost(string, optional, tag="2")]
+    pub owner: ::core::option::Option<::prost::alloc::string::String>,
+    /// Description about the provider.
+    #[prost(string, optional, tag="3")]
+    pub comment: ::core::option::Option<::prost::alloc::string::String>,
+    /// Provider properties as map of string key-value pairs.
+    ///
+    /// When provided in update request, the specified properties will override the existing properties.
+    /// To add and remove properties, one would need to perform a read-modify-write.
+    #[prost(message, optional, tag="4")]
+    pub properties: ::core::option::Option<super::super::super::google::protobuf::Struct>,
+    /// A new sharing profile, e.g. after the provider rotated the bearer token.
+    #[prost(string, optional, tag="5")]
+    pub recipient_profile_str: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Delete a provider
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteProviderRequest {
+    /// Name of the provider.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// List the shares offered by a provider.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProviderSharesRequest {
+    /// Name of the provider.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// The maximum number of results per page that should be returned.
+    #[prost(int32, optional, tag="2")]
+    pub max_results: ::core::option::Option<i32>,
+    /// Opaque pagination token to go to next page based on previous query.
+    #[prost(string, optional, tag="3")]
+    pub page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response to list the shares of a provider.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProviderSharesResponse {
+    /// List of shares.
+    #[prost(message, repeated, tag="1")]
+    pub shares: ::prost::alloc::vec::Vec<ProviderShare>,
+    /// Opaque pagination token to go to next page based on previous query.
+    #[prost(string, optional, tag="2")]
+    pub next_page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+include!("unitycatalog.providers.v1.serde.rs");
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -0,0 +1,40 @@
+// @generated
+// This file is @generated by prost-build.
+/// Execute a SQL query over the tables in the catalog.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRequest {
+    /// The SQL query to execute.
+    #[prost(string, tag="1")]
+    pub statement: ::prost::alloc::string::String,
+    /// Catalog of tables that are referenced without a catalog name.
+    #[prost(string, optional, tag="2")]
+    pub catalog_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// Schema of tables that are referenced by their name only.
+    #[prost(string, optional, tag="3")]
+    pub schema_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// Maximum number of rows to return.
+    #[prost(int32, optional, tag="4")]
+    pub max_rows: ::core::option::Option<i32>,
+    /// Time limit in seconds for planning and executing the query.
+    #[prost(int32, optional, tag="5")]
+    pub timeout_seconds: ::core::option::Option<i32>,
+}
+/// Read the first rows of a delta table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreviewTableRequest {
+    /// Full name of the table to preview.
+    #[prost(string, tag="1")]
+    pub full_name: ::prost::alloc::string::String,
+    /// Maximum number of rows to return.
+    #[prost(int32, optional, tag="2")]
+    pub limit: ::core::option::Option<i32>,
+    /// Columns to include in the preview, all columns if empty.
+    #[prost(string, repeated, tag="3")]
+    pub columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+include!("unitycatalog.query.v1.serde.rs");
+// @@protoc_insertion_point(module)
@@ -38,6 +38,11 @@ pub struct SchemaInfo {
     /// Unique identifier for the schema.
     #[prost(string, optional, tag="11")]
     pub schema_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Storage root URL for managed tables within schema.
+    ///
+    /// If not set, managed tables are stored below the storage root of the parent catalog.
+    #[prost(string, optional, tag="12")]
+    pub storage_root: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// List Schemas in a catalog
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
@@ -86,6 +91,9 @@ pub struct CreateSchemaRequest {
     /// A map of key-value properties attached to the securable.
     #[prost(message, optional, tag="4")]
     pub properties: ::core::option::Option<super::super::super::google::protobuf::Struct>,
+    /// Storage root URL for managed tables within schema.
+    #[prost(string, optional, tag="5")]
+    pub storage_root: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Get a Schema
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
@@ -19,6 +19,9 @@ impl serde::Serialize for CreateSchemaRequest {
         if self.properties.is_some() {
             len += 1;
         }
+        if self.storage_root.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("unitycatalog.schemas.v1.CreateSchemaRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -32,6 +35,9 @@ impl serde::Serialize for CreateSchemaRequest {
         if let Some(v) = self.properties.as_ref() {
             struct_ser.serialize_field("properties", v)?;
         }
+        if let Some(v) = self.storage_root.as_ref() {
+            struct_ser.serialize_field("storageRoot", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -47,6 +53,8 @@ impl<'de> serde::Deserialize<'de> for CreateSchemaRequest {
             "catalogName",
             "comment",
             "properties",
+            "storage_root",
+            "storageRoot",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -55,6 +63,7 @@ impl<'de> serde::Deserialize<'de> for CreateSchemaRequest {
             CatalogName,
             Comment,
             Properties,
+            StorageRoot,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -81,6 +90,7 @@ impl<'de> serde::Deserialize<'de> for CreateSchemaRequest {
                             "catalogName" | "catalog_name" => Ok(GeneratedField::CatalogName),
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
+                            "storageRoot" | "storage_root" => Ok(GeneratedField::StorageRoot),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -104,6 +114,7 @@ impl<'de> serde::Deserialize<'de> for CreateSchemaRequest {
                 let mut catalog_name__ = None;
                 let mut comment__ = None;
                 let mut properties__ = None;
+                let mut storage_root__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -130,6 +141,12 @@ impl<'de> serde::Deserialize<'de> for CreateSchemaRequest {
                             }
                             properties__ = map_.next_value()?;
                         }
+                        GeneratedField::StorageRoot => {
+                            if storage_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storageRoot"));
+                            }
+                            storage_root__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -140,6 +157,7 @@ impl<'de> serde::Deserialize<'de> for CreateSchemaRequest {
                     catalog_name: catalog_name__.unwrap_or_default(),
                     comment: comment__,
                     properties: properties__,
+                    storage_root: storage_root__,
                 })
             }
         }
@@ -661,6 +679,9 @@ impl serde::Serialize for SchemaInfo {
         if self.schema_id.is_some() {
             len += 1;
         }
+        if self.storage_root.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("unitycatalog.schemas.v1.SchemaInfo", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -699,6 +720,9 @@ impl serde::Serialize for SchemaInfo {
         if let Some(v) = self.schema_id.as_ref() {
             struct_ser.serialize_field("schemaId", v)?;
         }
+        if let Some(v) = self.storage_root.as_ref() {
+            struct_ser.serialize_field("storageRoot", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -727,6 +751,8 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
             "updatedBy",
             "schema_id",
             "schemaId",
+            "storage_root",
+            "storageRoot",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -742,6 +768,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
             UpdatedAt,
             UpdatedBy,
             SchemaId,
+            StorageRoot,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -775,6 +802,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                             "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "schemaId" | "schema_id" => Ok(GeneratedField::SchemaId),
+                            "storageRoot" | "storage_root" => Ok(GeneratedField::StorageRoot),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -805,6 +833,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                 let mut updated_at__ = None;
                 let mut updated_by__ = None;
                 let mut schema_id__ = None;
+                let mut storage_root__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -877,6 +906,12 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                             }
                             schema_id__ = map_.next_value()?;
                         }
+                        GeneratedField::StorageRoot => {
+                            if storage_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storageRoot"));
+                            }
+                            storage_root__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -894,6 +929,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                     updated_at: updated_at__,
                     updated_by: updated_by__,
                     schema_id: schema_id__,
+                    storage_root: storage_root__,
                 })
             }
         }
@@ -75,11 +75,12 @@ pub struct TableInfo {
     /// Storage root URL for table (for MANAGED, EXTERNAL tables)
     #[prost(string, optional, tag="7")]
     pub storage_location: ::core::option::Option<::prost::alloc::string::String>,
-    // View definition SQL (when table_type is VIEW, MATERIALIZED_VIEW, or STREAMING_TABLE)
-    // optional string view_definition = 8;
-
-    // optional string view_dependencies = 9;
-
+    /// View definition SQL (when table_type is VIEW, MATERIALIZED_VIEW, or STREAMING_TABLE)
+    #[prost(string, optional, tag="8")]
+    pub view_definition: ::core::option::Option<::prost::alloc::string::String>,
+    /// Tables the view depends on (when table_type is VIEW).
+    #[prost(message, optional, tag="9")]
+    pub view_dependencies: ::core::option::Option<DependencyList>,
     // optional string sql_path = 10;
 
     /// Username of current owner of table.
@@ -120,6 +121,226 @@ pub struct TableInfo {
     /// Unique identifier for the table.
     #[prost(string, optional, tag="23")]
     pub table_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Live metadata read from the delta log.
+    ///
+    /// Only set if requested via include_delta_metadata and the table's delta log could be read.
+    #[prost(message, optional, tag="24")]
+    pub delta_metadata: ::core::option::Option<DeltaTableMetadata>,
+    /// Live metadata read from the current iceberg metadata file.
+    ///
+    /// Only set for iceberg tables whose metadata could be read.
+    #[prost(message, optional, tag="25")]
+    pub iceberg_metadata: ::core::option::Option<IcebergTableMetadata>,
+}
+/// Metadata of the latest version of a delta table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeltaTableMetadata {
+    /// The table version the metadata was read from.
+    #[prost(int64, tag="1")]
+    pub version: i64,
+    /// Minimum protocol version required to read the table.
+    #[prost(int32, tag="2")]
+    pub min_reader_version: i32,
+    /// Minimum protocol version required to write the table.
+    #[prost(int32, tag="3")]
+    pub min_writer_version: i32,
+    /// Reader features enabled for the table.
+    #[prost(string, repeated, tag="4")]
+    pub reader_features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Writer features enabled for the table.
+    #[prost(string, repeated, tag="5")]
+    pub writer_features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Table properties as stored in the delta log.
+    #[prost(map="string, string", tag="6")]
+    pub properties: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Names of the columns the table is partitioned by.
+    #[prost(string, repeated, tag="7")]
+    pub partition_columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Number of data files in the table version.
+    #[prost(int64, tag="8")]
+    pub num_files: i64,
+    /// Total size of the data files in the table version, in bytes.
+    #[prost(int64, tag="9")]
+    pub size_in_bytes: i64,
+}
+/// Metadata of the current state of an iceberg table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IcebergTableMetadata {
+    /// Location of the metadata file the metadata was read from.
+    #[prost(string, tag="1")]
+    pub metadata_location: ::prost::alloc::string::String,
+    /// Version of the iceberg table format.
+    #[prost(int32, tag="2")]
+    pub format_version: i32,
+    /// Unique identifier of the table.
+    #[prost(string, tag="3")]
+    pub table_uuid: ::prost::alloc::string::String,
+    /// Id of the current snapshot, not set for tables without snapshots.
+    #[prost(int64, optional, tag="4")]
+    pub current_snapshot_id: ::core::option::Option<i64>,
+    /// Id of the current schema.
+    #[prost(int32, tag="5")]
+    pub current_schema_id: i32,
+    /// Id of the default partition spec.
+    #[prost(int32, tag="6")]
+    pub default_spec_id: i32,
+    /// Fields of the default partition spec.
+    #[prost(message, repeated, tag="7")]
+    pub partition_fields: ::prost::alloc::vec::Vec<IcebergPartitionField>,
+    /// Table properties as stored in the metadata file.
+    #[prost(map="string, string", tag="8")]
+    pub properties: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+/// A field of an iceberg partition spec.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IcebergPartitionField {
+    /// Name of the partition field.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Name of the column the partition values are derived from.
+    #[prost(string, tag="2")]
+    pub source_column: ::prost::alloc::string::String,
+    /// Transform applied to the source column, e.g. identity, day or bucket\[16\].
+    #[prost(string, tag="3")]
+    pub transform: ::prost::alloc::string::String,
+}
+/// A list of dependencies of a view.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DependencyList {
+    /// The dependencies of the view.
+    #[prost(message, repeated, tag="1")]
+    pub dependencies: ::prost::alloc::vec::Vec<Dependency>,
+}
+/// A dependency of a view.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Dependency {
+    #[prost(oneof="dependency::Dependency", tags="1")]
+    pub dependency: ::core::option::Option<dependency::Dependency>,
+}
+/// Nested message and enum types in `Dependency`.
+pub mod dependency {
+    #[cfg_attr(feature = "python", ::pyo3::pyclass)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Dependency {
+        /// A table the view reads from.
+        #[prost(message, tag="1")]
+        Table(super::TableDependency),
+    }
+}
+/// A table a view depends on.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TableDependency {
+    /// Full name of the table, in the form of catalog_name.schema_name.table_name.
+    #[prost(string, tag="1")]
+    pub table_full_name: ::prost::alloc::string::String,
+}
+/// A commit in the delta log of a table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TableCommit {
+    /// The table version created by the commit.
+    #[prost(int64, tag="1")]
+    pub version: i64,
+    /// Time the commit was created, in epoch milliseconds.
+    #[prost(int64, tag="2")]
+    pub timestamp: i64,
+    /// The operation performed by the commit, e.g. WRITE, MERGE or OPTIMIZE.
+    #[prost(string, optional, tag="3")]
+    pub operation: ::core::option::Option<::prost::alloc::string::String>,
+    /// Parameters of the operation, with non-string values encoded as JSON.
+    #[prost(map="string, string", tag="4")]
+    pub operation_parameters: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Metrics reported by the operation, e.g. the number of added files or rows.
+    #[prost(map="string, string", tag="5")]
+    pub operation_metrics: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// The engine that wrote the commit.
+    #[prost(string, optional, tag="6")]
+    pub engine_info: ::core::option::Option<::prost::alloc::string::String>,
+    /// The user that performed the operation, if recorded by the engine.
+    #[prost(string, optional, tag="7")]
+    pub user_name: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Statistics about the data files of a delta table version.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TableDetail {
+    /// Full name of the table, in the form of catalog_name.schema_name.table_name.
+    #[prost(string, tag="1")]
+    pub full_name: ::prost::alloc::string::String,
+    /// The table version the statistics were computed for.
+    #[prost(int64, tag="2")]
+    pub version: i64,
+    /// Number of data files.
+    #[prost(int64, tag="3")]
+    pub num_files: i64,
+    /// Total size of the data files, in bytes.
+    #[prost(int64, tag="4")]
+    pub size_in_bytes: i64,
+    /// Number and size of the data files by file size, smallest files first.
+    #[prost(message, repeated, tag="5")]
+    pub file_size_histogram: ::prost::alloc::vec::Vec<FileSizeBucket>,
+    /// Number of distinct partitions, 0 for unpartitioned tables.
+    #[prost(int64, tag="6")]
+    pub num_partitions: i64,
+    /// Size of the largest partition relative to the mean partition size.
+    ///
+    /// 1 for evenly sized partitions and unpartitioned tables, 0 for empty tables.
+    #[prost(double, tag="7")]
+    pub partition_skew: f64,
+    /// Fraction of data files smaller than small_file_threshold_bytes.
+    #[prost(double, tag="8")]
+    pub small_file_ratio: f64,
+    /// Size below which data files are considered small, in bytes.
+    #[prost(int64, tag="9")]
+    pub small_file_threshold_bytes: i64,
+    /// Number of data files with a deletion vector.
+    #[prost(int64, tag="10")]
+    pub num_files_with_deletion_vectors: i64,
+}
+/// Number and size of the data files within a range of file sizes.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FileSizeBucket {
+    /// Inclusive lower bound of the file sizes in the bucket, in bytes.
+    #[prost(int64, tag="1")]
+    pub min_size_in_bytes: i64,
+    /// Exclusive upper bound of the file sizes in the bucket, in bytes. Unbounded if not set.
+    #[prost(int64, optional, tag="2")]
+    pub max_size_in_bytes: ::core::option::Option<i64>,
+    /// Number of data files in the bucket.
+    #[prost(int64, tag="3")]
+    pub num_files: i64,
+    /// Total size of the data files in the bucket, in bytes.
+    #[prost(int64, tag="4")]
+    pub size_in_bytes: i64,
 }
 /// The type of the table.
 #[cfg_attr(feature = "python", ::pyo3::pyclass)]
@@ -129,6 +350,7 @@ pub enum TableType {
     Unspecified = 0,
     Managed = 1,
     External = 2,
+    View = 3,
 }
 impl TableType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -140,6 +362,7 @@ impl TableType {
             TableType::Unspecified => "TABLE_TYPE_UNSPECIFIED",
             TableType::Managed => "MANAGED",
             TableType::External => "EXTERNAL",
+            TableType::View => "VIEW",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -148,6 +371,7 @@ impl TableType {
             "TABLE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
             "MANAGED" => Some(Self::Managed),
             "EXTERNAL" => Some(Self::External),
+            "VIEW" => Some(TableType::View),
             _ => None,
         }
     }
@@ -403,6 +627,9 @@ pub struct CreateTableRequest {
     #[prost(message, repeated, tag="6")]
     pub columns: ::prost::alloc::vec::Vec<ColumnInfo>,
     /// Storage root URL for external table.
+    ///
+    /// Must not be set for managed tables, which are stored below the
+    /// storage root of the parent schema or catalog.
     #[prost(string, optional, tag="7")]
     pub storage_location: ::core::option::Option<::prost::alloc::string::String>,
     /// User-provided free-form text description.
@@ -411,6 +638,9 @@ pub struct CreateTableRequest {
     /// A map of key-value properties attached to the securable.
     #[prost(message, optional, tag="9")]
     pub properties: ::core::option::Option<super::super::super::google::protobuf::Struct>,
+    /// View definition SQL, required for views.
+    #[prost(string, optional, tag="10")]
+    pub view_definition: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Get a table
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
@@ -456,5 +686,52 @@ pub struct DeleteTableRequest {
     #[prost(string, tag="1")]
     pub full_name: ::prost::alloc::string::String,
 }
+/// Restore a dropped managed table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UndropTableRequest {
+    /// Full name of the dropped table.
+    #[prost(string, tag="1")]
+    pub full_name: ::prost::alloc::string::String,
+}
+/// Get the commit history of a delta table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTableHistoryRequest {
+    /// Full name of the table.
+    #[prost(string, tag="1")]
+    pub full_name: ::prost::alloc::string::String,
+    /// The oldest table version to include.
+    #[prost(int64, optional, tag="2")]
+    pub start_version: ::core::option::Option<i64>,
+    /// The latest table version to include, defaults to the current version.
+    #[prost(int64, optional, tag="3")]
+    pub end_version: ::core::option::Option<i64>,
+    /// The maximum number of commits to return.
+    #[prost(int32, optional, tag="4")]
+    pub max_results: ::core::option::Option<i32>,
+}
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTableHistoryResponse {
+    /// The commits in the requested version range, latest first.
+    #[prost(message, repeated, tag="1")]
+    pub commits: ::prost::alloc::vec::Vec<TableCommit>,
+    /// The end_version to include in the next request, if older commits remain.
+    #[prost(int64, optional, tag="2")]
+    pub next_end_version: ::core::option::Option<i64>,
+}
+/// Get statistics about the data files of a delta table.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTableDetailRequest {
+    /// Full name of the table.
+    #[prost(string, tag="1")]
+    pub full_name: ::prost::alloc::string::String,
+}
 include!("unitycatalog.tables.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
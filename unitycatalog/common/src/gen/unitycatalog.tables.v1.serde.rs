@@ -468,6 +468,9 @@ impl serde::Serialize for CreateTableRequest {
         if self.properties.is_some() {
             len += 1;
         }
+        if self.view_definition.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.CreateTableRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -500,6 +503,9 @@ impl serde::Serialize for CreateTableRequest {
         if let Some(v) = self.properties.as_ref() {
             struct_ser.serialize_field("properties", v)?;
         }
+        if let Some(v) = self.view_definition.as_ref() {
+            struct_ser.serialize_field("viewDefinition", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -524,6 +530,8 @@ impl<'de> serde::Deserialize<'de> for CreateTableRequest {
             "storageLocation",
             "comment",
             "properties",
+            "view_definition",
+            "viewDefinition",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -537,6 +545,7 @@ impl<'de> serde::Deserialize<'de> for CreateTableRequest {
             StorageLocation,
             Comment,
             Properties,
+            ViewDefinition,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -568,6 +577,7 @@ impl<'de> serde::Deserialize<'de> for CreateTableRequest {
                             "storageLocation" | "storage_location" => Ok(GeneratedField::StorageLocation),
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
+                            "viewDefinition" | "view_definition" => Ok(GeneratedField::ViewDefinition),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -596,6 +606,7 @@ impl<'de> serde::Deserialize<'de> for CreateTableRequest {
                 let mut storage_location__ = None;
                 let mut comment__ = None;
                 let mut properties__ = None;
+                let mut view_definition__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -652,6 +663,12 @@ impl<'de> serde::Deserialize<'de> for CreateTableRequest {
                             }
                             properties__ = map_.next_value()?;
                         }
+                        GeneratedField::ViewDefinition => {
+                            if view_definition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("viewDefinition"));
+                            }
+                            view_definition__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -667,6 +684,7 @@ impl<'de> serde::Deserialize<'de> for CreateTableRequest {
                     storage_location: storage_location__,
                     comment: comment__,
                     properties: properties__,
+                    view_definition: view_definition__,
                 })
             }
         }
@@ -870,7 +888,7 @@ impl<'de> serde::Deserialize<'de> for DeleteTableRequest {
         deserializer.deserialize_struct("unitycatalog.tables.v1.DeleteTableRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetTableExistsRequest {
+impl serde::Serialize for DeltaTableMetadata {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -878,30 +896,106 @@ impl serde::Serialize for GetTableExistsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.full_name.is_empty() {
+        if self.version != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableExistsRequest", len)?;
-        if !self.full_name.is_empty() {
-            struct_ser.serialize_field("fullName", &self.full_name)?;
+        if self.min_reader_version != 0 {
+            len += 1;
+        }
+        if self.min_writer_version != 0 {
+            len += 1;
+        }
+        if !self.reader_features.is_empty() {
+            len += 1;
+        }
+        if !self.writer_features.is_empty() {
+            len += 1;
+        }
+        if !self.properties.is_empty() {
+            len += 1;
+        }
+        if !self.partition_columns.is_empty() {
+            len += 1;
+        }
+        if self.num_files != 0 {
+            len += 1;
+        }
+        if self.size_in_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.DeltaTableMetadata", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if self.min_reader_version != 0 {
+            struct_ser.serialize_field("minReaderVersion", &self.min_reader_version)?;
+        }
+        if self.min_writer_version != 0 {
+            struct_ser.serialize_field("minWriterVersion", &self.min_writer_version)?;
+        }
+        if !self.reader_features.is_empty() {
+            struct_ser.serialize_field("readerFeatures", &self.reader_features)?;
+        }
+        if !self.writer_features.is_empty() {
+            struct_ser.serialize_field("writerFeatures", &self.writer_features)?;
+        }
+        if !self.properties.is_empty() {
+            struct_ser.serialize_field("properties", &self.properties)?;
+        }
+        if !self.partition_columns.is_empty() {
+            struct_ser.serialize_field("partitionColumns", &self.partition_columns)?;
+        }
+        if self.num_files != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("numFiles", ToString::to_string(&self.num_files).as_str())?;
+        }
+        if self.size_in_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sizeInBytes", ToString::to_string(&self.size_in_bytes).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetTableExistsRequest {
+impl<'de> serde::Deserialize<'de> for DeltaTableMetadata {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "full_name",
-            "fullName",
+            "version",
+            "min_reader_version",
+            "minReaderVersion",
+            "min_writer_version",
+            "minWriterVersion",
+            "reader_features",
+            "readerFeatures",
+            "writer_features",
+            "writerFeatures",
+            "properties",
+            "partition_columns",
+            "partitionColumns",
+            "num_files",
+            "numFiles",
+            "size_in_bytes",
+            "sizeInBytes",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FullName,
+            Version,
+            MinReaderVersion,
+            MinWriterVersion,
+            ReaderFeatures,
+            WriterFeatures,
+            Properties,
+            PartitionColumns,
+            NumFiles,
+            SizeInBytes,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -924,7 +1018,15 @@ impl<'de> serde::Deserialize<'de> for GetTableExistsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            "version" => Ok(GeneratedField::Version),
+                            "minReaderVersion" | "min_reader_version" => Ok(GeneratedField::MinReaderVersion),
+                            "minWriterVersion" | "min_writer_version" => Ok(GeneratedField::MinWriterVersion),
+                            "readerFeatures" | "reader_features" => Ok(GeneratedField::ReaderFeatures),
+                            "writerFeatures" | "writer_features" => Ok(GeneratedField::WriterFeatures),
+                            "properties" => Ok(GeneratedField::Properties),
+                            "partitionColumns" | "partition_columns" => Ok(GeneratedField::PartitionColumns),
+                            "numFiles" | "num_files" => Ok(GeneratedField::NumFiles),
+                            "sizeInBytes" | "size_in_bytes" => Ok(GeneratedField::SizeInBytes),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -934,39 +1036,115 @@ impl<'de> serde::Deserialize<'de> for GetTableExistsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetTableExistsRequest;
+            type Value = DeltaTableMetadata;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.GetTableExistsRequest")
+                formatter.write_str("struct unitycatalog.tables.v1.DeltaTableMetadata")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableExistsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeltaTableMetadata, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut full_name__ = None;
+                let mut version__ = None;
+                let mut min_reader_version__ = None;
+                let mut min_writer_version__ = None;
+                let mut reader_features__ = None;
+                let mut writer_features__ = None;
+                let mut properties__ = None;
+                let mut partition_columns__ = None;
+                let mut num_files__ = None;
+                let mut size_in_bytes__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::FullName => {
-                            if full_name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullName"));
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
                             }
-                            full_name__ = Some(map_.next_value()?);
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinReaderVersion => {
+                            if min_reader_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minReaderVersion"));
+                            }
+                            min_reader_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinWriterVersion => {
+                            if min_writer_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minWriterVersion"));
+                            }
+                            min_writer_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReaderFeatures => {
+                            if reader_features__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readerFeatures"));
+                            }
+                            reader_features__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WriterFeatures => {
+                            if writer_features__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("writerFeatures"));
+                            }
+                            writer_features__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Properties => {
+                            if properties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("properties"));
+                            }
+                            properties__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::PartitionColumns => {
+                            if partition_columns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partitionColumns"));
+                            }
+                            partition_columns__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NumFiles => {
+                            if num_files__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numFiles"));
+                            }
+                            num_files__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SizeInBytes => {
+                            if size_in_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeInBytes"));
+                            }
+                            size_in_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GetTableExistsRequest {
-                    full_name: full_name__.unwrap_or_default(),
+                Ok(DeltaTableMetadata {
+                    version: version__.unwrap_or_default(),
+                    min_reader_version: min_reader_version__.unwrap_or_default(),
+                    min_writer_version: min_writer_version__.unwrap_or_default(),
+                    reader_features: reader_features__.unwrap_or_default(),
+                    writer_features: writer_features__.unwrap_or_default(),
+                    properties: properties__.unwrap_or_default(),
+                    partition_columns: partition_columns__.unwrap_or_default(),
+                    num_files: num_files__.unwrap_or_default(),
+                    size_in_bytes: size_in_bytes__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableExistsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.DeltaTableMetadata", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetTableExistsResponse {
+impl serde::Serialize for Dependency {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -974,30 +1152,33 @@ impl serde::Serialize for GetTableExistsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.table_exists {
+        if self.dependency.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableExistsResponse", len)?;
-        if self.table_exists {
-            struct_ser.serialize_field("tableExists", &self.table_exists)?;
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.Dependency", len)?;
+        if let Some(v) = self.dependency.as_ref() {
+            match v {
+                dependency::Dependency::Table(v) => {
+                    struct_ser.serialize_field("table", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetTableExistsResponse {
+impl<'de> serde::Deserialize<'de> for Dependency {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "table_exists",
-            "tableExists",
+            "table",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TableExists,
+            Table,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1020,7 +1201,7 @@ impl<'de> serde::Deserialize<'de> for GetTableExistsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tableExists" | "table_exists" => Ok(GeneratedField::TableExists),
+                            "table" => Ok(GeneratedField::Table),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1030,39 +1211,40 @@ impl<'de> serde::Deserialize<'de> for GetTableExistsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetTableExistsResponse;
+            type Value = Dependency;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.GetTableExistsResponse")
+                formatter.write_str("struct unitycatalog.tables.v1.Dependency")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableExistsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Dependency, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut table_exists__ = None;
+                let mut dependency__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TableExists => {
-                            if table_exists__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tableExists"));
+                        GeneratedField::Table => {
+                            if dependency__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("table"));
                             }
-                            table_exists__ = Some(map_.next_value()?);
+                            dependency__ = map_.next_value::<::std::option::Option<_>>()?.map(dependency::Dependency::Table)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GetTableExistsResponse {
-                    table_exists: table_exists__.unwrap_or_default(),
+                Ok(Dependency {
+                    dependency: dependency__,
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableExistsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.Dependency", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetTableRequest {
+impl serde::Serialize for DependencyList {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1070,57 +1252,29 @@ impl serde::Serialize for GetTableRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.full_name.is_empty() {
-            len += 1;
-        }
-        if self.include_delta_metadata.is_some() {
+        if !self.dependencies.is_empty() {
             len += 1;
         }
-        if self.include_browse.is_some() {
-            len += 1;
-        }
-        if self.include_manifest_capabilities.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableRequest", len)?;
-        if !self.full_name.is_empty() {
-            struct_ser.serialize_field("fullName", &self.full_name)?;
-        }
-        if let Some(v) = self.include_delta_metadata.as_ref() {
-            struct_ser.serialize_field("includeDeltaMetadata", v)?;
-        }
-        if let Some(v) = self.include_browse.as_ref() {
-            struct_ser.serialize_field("includeBrowse", v)?;
-        }
-        if let Some(v) = self.include_manifest_capabilities.as_ref() {
-            struct_ser.serialize_field("includeManifestCapabilities", v)?;
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.DependencyList", len)?;
+        if !self.dependencies.is_empty() {
+            struct_ser.serialize_field("dependencies", &self.dependencies)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetTableRequest {
+impl<'de> serde::Deserialize<'de> for DependencyList {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "full_name",
-            "fullName",
-            "include_delta_metadata",
-            "includeDeltaMetadata",
-            "include_browse",
-            "includeBrowse",
-            "include_manifest_capabilities",
-            "includeManifestCapabilities",
+            "dependencies",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FullName,
-            IncludeDeltaMetadata,
-            IncludeBrowse,
-            IncludeManifestCapabilities,
+            Dependencies,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1143,10 +1297,7 @@ impl<'de> serde::Deserialize<'de> for GetTableRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
-                            "includeDeltaMetadata" | "include_delta_metadata" => Ok(GeneratedField::IncludeDeltaMetadata),
-                            "includeBrowse" | "include_browse" => Ok(GeneratedField::IncludeBrowse),
-                            "includeManifestCapabilities" | "include_manifest_capabilities" => Ok(GeneratedField::IncludeManifestCapabilities),
+                            "dependencies" => Ok(GeneratedField::Dependencies),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1156,63 +1307,39 @@ impl<'de> serde::Deserialize<'de> for GetTableRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetTableRequest;
+            type Value = DependencyList;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.GetTableRequest")
+                formatter.write_str("struct unitycatalog.tables.v1.DependencyList")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DependencyList, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut full_name__ = None;
-                let mut include_delta_metadata__ = None;
-                let mut include_browse__ = None;
-                let mut include_manifest_capabilities__ = None;
+                let mut dependencies__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::FullName => {
-                            if full_name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullName"));
-                            }
-                            full_name__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::IncludeDeltaMetadata => {
-                            if include_delta_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeDeltaMetadata"));
-                            }
-                            include_delta_metadata__ = map_.next_value()?;
-                        }
-                        GeneratedField::IncludeBrowse => {
-                            if include_browse__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeBrowse"));
-                            }
-                            include_browse__ = map_.next_value()?;
-                        }
-                        GeneratedField::IncludeManifestCapabilities => {
-                            if include_manifest_capabilities__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeManifestCapabilities"));
+                        GeneratedField::Dependencies => {
+                            if dependencies__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dependencies"));
                             }
-                            include_manifest_capabilities__ = map_.next_value()?;
+                            dependencies__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GetTableRequest {
-                    full_name: full_name__.unwrap_or_default(),
-                    include_delta_metadata: include_delta_metadata__,
-                    include_browse: include_browse__,
-                    include_manifest_capabilities: include_manifest_capabilities__,
+                Ok(DependencyList {
+                    dependencies: dependencies__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.DependencyList", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ListTableSummariesRequest {
+impl serde::Serialize for FileSizeBucket {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1220,75 +1347,65 @@ impl serde::Serialize for ListTableSummariesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.catalog_name.is_empty() {
+        if self.min_size_in_bytes != 0 {
             len += 1;
         }
-        if self.schema_name_pattern.is_some() {
-            len += 1;
-        }
-        if self.table_name_pattern.is_some() {
+        if self.max_size_in_bytes.is_some() {
             len += 1;
         }
-        if self.max_results.is_some() {
-            len += 1;
-        }
-        if self.page_token.is_some() {
+        if self.num_files != 0 {
             len += 1;
         }
-        if self.include_manifest_capabilities.is_some() {
+        if self.size_in_bytes != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTableSummariesRequest", len)?;
-        if !self.catalog_name.is_empty() {
-            struct_ser.serialize_field("catalogName", &self.catalog_name)?;
-        }
-        if let Some(v) = self.schema_name_pattern.as_ref() {
-            struct_ser.serialize_field("schemaNamePattern", v)?;
-        }
-        if let Some(v) = self.table_name_pattern.as_ref() {
-            struct_ser.serialize_field("tableNamePattern", v)?;
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.FileSizeBucket", len)?;
+        if self.min_size_in_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("minSizeInBytes", ToString::to_string(&self.min_size_in_bytes).as_str())?;
         }
-        if let Some(v) = self.max_results.as_ref() {
-            struct_ser.serialize_field("maxResults", v)?;
+        if let Some(v) = self.max_size_in_bytes.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxSizeInBytes", ToString::to_string(&v).as_str())?;
         }
-        if let Some(v) = self.page_token.as_ref() {
-            struct_ser.serialize_field("pageToken", v)?;
+        if self.num_files != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("numFiles", ToString::to_string(&self.num_files).as_str())?;
         }
-        if let Some(v) = self.include_manifest_capabilities.as_ref() {
-            struct_ser.serialize_field("includeManifestCapabilities", v)?;
+        if self.size_in_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sizeInBytes", ToString::to_string(&self.size_in_bytes).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ListTableSummariesRequest {
+impl<'de> serde::Deserialize<'de> for FileSizeBucket {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "catalog_name",
-            "catalogName",
-            "schema_name_pattern",
-            "schemaNamePattern",
-            "table_name_pattern",
-            "tableNamePattern",
-            "max_results",
-            "maxResults",
-            "page_token",
-            "pageToken",
-            "include_manifest_capabilities",
-            "includeManifestCapabilities",
+            "min_size_in_bytes",
+            "minSizeInBytes",
+            "max_size_in_bytes",
+            "maxSizeInBytes",
+            "num_files",
+            "numFiles",
+            "size_in_bytes",
+            "sizeInBytes",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            CatalogName,
-            SchemaNamePattern,
-            TableNamePattern,
-            MaxResults,
-            PageToken,
-            IncludeManifestCapabilities,
+            MinSizeInBytes,
+            MaxSizeInBytes,
+            NumFiles,
+            SizeInBytes,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1311,12 +1428,10 @@ impl<'de> serde::Deserialize<'de> for ListTableSummariesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "catalogName" | "catalog_name" => Ok(GeneratedField::CatalogName),
-                            "schemaNamePattern" | "schema_name_pattern" => Ok(GeneratedField::SchemaNamePattern),
-                            "tableNamePattern" | "table_name_pattern" => Ok(GeneratedField::TableNamePattern),
-                            "maxResults" | "max_results" => Ok(GeneratedField::MaxResults),
-                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
-                            "includeManifestCapabilities" | "include_manifest_capabilities" => Ok(GeneratedField::IncludeManifestCapabilities),
+                            "minSizeInBytes" | "min_size_in_bytes" => Ok(GeneratedField::MinSizeInBytes),
+                            "maxSizeInBytes" | "max_size_in_bytes" => Ok(GeneratedField::MaxSizeInBytes),
+                            "numFiles" | "num_files" => Ok(GeneratedField::NumFiles),
+                            "sizeInBytes" | "size_in_bytes" => Ok(GeneratedField::SizeInBytes),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1326,41 +1441,1636 @@ impl<'de> serde::Deserialize<'de> for ListTableSummariesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ListTableSummariesRequest;
+            type Value = FileSizeBucket;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.ListTableSummariesRequest")
+                formatter.write_str("struct unitycatalog.tables.v1.FileSizeBucket")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTableSummariesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FileSizeBucket, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut catalog_name__ = None;
-                let mut schema_name_pattern__ = None;
-                let mut table_name_pattern__ = None;
-                let mut max_results__ = None;
-                let mut page_token__ = None;
-                let mut include_manifest_capabilities__ = None;
+                let mut min_size_in_bytes__ = None;
+                let mut max_size_in_bytes__ = None;
+                let mut num_files__ = None;
+                let mut size_in_bytes__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::CatalogName => {
-                            if catalog_name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("catalogName"));
+                        GeneratedField::MinSizeInBytes => {
+                            if min_size_in_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minSizeInBytes"));
                             }
-                            catalog_name__ = Some(map_.next_value()?);
+                            min_size_in_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::SchemaNamePattern => {
+                        GeneratedField::MaxSizeInBytes => {
+                            if max_size_in_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxSizeInBytes"));
+                            }
+                            max_size_in_bytes__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::NumFiles => {
+                            if num_files__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numFiles"));
+                            }
+                            num_files__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SizeInBytes => {
+                            if size_in_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeInBytes"));
+                            }
+                            size_in_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FileSizeBucket {
+                    min_size_in_bytes: min_size_in_bytes__.unwrap_or_default(),
+                    max_size_in_bytes: max_size_in_bytes__,
+                    num_files: num_files__.unwrap_or_default(),
+                    size_in_bytes: size_in_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.FileSizeBucket", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTableDetailRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.full_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableDetailRequest", len)?;
+        if !self.full_name.is_empty() {
+            struct_ser.serialize_field("fullName", &self.full_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableDetailRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_name",
+            "fullName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullName,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableDetailRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.GetTableDetailRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableDetailRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullName => {
+                            if full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullName"));
+                            }
+                            full_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableDetailRequest {
+                    full_name: full_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableDetailRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTableExistsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.full_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableExistsRequest", len)?;
+        if !self.full_name.is_empty() {
+            struct_ser.serialize_field("fullName", &self.full_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableExistsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_name",
+            "fullName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullName,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableExistsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.GetTableExistsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableExistsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullName => {
+                            if full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullName"));
+                            }
+                            full_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableExistsRequest {
+                    full_name: full_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableExistsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTableExistsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.table_exists {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableExistsResponse", len)?;
+        if self.table_exists {
+            struct_ser.serialize_field("tableExists", &self.table_exists)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableExistsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "table_exists",
+            "tableExists",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TableExists,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tableExists" | "table_exists" => Ok(GeneratedField::TableExists),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableExistsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.GetTableExistsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableExistsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut table_exists__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TableExists => {
+                            if table_exists__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tableExists"));
+                            }
+                            table_exists__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableExistsResponse {
+                    table_exists: table_exists__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableExistsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTableHistoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.full_name.is_empty() {
+            len += 1;
+        }
+        if self.start_version.is_some() {
+            len += 1;
+        }
+        if self.end_version.is_some() {
+            len += 1;
+        }
+        if self.max_results.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableHistoryRequest", len)?;
+        if !self.full_name.is_empty() {
+            struct_ser.serialize_field("fullName", &self.full_name)?;
+        }
+        if let Some(v) = self.start_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.end_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.max_results.as_ref() {
+            struct_ser.serialize_field("maxResults", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableHistoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_name",
+            "fullName",
+            "start_version",
+            "startVersion",
+            "end_version",
+            "endVersion",
+            "max_results",
+            "maxResults",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullName,
+            StartVersion,
+            EndVersion,
+            MaxResults,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            "startVersion" | "start_version" => Ok(GeneratedField::StartVersion),
+                            "endVersion" | "end_version" => Ok(GeneratedField::EndVersion),
+                            "maxResults" | "max_results" => Ok(GeneratedField::MaxResults),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableHistoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.GetTableHistoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableHistoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_name__ = None;
+                let mut start_version__ = None;
+                let mut end_version__ = None;
+                let mut max_results__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullName => {
+                            if full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullName"));
+                            }
+                            full_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartVersion => {
+                            if start_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startVersion"));
+                            }
+                            start_version__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::EndVersion => {
+                            if end_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endVersion"));
+                            }
+                            end_version__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::MaxResults => {
+                            if max_results__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxResults"));
+                            }
+                            max_results__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableHistoryRequest {
+                    full_name: full_name__.unwrap_or_default(),
+                    start_version: start_version__,
+                    end_version: end_version__,
+                    max_results: max_results__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableHistoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTableHistoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.commits.is_empty() {
+            len += 1;
+        }
+        if self.next_end_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableHistoryResponse", len)?;
+        if !self.commits.is_empty() {
+            struct_ser.serialize_field("commits", &self.commits)?;
+        }
+        if let Some(v) = self.next_end_version.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("nextEndVersion", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableHistoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "commits",
+            "next_end_version",
+            "nextEndVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Commits,
+            NextEndVersion,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "commits" => Ok(GeneratedField::Commits),
+                            "nextEndVersion" | "next_end_version" => Ok(GeneratedField::NextEndVersion),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableHistoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.GetTableHistoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableHistoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut commits__ = None;
+                let mut next_end_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Commits => {
+                            if commits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commits"));
+                            }
+                            commits__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NextEndVersion => {
+                            if next_end_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextEndVersion"));
+                            }
+                            next_end_version__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableHistoryResponse {
+                    commits: commits__.unwrap_or_default(),
+                    next_end_version: next_end_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableHistoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTableRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.full_name.is_empty() {
+            len += 1;
+        }
+        if self.include_delta_metadata.is_some() {
+            len += 1;
+        }
+        if self.include_browse.is_some() {
+            len += 1;
+        }
+        if self.include_manifest_capabilities.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.GetTableRequest", len)?;
+        if !self.full_name.is_empty() {
+            struct_ser.serialize_field("fullName", &self.full_name)?;
+        }
+        if let Some(v) = self.include_delta_metadata.as_ref() {
+            struct_ser.serialize_field("includeDeltaMetadata", v)?;
+        }
+        if let Some(v) = self.include_browse.as_ref() {
+            struct_ser.serialize_field("includeBrowse", v)?;
+        }
+        if let Some(v) = self.include_manifest_capabilities.as_ref() {
+            struct_ser.serialize_field("includeManifestCapabilities", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_name",
+            "fullName",
+            "include_delta_metadata",
+            "includeDeltaMetadata",
+            "include_browse",
+            "includeBrowse",
+            "include_manifest_capabilities",
+            "includeManifestCapabilities",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullName,
+            IncludeDeltaMetadata,
+            IncludeBrowse,
+            IncludeManifestCapabilities,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            "includeDeltaMetadata" | "include_delta_metadata" => Ok(GeneratedField::IncludeDeltaMetadata),
+                            "includeBrowse" | "include_browse" => Ok(GeneratedField::IncludeBrowse),
+                            "includeManifestCapabilities" | "include_manifest_capabilities" => Ok(GeneratedField::IncludeManifestCapabilities),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.GetTableRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_name__ = None;
+                let mut include_delta_metadata__ = None;
+                let mut include_browse__ = None;
+                let mut include_manifest_capabilities__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullName => {
+                            if full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullName"));
+                            }
+                            full_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IncludeDeltaMetadata => {
+                            if include_delta_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeDeltaMetadata"));
+                            }
+                            include_delta_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::IncludeBrowse => {
+                            if include_browse__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeBrowse"));
+                            }
+                            include_browse__ = map_.next_value()?;
+                        }
+                        GeneratedField::IncludeManifestCapabilities => {
+                            if include_manifest_capabilities__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeManifestCapabilities"));
+                            }
+                            include_manifest_capabilities__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableRequest {
+                    full_name: full_name__.unwrap_or_default(),
+                    include_delta_metadata: include_delta_metadata__,
+                    include_browse: include_browse__,
+                    include_manifest_capabilities: include_manifest_capabilities__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.GetTableRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IcebergPartitionField {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.source_column.is_empty() {
+            len += 1;
+        }
+        if !self.transform.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.IcebergPartitionField", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.source_column.is_empty() {
+            struct_ser.serialize_field("sourceColumn", &self.source_column)?;
+        }
+        if !self.transform.is_empty() {
+            struct_ser.serialize_field("transform", &self.transform)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IcebergPartitionField {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "source_column",
+            "sourceColumn",
+            "transform",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            SourceColumn,
+            Transform,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "sourceColumn" | "source_column" => Ok(GeneratedField::SourceColumn),
+                            "transform" => Ok(GeneratedField::Transform),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IcebergPartitionField;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.IcebergPartitionField")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IcebergPartitionField, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut source_column__ = None;
+                let mut transform__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SourceColumn => {
+                            if source_column__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceColumn"));
+                            }
+                            source_column__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Transform => {
+                            if transform__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transform"));
+                            }
+                            transform__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IcebergPartitionField {
+                    name: name__.unwrap_or_default(),
+                    source_column: source_column__.unwrap_or_default(),
+                    transform: transform__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.IcebergPartitionField", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IcebergTableMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.metadata_location.is_empty() {
+            len += 1;
+        }
+        if self.format_version != 0 {
+            len += 1;
+        }
+        if !self.table_uuid.is_empty() {
+            len += 1;
+        }
+        if self.current_snapshot_id.is_some() {
+            len += 1;
+        }
+        if self.current_schema_id != 0 {
+            len += 1;
+        }
+        if self.default_spec_id != 0 {
+            len += 1;
+        }
+        if !self.partition_fields.is_empty() {
+            len += 1;
+        }
+        if !self.properties.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.IcebergTableMetadata", len)?;
+        if !self.metadata_location.is_empty() {
+            struct_ser.serialize_field("metadataLocation", &self.metadata_location)?;
+        }
+        if self.format_version != 0 {
+            struct_ser.serialize_field("formatVersion", &self.format_version)?;
+        }
+        if !self.table_uuid.is_empty() {
+            struct_ser.serialize_field("tableUuid", &self.table_uuid)?;
+        }
+        if let Some(v) = self.current_snapshot_id.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("currentSnapshotId", ToString::to_string(&v).as_str())?;
+        }
+        if self.current_schema_id != 0 {
+            struct_ser.serialize_field("currentSchemaId", &self.current_schema_id)?;
+        }
+        if self.default_spec_id != 0 {
+            struct_ser.serialize_field("defaultSpecId", &self.default_spec_id)?;
+        }
+        if !self.partition_fields.is_empty() {
+            struct_ser.serialize_field("partitionFields", &self.partition_fields)?;
+        }
+        if !self.properties.is_empty() {
+            struct_ser.serialize_field("properties", &self.properties)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IcebergTableMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "metadata_location",
+            "metadataLocation",
+            "format_version",
+            "formatVersion",
+            "table_uuid",
+            "tableUuid",
+            "current_snapshot_id",
+            "currentSnapshotId",
+            "current_schema_id",
+            "currentSchemaId",
+            "default_spec_id",
+            "defaultSpecId",
+            "partition_fields",
+            "partitionFields",
+            "properties",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MetadataLocation,
+            FormatVersion,
+            TableUuid,
+            CurrentSnapshotId,
+            CurrentSchemaId,
+            DefaultSpecId,
+            PartitionFields,
+            Properties,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "metadataLocation" | "metadata_location" => Ok(GeneratedField::MetadataLocation),
+                            "formatVersion" | "format_version" => Ok(GeneratedField::FormatVersion),
+                            "tableUuid" | "table_uuid" => Ok(GeneratedField::TableUuid),
+                            "currentSnapshotId" | "current_snapshot_id" => Ok(GeneratedField::CurrentSnapshotId),
+                            "currentSchemaId" | "current_schema_id" => Ok(GeneratedField::CurrentSchemaId),
+                            "defaultSpecId" | "default_spec_id" => Ok(GeneratedField::DefaultSpecId),
+                            "partitionFields" | "partition_fields" => Ok(GeneratedField::PartitionFields),
+                            "properties" => Ok(GeneratedField::Properties),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IcebergTableMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.IcebergTableMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IcebergTableMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut metadata_location__ = None;
+                let mut format_version__ = None;
+                let mut table_uuid__ = None;
+                let mut current_snapshot_id__ = None;
+                let mut current_schema_id__ = None;
+                let mut default_spec_id__ = None;
+                let mut partition_fields__ = None;
+                let mut properties__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MetadataLocation => {
+                            if metadata_location__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadataLocation"));
+                            }
+                            metadata_location__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FormatVersion => {
+                            if format_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("formatVersion"));
+                            }
+                            format_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TableUuid => {
+                            if table_uuid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tableUuid"));
+                            }
+                            table_uuid__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentSnapshotId => {
+                            if current_snapshot_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentSnapshotId"));
+                            }
+                            current_snapshot_id__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::CurrentSchemaId => {
+                            if current_schema_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentSchemaId"));
+                            }
+                            current_schema_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DefaultSpecId => {
+                            if default_spec_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("defaultSpecId"));
+                            }
+                            default_spec_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartitionFields => {
+                            if partition_fields__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partitionFields"));
+                            }
+                            partition_fields__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Properties => {
+                            if properties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("properties"));
+                            }
+                            properties__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IcebergTableMetadata {
+                    metadata_location: metadata_location__.unwrap_or_default(),
+                    format_version: format_version__.unwrap_or_default(),
+                    table_uuid: table_uuid__.unwrap_or_default(),
+                    current_snapshot_id: current_snapshot_id__,
+                    current_schema_id: current_schema_id__.unwrap_or_default(),
+                    default_spec_id: default_spec_id__.unwrap_or_default(),
+                    partition_fields: partition_fields__.unwrap_or_default(),
+                    properties: properties__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.IcebergTableMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListTableSummariesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.catalog_name.is_empty() {
+            len += 1;
+        }
+        if self.schema_name_pattern.is_some() {
+            len += 1;
+        }
+        if self.table_name_pattern.is_some() {
+            len += 1;
+        }
+        if self.max_results.is_some() {
+            len += 1;
+        }
+        if self.page_token.is_some() {
+            len += 1;
+        }
+        if self.include_manifest_capabilities.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTableSummariesRequest", len)?;
+        if !self.catalog_name.is_empty() {
+            struct_ser.serialize_field("catalogName", &self.catalog_name)?;
+        }
+        if let Some(v) = self.schema_name_pattern.as_ref() {
+            struct_ser.serialize_field("schemaNamePattern", v)?;
+        }
+        if let Some(v) = self.table_name_pattern.as_ref() {
+            struct_ser.serialize_field("tableNamePattern", v)?;
+        }
+        if let Some(v) = self.max_results.as_ref() {
+            struct_ser.serialize_field("maxResults", v)?;
+        }
+        if let Some(v) = self.page_token.as_ref() {
+            struct_ser.serialize_field("pageToken", v)?;
+        }
+        if let Some(v) = self.include_manifest_capabilities.as_ref() {
+            struct_ser.serialize_field("includeManifestCapabilities", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListTableSummariesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "catalog_name",
+            "catalogName",
+            "schema_name_pattern",
+            "schemaNamePattern",
+            "table_name_pattern",
+            "tableNamePattern",
+            "max_results",
+            "maxResults",
+            "page_token",
+            "pageToken",
+            "include_manifest_capabilities",
+            "includeManifestCapabilities",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CatalogName,
+            SchemaNamePattern,
+            TableNamePattern,
+            MaxResults,
+            PageToken,
+            IncludeManifestCapabilities,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "catalogName" | "catalog_name" => Ok(GeneratedField::CatalogName),
+                            "schemaNamePattern" | "schema_name_pattern" => Ok(GeneratedField::SchemaNamePattern),
+                            "tableNamePattern" | "table_name_pattern" => Ok(GeneratedField::TableNamePattern),
+                            "maxResults" | "max_results" => Ok(GeneratedField::MaxResults),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+                            "includeManifestCapabilities" | "include_manifest_capabilities" => Ok(GeneratedField::IncludeManifestCapabilities),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListTableSummariesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.ListTableSummariesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTableSummariesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut catalog_name__ = None;
+                let mut schema_name_pattern__ = None;
+                let mut table_name_pattern__ = None;
+                let mut max_results__ = None;
+                let mut page_token__ = None;
+                let mut include_manifest_capabilities__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CatalogName => {
+                            if catalog_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catalogName"));
+                            }
+                            catalog_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SchemaNamePattern => {
                             if schema_name_pattern__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("schemaNamePattern"));
                             }
-                            schema_name_pattern__ = map_.next_value()?;
+                            schema_name_pattern__ = map_.next_value()?;
+                        }
+                        GeneratedField::TableNamePattern => {
+                            if table_name_pattern__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tableNamePattern"));
+                            }
+                            table_name_pattern__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxResults => {
+                            if max_results__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxResults"));
+                            }
+                            max_results__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::PageToken => {
+                            if page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                            }
+                            page_token__ = map_.next_value()?;
+                        }
+                        GeneratedField::IncludeManifestCapabilities => {
+                            if include_manifest_capabilities__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeManifestCapabilities"));
+                            }
+                            include_manifest_capabilities__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ListTableSummariesRequest {
+                    catalog_name: catalog_name__.unwrap_or_default(),
+                    schema_name_pattern: schema_name_pattern__,
+                    table_name_pattern: table_name_pattern__,
+                    max_results: max_results__,
+                    page_token: page_token__,
+                    include_manifest_capabilities: include_manifest_capabilities__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTableSummariesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListTableSummariesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tables.is_empty() {
+            len += 1;
+        }
+        if self.next_page_token.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTableSummariesResponse", len)?;
+        if !self.tables.is_empty() {
+            struct_ser.serialize_field("tables", &self.tables)?;
+        }
+        if let Some(v) = self.next_page_token.as_ref() {
+            struct_ser.serialize_field("nextPageToken", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListTableSummariesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tables",
+            "next_page_token",
+            "nextPageToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Tables,
+            NextPageToken,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tables" => Ok(GeneratedField::Tables),
+                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListTableSummariesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.ListTableSummariesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTableSummariesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tables__ = None;
+                let mut next_page_token__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Tables => {
+                            if tables__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tables"));
+                            }
+                            tables__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NextPageToken => {
+                            if next_page_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                            }
+                            next_page_token__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ListTableSummariesResponse {
+                    tables: tables__.unwrap_or_default(),
+                    next_page_token: next_page_token__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTableSummariesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListTablesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.schema_name.is_empty() {
+            len += 1;
+        }
+        if !self.catalog_name.is_empty() {
+            len += 1;
+        }
+        if self.max_results.is_some() {
+            len += 1;
+        }
+        if self.page_token.is_some() {
+            len += 1;
+        }
+        if self.include_delta_metadata.is_some() {
+            len += 1;
+        }
+        if self.omit_columns.is_some() {
+            len += 1;
+        }
+        if self.omit_properties.is_some() {
+            len += 1;
+        }
+        if self.omit_username.is_some() {
+            len += 1;
+        }
+        if self.include_browse.is_some() {
+            len += 1;
+        }
+        if self.include_manifest_capabilities.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTablesRequest", len)?;
+        if !self.schema_name.is_empty() {
+            struct_ser.serialize_field("schemaName", &self.schema_name)?;
+        }
+        if !self.catalog_name.is_empty() {
+            struct_ser.serialize_field("catalogName", &self.catalog_name)?;
+        }
+        if let Some(v) = self.max_results.as_ref() {
+            struct_ser.serialize_field("maxResults", v)?;
+        }
+        if let Some(v) = self.page_token.as_ref() {
+            struct_ser.serialize_field("pageToken", v)?;
+        }
+        if let Some(v) = self.include_delta_metadata.as_ref() {
+            struct_ser.serialize_field("includeDeltaMetadata", v)?;
+        }
+        if let Some(v) = self.omit_columns.as_ref() {
+            struct_ser.serialize_field("omitColumns", v)?;
+        }
+        if let Some(v) = self.omit_properties.as_ref() {
+            struct_ser.serialize_field("omitProperties", v)?;
+        }
+        if let Some(v) = self.omit_username.as_ref() {
+            struct_ser.serialize_field("omitUsername", v)?;
+        }
+        if let Some(v) = self.include_browse.as_ref() {
+            struct_ser.serialize_field("includeBrowse", v)?;
+        }
+        if let Some(v) = self.include_manifest_capabilities.as_ref() {
+            struct_ser.serialize_field("includeManifestCapabilities", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListTablesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schema_name",
+            "schemaName",
+            "catalog_name",
+            "catalogName",
+            "max_results",
+            "maxResults",
+            "page_token",
+            "pageToken",
+            "include_delta_metadata",
+            "includeDeltaMetadata",
+            "omit_columns",
+            "omitColumns",
+            "omit_properties",
+            "omitProperties",
+            "omit_username",
+            "omitUsername",
+            "include_browse",
+            "includeBrowse",
+            "include_manifest_capabilities",
+            "includeManifestCapabilities",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SchemaName,
+            CatalogName,
+            MaxResults,
+            PageToken,
+            IncludeDeltaMetadata,
+            OmitColumns,
+            OmitProperties,
+            OmitUsername,
+            IncludeBrowse,
+            IncludeManifestCapabilities,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "schemaName" | "schema_name" => Ok(GeneratedField::SchemaName),
+                            "catalogName" | "catalog_name" => Ok(GeneratedField::CatalogName),
+                            "maxResults" | "max_results" => Ok(GeneratedField::MaxResults),
+                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+                            "includeDeltaMetadata" | "include_delta_metadata" => Ok(GeneratedField::IncludeDeltaMetadata),
+                            "omitColumns" | "omit_columns" => Ok(GeneratedField::OmitColumns),
+                            "omitProperties" | "omit_properties" => Ok(GeneratedField::OmitProperties),
+                            "omitUsername" | "omit_username" => Ok(GeneratedField::OmitUsername),
+                            "includeBrowse" | "include_browse" => Ok(GeneratedField::IncludeBrowse),
+                            "includeManifestCapabilities" | "include_manifest_capabilities" => Ok(GeneratedField::IncludeManifestCapabilities),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListTablesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.ListTablesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTablesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schema_name__ = None;
+                let mut catalog_name__ = None;
+                let mut max_results__ = None;
+                let mut page_token__ = None;
+                let mut include_delta_metadata__ = None;
+                let mut omit_columns__ = None;
+                let mut omit_properties__ = None;
+                let mut omit_username__ = None;
+                let mut include_browse__ = None;
+                let mut include_manifest_capabilities__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SchemaName => {
+                            if schema_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schemaName"));
+                            }
+                            schema_name__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::TableNamePattern => {
-                            if table_name_pattern__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tableNamePattern"));
+                        GeneratedField::CatalogName => {
+                            if catalog_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catalogName"));
                             }
-                            table_name_pattern__ = map_.next_value()?;
+                            catalog_name__ = Some(map_.next_value()?);
                         }
                         GeneratedField::MaxResults => {
                             if max_results__.is_some() {
@@ -1376,6 +3086,36 @@ impl<'de> serde::Deserialize<'de> for ListTableSummariesRequest {
                             }
                             page_token__ = map_.next_value()?;
                         }
+                        GeneratedField::IncludeDeltaMetadata => {
+                            if include_delta_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeDeltaMetadata"));
+                            }
+                            include_delta_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::OmitColumns => {
+                            if omit_columns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("omitColumns"));
+                            }
+                            omit_columns__ = map_.next_value()?;
+                        }
+                        GeneratedField::OmitProperties => {
+                            if omit_properties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("omitProperties"));
+                            }
+                            omit_properties__ = map_.next_value()?;
+                        }
+                        GeneratedField::OmitUsername => {
+                            if omit_username__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("omitUsername"));
+                            }
+                            omit_username__ = map_.next_value()?;
+                        }
+                        GeneratedField::IncludeBrowse => {
+                            if include_browse__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeBrowse"));
+                            }
+                            include_browse__ = map_.next_value()?;
+                        }
                         GeneratedField::IncludeManifestCapabilities => {
                             if include_manifest_capabilities__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("includeManifestCapabilities"));
@@ -1387,20 +3127,24 @@ impl<'de> serde::Deserialize<'de> for ListTableSummariesRequest {
                         }
                     }
                 }
-                Ok(ListTableSummariesRequest {
+                Ok(ListTablesRequest {
+                    schema_name: schema_name__.unwrap_or_default(),
                     catalog_name: catalog_name__.unwrap_or_default(),
-                    schema_name_pattern: schema_name_pattern__,
-                    table_name_pattern: table_name_pattern__,
                     max_results: max_results__,
                     page_token: page_token__,
+                    include_delta_metadata: include_delta_metadata__,
+                    omit_columns: omit_columns__,
+                    omit_properties: omit_properties__,
+                    omit_username: omit_username__,
+                    include_browse: include_browse__,
                     include_manifest_capabilities: include_manifest_capabilities__,
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTableSummariesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTablesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ListTableSummariesResponse {
+impl serde::Serialize for ListTablesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1414,7 +3158,7 @@ impl serde::Serialize for ListTableSummariesResponse {
         if self.next_page_token.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTableSummariesResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTablesResponse", len)?;
         if !self.tables.is_empty() {
             struct_ser.serialize_field("tables", &self.tables)?;
         }
@@ -1424,7 +3168,7 @@ impl serde::Serialize for ListTableSummariesResponse {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ListTableSummariesResponse {
+impl<'de> serde::Deserialize<'de> for ListTablesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1473,13 +3217,13 @@ impl<'de> serde::Deserialize<'de> for ListTableSummariesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ListTableSummariesResponse;
+            type Value = ListTablesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.ListTableSummariesResponse")
+                formatter.write_str("struct unitycatalog.tables.v1.ListTablesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTableSummariesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTablesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1504,16 +3248,16 @@ impl<'de> serde::Deserialize<'de> for ListTableSummariesResponse {
                         }
                     }
                 }
-                Ok(ListTableSummariesResponse {
+                Ok(ListTablesResponse {
                     tables: tables__.unwrap_or_default(),
                     next_page_token: next_page_token__,
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTableSummariesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTablesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ListTablesRequest {
+impl serde::Serialize for TableCommit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1521,111 +3265,85 @@ impl serde::Serialize for ListTablesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.schema_name.is_empty() {
-            len += 1;
-        }
-        if !self.catalog_name.is_empty() {
-            len += 1;
-        }
-        if self.max_results.is_some() {
-            len += 1;
-        }
-        if self.page_token.is_some() {
+        if self.version != 0 {
             len += 1;
         }
-        if self.include_delta_metadata.is_some() {
+        if self.timestamp != 0 {
             len += 1;
         }
-        if self.omit_columns.is_some() {
+        if self.operation.is_some() {
             len += 1;
         }
-        if self.omit_properties.is_some() {
+        if !self.operation_parameters.is_empty() {
             len += 1;
         }
-        if self.omit_username.is_some() {
+        if !self.operation_metrics.is_empty() {
             len += 1;
         }
-        if self.include_browse.is_some() {
+        if self.engine_info.is_some() {
             len += 1;
         }
-        if self.include_manifest_capabilities.is_some() {
+        if self.user_name.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTablesRequest", len)?;
-        if !self.schema_name.is_empty() {
-            struct_ser.serialize_field("schemaName", &self.schema_name)?;
-        }
-        if !self.catalog_name.is_empty() {
-            struct_ser.serialize_field("catalogName", &self.catalog_name)?;
-        }
-        if let Some(v) = self.max_results.as_ref() {
-            struct_ser.serialize_field("maxResults", v)?;
-        }
-        if let Some(v) = self.page_token.as_ref() {
-            struct_ser.serialize_field("pageToken", v)?;
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.TableCommit", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
         }
-        if let Some(v) = self.include_delta_metadata.as_ref() {
-            struct_ser.serialize_field("includeDeltaMetadata", v)?;
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
         }
-        if let Some(v) = self.omit_columns.as_ref() {
-            struct_ser.serialize_field("omitColumns", v)?;
+        if let Some(v) = self.operation.as_ref() {
+            struct_ser.serialize_field("operation", v)?;
         }
-        if let Some(v) = self.omit_properties.as_ref() {
-            struct_ser.serialize_field("omitProperties", v)?;
+        if !self.operation_parameters.is_empty() {
+            struct_ser.serialize_field("operationParameters", &self.operation_parameters)?;
         }
-        if let Some(v) = self.omit_username.as_ref() {
-            struct_ser.serialize_field("omitUsername", v)?;
+        if !self.operation_metrics.is_empty() {
+            struct_ser.serialize_field("operationMetrics", &self.operation_metrics)?;
         }
-        if let Some(v) = self.include_browse.as_ref() {
-            struct_ser.serialize_field("includeBrowse", v)?;
+        if let Some(v) = self.engine_info.as_ref() {
+            struct_ser.serialize_field("engineInfo", v)?;
         }
-        if let Some(v) = self.include_manifest_capabilities.as_ref() {
-            struct_ser.serialize_field("includeManifestCapabilities", v)?;
+        if let Some(v) = self.user_name.as_ref() {
+            struct_ser.serialize_field("userName", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ListTablesRequest {
+impl<'de> serde::Deserialize<'de> for TableCommit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "schema_name",
-            "schemaName",
-            "catalog_name",
-            "catalogName",
-            "max_results",
-            "maxResults",
-            "page_token",
-            "pageToken",
-            "include_delta_metadata",
-            "includeDeltaMetadata",
-            "omit_columns",
-            "omitColumns",
-            "omit_properties",
-            "omitProperties",
-            "omit_username",
-            "omitUsername",
-            "include_browse",
-            "includeBrowse",
-            "include_manifest_capabilities",
-            "includeManifestCapabilities",
+            "version",
+            "timestamp",
+            "operation",
+            "operation_parameters",
+            "operationParameters",
+            "operation_metrics",
+            "operationMetrics",
+            "engine_info",
+            "engineInfo",
+            "user_name",
+            "userName",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SchemaName,
-            CatalogName,
-            MaxResults,
-            PageToken,
-            IncludeDeltaMetadata,
-            OmitColumns,
-            OmitProperties,
-            OmitUsername,
-            IncludeBrowse,
-            IncludeManifestCapabilities,
+            Version,
+            Timestamp,
+            Operation,
+            OperationParameters,
+            OperationMetrics,
+            EngineInfo,
+            UserName,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1648,16 +3366,13 @@ impl<'de> serde::Deserialize<'de> for ListTablesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "schemaName" | "schema_name" => Ok(GeneratedField::SchemaName),
-                            "catalogName" | "catalog_name" => Ok(GeneratedField::CatalogName),
-                            "maxResults" | "max_results" => Ok(GeneratedField::MaxResults),
-                            "pageToken" | "page_token" => Ok(GeneratedField::PageToken),
-                            "includeDeltaMetadata" | "include_delta_metadata" => Ok(GeneratedField::IncludeDeltaMetadata),
-                            "omitColumns" | "omit_columns" => Ok(GeneratedField::OmitColumns),
-                            "omitProperties" | "omit_properties" => Ok(GeneratedField::OmitProperties),
-                            "omitUsername" | "omit_username" => Ok(GeneratedField::OmitUsername),
-                            "includeBrowse" | "include_browse" => Ok(GeneratedField::IncludeBrowse),
-                            "includeManifestCapabilities" | "include_manifest_capabilities" => Ok(GeneratedField::IncludeManifestCapabilities),
+                            "version" => Ok(GeneratedField::Version),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "operationParameters" | "operation_parameters" => Ok(GeneratedField::OperationParameters),
+                            "operationMetrics" | "operation_metrics" => Ok(GeneratedField::OperationMetrics),
+                            "engineInfo" | "engine_info" => Ok(GeneratedField::EngineInfo),
+                            "userName" | "user_name" => Ok(GeneratedField::UserName),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1667,113 +3382,191 @@ impl<'de> serde::Deserialize<'de> for ListTablesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ListTablesRequest;
+            type Value = TableCommit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.ListTablesRequest")
+                formatter.write_str("struct unitycatalog.tables.v1.TableCommit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTablesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TableCommit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut schema_name__ = None;
-                let mut catalog_name__ = None;
-                let mut max_results__ = None;
-                let mut page_token__ = None;
-                let mut include_delta_metadata__ = None;
-                let mut omit_columns__ = None;
-                let mut omit_properties__ = None;
-                let mut omit_username__ = None;
-                let mut include_browse__ = None;
-                let mut include_manifest_capabilities__ = None;
+                let mut version__ = None;
+                let mut timestamp__ = None;
+                let mut operation__ = None;
+                let mut operation_parameters__ = None;
+                let mut operation_metrics__ = None;
+                let mut engine_info__ = None;
+                let mut user_name__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SchemaName => {
-                            if schema_name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("schemaName"));
-                            }
-                            schema_name__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::CatalogName => {
-                            if catalog_name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("catalogName"));
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
                             }
-                            catalog_name__ = Some(map_.next_value()?);
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::MaxResults => {
-                            if max_results__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxResults"));
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
                             }
-                            max_results__ = 
-                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::PageToken => {
-                            if page_token__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pageToken"));
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
                             }
-                            page_token__ = map_.next_value()?;
+                            operation__ = map_.next_value()?;
                         }
-                        GeneratedField::IncludeDeltaMetadata => {
-                            if include_delta_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeDeltaMetadata"));
+                        GeneratedField::OperationParameters => {
+                            if operation_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationParameters"));
                             }
-                            include_delta_metadata__ = map_.next_value()?;
+                            operation_parameters__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
                         }
-                        GeneratedField::OmitColumns => {
-                            if omit_columns__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("omitColumns"));
+                        GeneratedField::OperationMetrics => {
+                            if operation_metrics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationMetrics"));
                             }
-                            omit_columns__ = map_.next_value()?;
+                            operation_metrics__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
                         }
-                        GeneratedField::OmitProperties => {
-                            if omit_properties__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("omitProperties"));
+                        GeneratedField::EngineInfo => {
+                            if engine_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("engineInfo"));
                             }
-                            omit_properties__ = map_.next_value()?;
+                            engine_info__ = map_.next_value()?;
                         }
-                        GeneratedField::OmitUsername => {
-                            if omit_username__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("omitUsername"));
+                        GeneratedField::UserName => {
+                            if user_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userName"));
                             }
-                            omit_username__ = map_.next_value()?;
+                            user_name__ = map_.next_value()?;
                         }
-                        GeneratedField::IncludeBrowse => {
-                            if include_browse__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeBrowse"));
-                            }
-                            include_browse__ = map_.next_value()?;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TableCommit {
+                    version: version__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    operation: operation__,
+                    operation_parameters: operation_parameters__.unwrap_or_default(),
+                    operation_metrics: operation_metrics__.unwrap_or_default(),
+                    engine_info: engine_info__,
+                    user_name: user_name__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.TableCommit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TableDependency {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.table_full_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.TableDependency", len)?;
+        if !self.table_full_name.is_empty() {
+            struct_ser.serialize_field("tableFullName", &self.table_full_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TableDependency {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "table_full_name",
+            "tableFullName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TableFullName,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tableFullName" | "table_full_name" => Ok(GeneratedField::TableFullName),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::IncludeManifestCapabilities => {
-                            if include_manifest_capabilities__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeManifestCapabilities"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TableDependency;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.TableDependency")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TableDependency, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut table_full_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TableFullName => {
+                            if table_full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tableFullName"));
                             }
-                            include_manifest_capabilities__ = map_.next_value()?;
+                            table_full_name__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ListTablesRequest {
-                    schema_name: schema_name__.unwrap_or_default(),
-                    catalog_name: catalog_name__.unwrap_or_default(),
-                    max_results: max_results__,
-                    page_token: page_token__,
-                    include_delta_metadata: include_delta_metadata__,
-                    omit_columns: omit_columns__,
-                    omit_properties: omit_properties__,
-                    omit_username: omit_username__,
-                    include_browse: include_browse__,
-                    include_manifest_capabilities: include_manifest_capabilities__,
+                Ok(TableDependency {
+                    table_full_name: table_full_name__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTablesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.TableDependency", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ListTablesResponse {
+impl serde::Serialize for TableDetail {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1781,38 +3574,122 @@ impl serde::Serialize for ListTablesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.tables.is_empty() {
+        if !self.full_name.is_empty() {
             len += 1;
         }
-        if self.next_page_token.is_some() {
+        if self.version != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.ListTablesResponse", len)?;
-        if !self.tables.is_empty() {
-            struct_ser.serialize_field("tables", &self.tables)?;
+        if self.num_files != 0 {
+            len += 1;
         }
-        if let Some(v) = self.next_page_token.as_ref() {
-            struct_ser.serialize_field("nextPageToken", v)?;
+        if self.size_in_bytes != 0 {
+            len += 1;
+        }
+        if !self.file_size_histogram.is_empty() {
+            len += 1;
+        }
+        if self.num_partitions != 0 {
+            len += 1;
+        }
+        if self.partition_skew != 0. {
+            len += 1;
+        }
+        if self.small_file_ratio != 0. {
+            len += 1;
+        }
+        if self.small_file_threshold_bytes != 0 {
+            len += 1;
+        }
+        if self.num_files_with_deletion_vectors != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.TableDetail", len)?;
+        if !self.full_name.is_empty() {
+            struct_ser.serialize_field("fullName", &self.full_name)?;
+        }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if self.num_files != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("numFiles", ToString::to_string(&self.num_files).as_str())?;
+        }
+        if self.size_in_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sizeInBytes", ToString::to_string(&self.size_in_bytes).as_str())?;
+        }
+        if !self.file_size_histogram.is_empty() {
+            struct_ser.serialize_field("fileSizeHistogram", &self.file_size_histogram)?;
+        }
+        if self.num_partitions != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("numPartitions", ToString::to_string(&self.num_partitions).as_str())?;
+        }
+        if self.partition_skew != 0. {
+            struct_ser.serialize_field("partitionSkew", &self.partition_skew)?;
+        }
+        if self.small_file_ratio != 0. {
+            struct_ser.serialize_field("smallFileRatio", &self.small_file_ratio)?;
+        }
+        if self.small_file_threshold_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("smallFileThresholdBytes", ToString::to_string(&self.small_file_threshold_bytes).as_str())?;
+        }
+        if self.num_files_with_deletion_vectors != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("numFilesWithDeletionVectors", ToString::to_string(&self.num_files_with_deletion_vectors).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ListTablesResponse {
+impl<'de> serde::Deserialize<'de> for TableDetail {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "tables",
-            "next_page_token",
-            "nextPageToken",
+            "full_name",
+            "fullName",
+            "version",
+            "num_files",
+            "numFiles",
+            "size_in_bytes",
+            "sizeInBytes",
+            "file_size_histogram",
+            "fileSizeHistogram",
+            "num_partitions",
+            "numPartitions",
+            "partition_skew",
+            "partitionSkew",
+            "small_file_ratio",
+            "smallFileRatio",
+            "small_file_threshold_bytes",
+            "smallFileThresholdBytes",
+            "num_files_with_deletion_vectors",
+            "numFilesWithDeletionVectors",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Tables,
-            NextPageToken,
+            FullName,
+            Version,
+            NumFiles,
+            SizeInBytes,
+            FileSizeHistogram,
+            NumPartitions,
+            PartitionSkew,
+            SmallFileRatio,
+            SmallFileThresholdBytes,
+            NumFilesWithDeletionVectors,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1835,8 +3712,16 @@ impl<'de> serde::Deserialize<'de> for ListTablesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tables" => Ok(GeneratedField::Tables),
-                            "nextPageToken" | "next_page_token" => Ok(GeneratedField::NextPageToken),
+                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            "version" => Ok(GeneratedField::Version),
+                            "numFiles" | "num_files" => Ok(GeneratedField::NumFiles),
+                            "sizeInBytes" | "size_in_bytes" => Ok(GeneratedField::SizeInBytes),
+                            "fileSizeHistogram" | "file_size_histogram" => Ok(GeneratedField::FileSizeHistogram),
+                            "numPartitions" | "num_partitions" => Ok(GeneratedField::NumPartitions),
+                            "partitionSkew" | "partition_skew" => Ok(GeneratedField::PartitionSkew),
+                            "smallFileRatio" | "small_file_ratio" => Ok(GeneratedField::SmallFileRatio),
+                            "smallFileThresholdBytes" | "small_file_threshold_bytes" => Ok(GeneratedField::SmallFileThresholdBytes),
+                            "numFilesWithDeletionVectors" | "num_files_with_deletion_vectors" => Ok(GeneratedField::NumFilesWithDeletionVectors),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1846,44 +3731,124 @@ impl<'de> serde::Deserialize<'de> for ListTablesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ListTablesResponse;
+            type Value = TableDetail;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct unitycatalog.tables.v1.ListTablesResponse")
+                formatter.write_str("struct unitycatalog.tables.v1.TableDetail")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListTablesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TableDetail, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut tables__ = None;
-                let mut next_page_token__ = None;
+                let mut full_name__ = None;
+                let mut version__ = None;
+                let mut num_files__ = None;
+                let mut size_in_bytes__ = None;
+                let mut file_size_histogram__ = None;
+                let mut num_partitions__ = None;
+                let mut partition_skew__ = None;
+                let mut small_file_ratio__ = None;
+                let mut small_file_threshold_bytes__ = None;
+                let mut num_files_with_deletion_vectors__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Tables => {
-                            if tables__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tables"));
+                        GeneratedField::FullName => {
+                            if full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullName"));
                             }
-                            tables__ = Some(map_.next_value()?);
+                            full_name__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::NextPageToken => {
-                            if next_page_token__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nextPageToken"));
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
                             }
-                            next_page_token__ = map_.next_value()?;
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NumFiles => {
+                            if num_files__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numFiles"));
+                            }
+                            num_files__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SizeInBytes => {
+                            if size_in_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeInBytes"));
+                            }
+                            size_in_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FileSizeHistogram => {
+                            if file_size_histogram__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fileSizeHistogram"));
+                            }
+                            file_size_histogram__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NumPartitions => {
+                            if num_partitions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numPartitions"));
+                            }
+                            num_partitions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartitionSkew => {
+                            if partition_skew__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partitionSkew"));
+                            }
+                            partition_skew__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SmallFileRatio => {
+                            if small_file_ratio__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("smallFileRatio"));
+                            }
+                            small_file_ratio__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SmallFileThresholdBytes => {
+                            if small_file_threshold_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("smallFileThresholdBytes"));
+                            }
+                            small_file_threshold_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NumFilesWithDeletionVectors => {
+                            if num_files_with_deletion_vectors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numFilesWithDeletionVectors"));
+                            }
+                            num_files_with_deletion_vectors__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ListTablesResponse {
-                    tables: tables__.unwrap_or_default(),
-                    next_page_token: next_page_token__,
+                Ok(TableDetail {
+                    full_name: full_name__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                    num_files: num_files__.unwrap_or_default(),
+                    size_in_bytes: size_in_bytes__.unwrap_or_default(),
+                    file_size_histogram: file_size_histogram__.unwrap_or_default(),
+                    num_partitions: num_partitions__.unwrap_or_default(),
+                    partition_skew: partition_skew__.unwrap_or_default(),
+                    small_file_ratio: small_file_ratio__.unwrap_or_default(),
+                    small_file_threshold_bytes: small_file_threshold_bytes__.unwrap_or_default(),
+                    num_files_with_deletion_vectors: num_files_with_deletion_vectors__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("unitycatalog.tables.v1.ListTablesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("unitycatalog.tables.v1.TableDetail", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for TableInfo {
@@ -1915,6 +3880,12 @@ impl serde::Serialize for TableInfo {
         if self.storage_location.is_some() {
             len += 1;
         }
+        if self.view_definition.is_some() {
+            len += 1;
+        }
+        if self.view_dependencies.is_some() {
+            len += 1;
+        }
         if self.owner.is_some() {
             len += 1;
         }
@@ -1948,6 +3919,12 @@ impl serde::Serialize for TableInfo {
         if self.table_id.is_some() {
             len += 1;
         }
+        if self.delta_metadata.is_some() {
+            len += 1;
+        }
+        if self.iceberg_metadata.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.TableInfo", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -1974,6 +3951,12 @@ impl serde::Serialize for TableInfo {
         if let Some(v) = self.storage_location.as_ref() {
             struct_ser.serialize_field("storageLocation", v)?;
         }
+        if let Some(v) = self.view_definition.as_ref() {
+            struct_ser.serialize_field("viewDefinition", v)?;
+        }
+        if let Some(v) = self.view_dependencies.as_ref() {
+            struct_ser.serialize_field("viewDependencies", v)?;
+        }
         if let Some(v) = self.owner.as_ref() {
             struct_ser.serialize_field("owner", v)?;
         }
@@ -2013,6 +3996,12 @@ impl serde::Serialize for TableInfo {
         if let Some(v) = self.table_id.as_ref() {
             struct_ser.serialize_field("tableId", v)?;
         }
+        if let Some(v) = self.delta_metadata.as_ref() {
+            struct_ser.serialize_field("deltaMetadata", v)?;
+        }
+        if let Some(v) = self.iceberg_metadata.as_ref() {
+            struct_ser.serialize_field("icebergMetadata", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2035,6 +4024,10 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
             "columns",
             "storage_location",
             "storageLocation",
+            "view_definition",
+            "viewDefinition",
+            "view_dependencies",
+            "viewDependencies",
             "owner",
             "comment",
             "properties",
@@ -2054,6 +4047,10 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
             "deletedAt",
             "table_id",
             "tableId",
+            "delta_metadata",
+            "deltaMetadata",
+            "iceberg_metadata",
+            "icebergMetadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2065,6 +4062,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
             DataSourceFormat,
             Columns,
             StorageLocation,
+            ViewDefinition,
+            ViewDependencies,
             Owner,
             Comment,
             Properties,
@@ -2076,6 +4075,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
             UpdatedBy,
             DeletedAt,
             TableId,
+            DeltaMetadata,
+            IcebergMetadata,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2105,6 +4106,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                             "dataSourceFormat" | "data_source_format" => Ok(GeneratedField::DataSourceFormat),
                             "columns" => Ok(GeneratedField::Columns),
                             "storageLocation" | "storage_location" => Ok(GeneratedField::StorageLocation),
+                            "viewDefinition" | "view_definition" => Ok(GeneratedField::ViewDefinition),
+                            "viewDependencies" | "view_dependencies" => Ok(GeneratedField::ViewDependencies),
                             "owner" => Ok(GeneratedField::Owner),
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
@@ -2116,6 +4119,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "deletedAt" | "deleted_at" => Ok(GeneratedField::DeletedAt),
                             "tableId" | "table_id" => Ok(GeneratedField::TableId),
+                            "deltaMetadata" | "delta_metadata" => Ok(GeneratedField::DeltaMetadata),
+                            "icebergMetadata" | "iceberg_metadata" => Ok(GeneratedField::IcebergMetadata),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2142,6 +4147,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                 let mut data_source_format__ = None;
                 let mut columns__ = None;
                 let mut storage_location__ = None;
+                let mut view_definition__ = None;
+                let mut view_dependencies__ = None;
                 let mut owner__ = None;
                 let mut comment__ = None;
                 let mut properties__ = None;
@@ -2153,6 +4160,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                 let mut updated_by__ = None;
                 let mut deleted_at__ = None;
                 let mut table_id__ = None;
+                let mut delta_metadata__ = None;
+                let mut iceberg_metadata__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -2197,6 +4206,18 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                             }
                             storage_location__ = map_.next_value()?;
                         }
+                        GeneratedField::ViewDefinition => {
+                            if view_definition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("viewDefinition"));
+                            }
+                            view_definition__ = map_.next_value()?;
+                        }
+                        GeneratedField::ViewDependencies => {
+                            if view_dependencies__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("viewDependencies"));
+                            }
+                            view_dependencies__ = map_.next_value()?;
+                        }
                         GeneratedField::Owner => {
                             if owner__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("owner"));
@@ -2269,6 +4290,18 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                             }
                             table_id__ = map_.next_value()?;
                         }
+                        GeneratedField::DeltaMetadata => {
+                            if delta_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deltaMetadata"));
+                            }
+                            delta_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::IcebergMetadata => {
+                            if iceberg_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("icebergMetadata"));
+                            }
+                            iceberg_metadata__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2282,6 +4315,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                     data_source_format: data_source_format__.unwrap_or_default(),
                     columns: columns__.unwrap_or_default(),
                     storage_location: storage_location__,
+                    view_definition: view_definition__,
+                    view_dependencies: view_dependencies__,
                     owner: owner__,
                     comment: comment__,
                     properties: properties__,
@@ -2293,6 +4328,8 @@ impl<'de> serde::Deserialize<'de> for TableInfo {
                     updated_by: updated_by__,
                     deleted_at: deleted_at__,
                     table_id: table_id__,
+                    delta_metadata: delta_metadata__,
+                    iceberg_metadata: iceberg_metadata__,
                 })
             }
         }
@@ -2425,6 +4462,7 @@ impl serde::Serialize for TableType {
             Self::Unspecified => "TABLE_TYPE_UNSPECIFIED",
             Self::Managed => "MANAGED",
             Self::External => "EXTERNAL",
+            Self::View => "VIEW",
         };
         serializer.serialize_str(variant)
     }
@@ -2439,6 +4477,7 @@ impl<'de> serde::Deserialize<'de> for TableType {
             "TABLE_TYPE_UNSPECIFIED",
             "MANAGED",
             "EXTERNAL",
+            "VIEW",
         ];
 
         struct GeneratedVisitor;
@@ -2482,6 +4521,7 @@ impl<'de> serde::Deserialize<'de> for TableType {
                     "TABLE_TYPE_UNSPECIFIED" => Ok(TableType::Unspecified),
                     "MANAGED" => Ok(TableType::Managed),
                     "EXTERNAL" => Ok(TableType::External),
+                    "VIEW" => Ok(TableType::View),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -2489,3 +4529,99 @@ impl<'de> serde::Deserialize<'de> for TableType {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for UndropTableRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.full_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.UndropTableRequest", len)?;
+        if !self.full_name.is_empty() {
+            struct_ser.serialize_field("fullName", &self.full_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndropTableRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_name",
+            "fullName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullName,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndropTableRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.UndropTableRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndropTableRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullName => {
+                            if full_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullName"));
+                            }
+                            full_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UndropTableRequest {
+                    full_name: full_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.UndropTableRequest", FIELDS, GeneratedVisitor)
+    }
+}
@@ -62,6 +62,33 @@ pub mod tables_service_server {
             &self,
             request: tonic::Request<super::DeleteTableRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// Restore a dropped managed table.
+        ///
+        /// Dropped managed tables are retained until the configured retention period has passed
+        /// and can be restored until their data is deleted.
+        async fn undrop_table(
+            &self,
+            request: tonic::Request<super::UndropTableRequest>,
+        ) -> std::result::Result<tonic::Response<super::TableInfo>, tonic::Status>;
+        /// Get the commit history of a delta table.
+        ///
+        /// Commits are read from the delta log, latest first. Older commits can be paged through
+        /// by passing the returned next_end_version as end_version.
+        async fn get_table_history(
+            &self,
+            request: tonic::Request<super::GetTableHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTableHistoryResponse>,
+            tonic::Status,
+        >;
+        /// Get statistics about the data files of a delta table.
+        ///
+        /// The statistics are computed from the latest snapshot of the table and help to find
+        /// tables that need compaction, e.g. due to many small files or skewed partitions.
+        async fn get_table_detail(
+            &self,
+            request: tonic::Request<super::GetTableDetailRequest>,
+        ) -> std::result::Result<tonic::Response<super::TableDetail>, tonic::Status>;
     }
     ///
     #[derive(Debug)]
@@ -412,6 +439,143 @@ pub mod tables_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/unitycatalog.tables.v1.TablesService/UndropTable" => {
+                    #[allow(non_camel_case_types)]
+                    struct UndropTableSvc<T: TablesService>(pub Arc<T>);
+                    impl<
+                        T: TablesService,
+                    > tonic::server::UnaryService<super::UndropTableRequest>
+                    for UndropTableSvc<T> {
+                        type Response = super::TableInfo;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UndropTableRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TablesService>::undrop_table(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UndropTableSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/unitycatalog.tables.v1.TablesService/GetTableHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTableHistorySvc<T: TablesService>(pub Arc<T>);
+                    impl<
+                        T: TablesService,
+                    > tonic::server::UnaryService<super::GetTableHistoryRequest>
+                    for GetTableHistorySvc<T> {
+                        type Response = super::GetTableHistoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTableHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TablesService>::get_table_history(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTableHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/unitycatalog.tables.v1.TablesService/GetTableDetail" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTableDetailSvc<T: TablesService>(pub Arc<T>);
+                    impl<
+                        T: TablesService,
+                    > tonic::server::UnaryService<super::GetTableDetailRequest>
+                    for GetTableDetailSvc<T> {
+                        type Response = super::TableDetail;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTableDetailRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TablesService>::get_table_detail(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTableDetailSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -5,6 +5,8 @@ pub use unitycatalog_derive as derive;
 
 pub mod api;
 pub mod error;
+#[cfg(feature = "flight")]
+pub mod flight;
 #[cfg(feature = "memory")]
 pub mod memory;
 pub mod models;
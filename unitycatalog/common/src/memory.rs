@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use dashmap::DashMap;
@@ -5,9 +6,11 @@ use uuid::Uuid;
 
 use crate::models::{AssociationLabel, ObjectLabel, PropertyMap, Resource};
 use crate::resources::{
-    ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore, ResourceStoreReader,
+    NamePattern, ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore,
+    ResourceStoreReader, name_matches,
 };
-use crate::services::secrets::SecretManager;
+use crate::services::secrets::{SecretManager, SecretVersion};
+use crate::services::{StorageLocationUrl, normalize_location_url};
 use crate::{Error, Result};
 
 const MAX_PAGE_SIZE: usize = 10000;
@@ -19,8 +22,11 @@ const MAX_PAGE_SIZE: usize = 10000;
 pub struct InMemoryResourceStore {
     resources: Arc<DashMap<Uuid, Resource>>,
     id_map: Arc<DashMap<ObjectLabel, DashMap<ResourceName, Uuid>>>,
-    associations: Arc<DashMap<AssociationLabel, DashMap<Uuid, (Uuid, Option<PropertyMap>)>>>,
+    /// Targets and properties of the associations of each resource, by label.
+    associations: Arc<DashMap<AssociationLabel, DashMap<Uuid, HashMap<Uuid, Option<PropertyMap>>>>>,
     secrets: Arc<DashMap<String, DashMap<Uuid, bytes::Bytes>>>,
+    /// Normalized external location urls, used for prefix lookups.
+    location_index: Arc<DashMap<String, Uuid>>,
 }
 
 impl Default for InMemoryResourceStore {
@@ -36,6 +42,21 @@ impl InMemoryResourceStore {
             id_map: DashMap::new().into(),
             associations: DashMap::new().into(),
             secrets: DashMap::new().into(),
+            location_index: DashMap::new().into(),
+        }
+    }
+
+    fn index_location(&self, uuid: Uuid, resource: &Resource) {
+        if let Resource::ExternalLocationInfo(info) = resource {
+            self.location_index
+                .insert(normalize_location_url(&info.url).to_string(), uuid);
+        }
+    }
+
+    fn unindex_location(&self, resource: &Resource) {
+        if let Resource::ExternalLocationInfo(info) = resource {
+            self.location_index
+                .remove(normalize_location_url(&info.url));
         }
     }
 
@@ -85,6 +106,18 @@ impl ResourceStoreReader for InMemoryResourceStore {
         namespace: Option<&ResourceName>,
         max_results: Option<usize>,
         page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        self.list_matching(label, namespace, &[], max_results, page_token)
+            .await
+    }
+
+    async fn list_matching(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        patterns: &[Option<NamePattern>],
+        max_results: Option<usize>,
+        page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)> {
         let page_token = page_token.map(|t| Uuid::parse_str(&t)).transpose()?;
         let mut resource_ids = self
@@ -96,6 +129,7 @@ impl ResourceStoreReader for InMemoryResourceStore {
                     .filter(|entry| {
                         namespace.is_none_or(|ns| entry.key().prefix_matches(ns))
                             && page_token.is_none_or(|t| &t > entry.value())
+                            && name_matches(entry.key(), patterns)
                     })
                     .map(|entry| *entry.value())
                     .collect::<Vec<_>>()
@@ -117,6 +151,18 @@ impl ResourceStoreReader for InMemoryResourceStore {
         let next_page_token = (resources.len() == max_page_size).then(|| last_id.to_string());
         Ok((resources, next_page_token))
     }
+
+    async fn find_external_location(
+        &self,
+        location: &StorageLocationUrl,
+    ) -> Result<(Resource, ResourceRef)> {
+        let uuid = location
+            .prefixes()
+            .iter()
+            .find_map(|prefix| self.location_index.get(prefix).map(|uuid| *uuid.value()))
+            .ok_or(Error::NotFound)?;
+        self.get(&ResourceIdent::external_location(uuid)).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -129,6 +175,7 @@ impl ResourceStore for InMemoryResourceStore {
             return Err(Error::AlreadyExists);
         }
         let uuid = self.new_uuid(resource.resource_label(), &resource.resource_name())?;
+        self.index_location(uuid, &resource);
         self.resources.insert(uuid, resource.clone());
         Ok((resource, ResourceRef::Uuid(uuid)))
     }
@@ -140,7 +187,16 @@ impl ResourceStore for InMemoryResourceStore {
             ResourceRef::Undefined => return Err(Error::NotFound),
         };
         match self.resources.remove(&uuid) {
-            Some((_, resource)) => self.remove_uuid(id.label(), &resource.resource_name()),
+            Some((_, resource)) => {
+                self.unindex_location(&resource);
+                for map in self.associations.iter() {
+                    map.remove(&uuid);
+                    for mut targets in map.iter_mut() {
+                        targets.remove(&uuid);
+                    }
+                }
+                self.remove_uuid(id.label(), &resource.resource_name())
+            }
             None => None,
         };
         Ok(())
@@ -179,6 +235,8 @@ impl ResourceStore for InMemoryResourceStore {
                 .get(existing.resource_label())
                 .and_then(|map| map.value().insert(resource.resource_name(), uuid));
         }
+        self.unindex_location(&existing);
+        self.index_location(uuid, &resource);
         self.resources.insert(uuid, resource.clone());
         Ok((resource, ResourceRef::Uuid(uuid)))
     }
@@ -200,11 +258,19 @@ impl ResourceStore for InMemoryResourceStore {
             ResourceRef::Name(name) => self.get_uuid(to.label(), name).ok_or(Error::NotFound)?,
             ResourceRef::Undefined => return Err(Error::NotFound),
         };
-        let map = self.associations.entry(label.clone()).or_default();
-        map.insert(from_uuid, (to_uuid, properties.clone()));
+        self.associations
+            .entry(label.clone())
+            .or_default()
+            .entry(from_uuid)
+            .or_default()
+            .insert(to_uuid, properties.clone());
         if let Some(inverse) = label.inverse() {
-            let inverse_map = self.associations.entry(inverse).or_default();
-            inverse_map.insert(to_uuid, (from_uuid, properties.clone()));
+            self.associations
+                .entry(inverse)
+                .or_default()
+                .entry(to_uuid)
+                .or_default()
+                .insert(from_uuid, properties);
         }
         Ok(())
     }
@@ -225,11 +291,19 @@ impl ResourceStore for InMemoryResourceStore {
             ResourceRef::Name(name) => self.get_uuid(to.label(), name).ok_or(Error::NotFound)?,
             ResourceRef::Undefined => return Err(Error::NotFound),
         };
-        let map = self.associations.get(label).ok_or(Error::NotFound)?;
-        map.remove(&from_uuid);
+        let removed = self
+            .associations
+            .get(label)
+            .and_then(|map| map.get_mut(&from_uuid)?.remove(&to_uuid));
+        if removed.is_none() {
+            return Err(Error::NotFound);
+        }
         if let Some(inverse) = label.inverse() {
-            let inverse_map = self.associations.get(&inverse).ok_or(Error::NotFound)?;
-            inverse_map.remove(&to_uuid);
+            if let Some(map) = self.associations.get(&inverse) {
+                if let Some(mut targets) = map.get_mut(&to_uuid) {
+                    targets.remove(&from_uuid);
+                }
+            }
         }
         Ok(())
     }
@@ -264,16 +338,17 @@ impl ResourceStore for InMemoryResourceStore {
         let mut association_ids = self
             .associations
             .get(label)
-            .map(|map| {
-                map.value()
-                    .get(&resource_uuid)
-                    .iter()
-                    .filter(|entry| {
-                        target_uuid.is_none_or(|uuid| entry.value().0 == uuid)
-                            && page_token.is_none_or(|t| &t > entry.key())
-                    })
-                    .map(|entry| entry.value().0)
-                    .collect::<Vec<_>>()
+            .and_then(|map| {
+                map.get(&resource_uuid).map(|targets| {
+                    targets
+                        .keys()
+                        .filter(|uuid| {
+                            target_uuid.is_none_or(|t| &t == *uuid)
+                                && page_token.is_none_or(|t| &t > *uuid)
+                        })
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
             })
             .unwrap_or_default();
         if association_ids.is_empty() {
@@ -340,11 +415,7 @@ impl SecretManager for InMemoryResourceStore {
     }
 
     async fn update_secret(&self, secret_name: &str, secret_value: bytes::Bytes) -> Result<Uuid> {
-        let map = self
-            .secrets
-            .get(secret_name)
-            .map(|entry| entry.value().clone())
-            .ok_or(Error::NotFound)?;
+        let map = self.secrets.get(secret_name).ok_or(Error::NotFound)?;
         let uuid = Uuid::now_v7();
         map.insert(uuid, secret_value);
         Ok(uuid)
@@ -354,12 +425,47 @@ impl SecretManager for InMemoryResourceStore {
         self.secrets.remove(secret_name).ok_or(Error::NotFound)?;
         Ok(())
     }
+
+    async fn list_secret_versions(&self, secret_name: &str) -> Result<Vec<SecretVersion>> {
+        let map = self.secrets.get(secret_name).ok_or(Error::NotFound)?;
+        let mut versions = map
+            .iter()
+            .map(|entry| {
+                // versions are v7 uuids, which encode their creation time.
+                let (secs, nanos) = entry
+                    .key()
+                    .get_timestamp()
+                    .map(|ts| ts.to_unix())
+                    .unwrap_or_default();
+                SecretVersion {
+                    version: *entry.key(),
+                    created_at: chrono::DateTime::from_timestamp(secs as i64, nanos)
+                        .unwrap_or_default(),
+                }
+            })
+            .collect::<Vec<_>>();
+        versions.sort_by_key(|v| std::cmp::Reverse(v.version));
+        Ok(versions)
+    }
+
+    async fn delete_secret_version(&self, secret_name: &str, version: Uuid) -> Result<()> {
+        let map = self.secrets.get(secret_name).ok_or(Error::NotFound)?;
+        if map.iter().map(|entry| *entry.key()).max() == Some(version) {
+            return Err(Error::invalid_argument(
+                "the current version of a secret cannot be deleted",
+            ));
+        }
+        map.remove(&version).ok_or(Error::NotFound)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{CatalogInfo, ObjectLabel};
+    use crate::models::external_locations::v1::ExternalLocationInfo;
+    use crate::models::{CatalogInfo, ObjectLabel, TableInfo};
+    use crate::services::secrets::SecretRetention;
 
     #[tokio::test]
     async fn test_create_get_delete() {
@@ -382,6 +488,150 @@ mod tests {
         assert!(matches!(result.unwrap_err(), Error::NotFound));
     }
 
+    #[tokio::test]
+    async fn test_list_matching() {
+        let store = InMemoryResourceStore::new();
+        for (schema, table) in [
+            ("sales", "orders"),
+            ("sales", "orders_raw"),
+            ("sales_eu", "orders"),
+            ("hr", "employees"),
+        ] {
+            let table: Resource = TableInfo {
+                name: table.into(),
+                schema_name: schema.into(),
+                catalog_name: "main".into(),
+                full_name: Some(format!("main.{schema}.{table}")),
+                ..Default::default()
+            }
+            .into();
+            store.create(table).await.unwrap();
+        }
+
+        let list = |schema: Option<&str>, table: Option<&str>, max_results| {
+            let patterns = [
+                None,
+                schema.map(NamePattern::new),
+                table.map(NamePattern::new),
+            ];
+            let store = store.clone();
+            async move {
+                let namespace = ResourceName::new(["main"]);
+                let (resources, token) = store
+                    .list_matching(
+                        &ObjectLabel::TableInfo,
+                        Some(&namespace),
+                        &patterns,
+                        max_results,
+                        None,
+                    )
+                    .await
+                    .unwrap();
+                let mut names = resources
+                    .iter()
+                    .map(|r| r.resource_name().to_string())
+                    .collect::<Vec<_>>();
+                names.sort();
+                (names, token)
+            }
+        };
+
+        let (names, _) = list(Some("sales"), None, None).await;
+        assert_eq!(names, vec!["main.sales.orders", "main.sales.orders_raw"]);
+        let (names, _) = list(Some("sales*"), Some("orders"), None).await;
+        assert_eq!(names, vec!["main.sales.orders", "main.sales_eu.orders"]);
+        let (names, _) = list(None, Some("%_raw"), None).await;
+        assert_eq!(names, vec!["main.sales.orders_raw"]);
+
+        // pages are filled with matching resources only
+        let (names, token) = list(Some("SALES%"), None, Some(3)).await;
+        assert_eq!(names.len(), 3);
+        assert!(token.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_associations() {
+        let store = InMemoryResourceStore::new();
+        let mut idents = Vec::new();
+        for name in ["view", "orders", "customers"] {
+            let table: Resource = TableInfo {
+                name: name.into(),
+                schema_name: "sales".into(),
+                catalog_name: "main".into(),
+                full_name: Some(format!("main.sales.{name}")),
+                ..Default::default()
+            }
+            .into();
+            let (_, reference) = store.create(table).await.unwrap();
+            idents.push(ResourceIdent::table(reference));
+        }
+        let [view, orders, customers] = idents.as_slice() else {
+            unreachable!()
+        };
+        for table in [orders, customers] {
+            store
+                .add_association(view, table, &AssociationLabel::DependsOn, None)
+                .await
+                .unwrap();
+        }
+
+        let (deps, _) = store
+            .list_associations(view, &AssociationLabel::DependsOn, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(deps.len(), 2);
+        let (dependents, _) = store
+            .list_associations(orders, &AssociationLabel::DependencyOf, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(dependents, vec![view.clone()]);
+
+        store
+            .remove_association(view, orders, &AssociationLabel::DependsOn)
+            .await
+            .unwrap();
+        let (deps, _) = store
+            .list_associations(view, &AssociationLabel::DependsOn, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(deps, vec![customers.clone()]);
+
+        // deleting a resource removes its associations
+        store.delete(customers).await.unwrap();
+        let (deps, _) = store
+            .list_associations(view, &AssociationLabel::DependsOn, None, None, None)
+            .await
+            .unwrap();
+        assert!(deps.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_secret_versions() {
+        let store = InMemoryResourceStore::new();
+        let v1 = store.create_secret("cred", "v1".into()).await.unwrap();
+        let v2 = store.update_secret("cred", "v2".into()).await.unwrap();
+        let v3 = store.update_secret("cred", "v3".into()).await.unwrap();
+
+        let (current, value) = store.get_secret("cred").await.unwrap();
+        assert_eq!((current, value), (v3, "v3".into()));
+        let versions = store.list_secret_versions("cred").await.unwrap();
+        let ids = versions.iter().map(|v| v.version).collect::<Vec<_>>();
+        assert_eq!(ids, vec![v3, v2, v1]);
+
+        let result = store.delete_secret_version("cred", v3).await;
+        assert!(matches!(result.unwrap_err(), Error::InvalidArgument(_)));
+
+        let retention = SecretRetention::new().with_keep_last(2);
+        let pruned = store
+            .prune_secret_versions("cred", &retention)
+            .await
+            .unwrap();
+        assert_eq!(pruned, 1);
+        let result = store.get_secret_version("cred", v1).await;
+        assert!(matches!(result.unwrap_err(), Error::NotFound));
+        assert!(store.get_secret_version("cred", v2).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_list() {
         let store = InMemoryResourceStore::new();
@@ -428,4 +678,42 @@ mod tests {
         assert_eq!(resources.len(), 1);
         assert!(next.is_none());
     }
+
+    #[tokio::test]
+    async fn test_find_external_location() {
+        let store = InMemoryResourceStore::new();
+        for (name, url) in [("root", "s3://bucket/"), ("nested", "s3://bucket/data")] {
+            let resource: Resource = ExternalLocationInfo {
+                name: name.into(),
+                url: url.into(),
+                ..Default::default()
+            }
+            .into();
+            store.create(resource).await.unwrap();
+        }
+        let find = |url: &str| {
+            let location = StorageLocationUrl::parse(url).unwrap();
+            let store = store.clone();
+            async move {
+                let (resource, _) = store.find_external_location(&location).await?;
+                let info: ExternalLocationInfo = resource.try_into()?;
+                Ok::<_, Error>(info.name)
+            }
+        };
+
+        assert_eq!(find("s3://bucket/data/table").await.unwrap(), "nested");
+        assert_eq!(find("s3://bucket/database/table").await.unwrap(), "root");
+        assert!(matches!(
+            find("s3://other/data").await.unwrap_err(),
+            Error::NotFound
+        ));
+
+        store
+            .delete(&ResourceIdent::external_location(ResourceName::new([
+                "nested",
+            ])))
+            .await
+            .unwrap();
+        assert_eq!(find("s3://bucket/data/table").await.unwrap(), "root");
+    }
 }
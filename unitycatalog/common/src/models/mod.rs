@@ -10,6 +10,7 @@ pub use external_locations::v1::ExternalLocationInfo;
 pub use internal::resource::{ObjectLabel, Resource};
 pub use object::Object;
 pub use profiles::v1::Profile;
+pub use providers::v1::ProviderInfo;
 pub use recipients::v1::RecipientInfo;
 pub use schemas::v1::SchemaInfo;
 pub use shares::v1::ShareInfo;
@@ -75,6 +76,14 @@ pub mod recipients {
     }
 }
 
+pub mod providers {
+    pub mod v1 {
+        include!("../gen/unitycatalog.providers.v1.rs");
+        #[cfg(feature = "grpc")]
+        include!("../gen/unitycatalog.providers.v1.tonic.rs");
+    }
+}
+
 pub mod external_locations {
     pub mod v1 {
         include!("../gen/unitycatalog.external_locations.v1.rs");
@@ -99,6 +108,13 @@ pub mod profiles {
     }
 }
 
+pub mod query {
+    pub mod v1 {
+        // results are streamed as arrow or json over http, so there is no grpc service.
+        include!("../gen/unitycatalog.query.v1.rs");
+    }
+}
+
 pub(crate) mod internal {
     include!("../gen/unitycatalog.internal.rs");
 }
@@ -115,6 +131,7 @@ impl ObjectLabel {
             ObjectLabel::TableInfo => ResourceIdent::table(id),
             ObjectLabel::ExternalLocationInfo => ResourceIdent::external_location(id),
             ObjectLabel::RecipientInfo => ResourceIdent::recipient(id),
+            ObjectLabel::ProviderInfo => ResourceIdent::provider(id),
             ObjectLabel::ColumnInfo => ResourceIdent::column(id),
         }
     }
@@ -6,8 +6,8 @@ use super::ExternalLocationInfo;
 use super::tables::v1::TableSummary;
 use crate::Error;
 use crate::models::{
-    CatalogInfo, ColumnInfo, CredentialInfo, ObjectLabel, RecipientInfo, Resource, SchemaInfo,
-    ShareInfo, SharingSchemaInfo, SharingTable, TableInfo,
+    CatalogInfo, ColumnInfo, CredentialInfo, ObjectLabel, ProviderInfo, RecipientInfo, Resource,
+    SchemaInfo, ShareInfo, SharingSchemaInfo, SharingTable, TableInfo,
 };
 use crate::resources::{ResourceExt, ResourceName, ResourceRef};
 
@@ -58,6 +58,7 @@ impl ResourceExt for Resource {
             Resource::TableInfo(_) => &ObjectLabel::TableInfo,
             Resource::ExternalLocationInfo(_) => &ObjectLabel::ExternalLocationInfo,
             Resource::RecipientInfo(_) => &ObjectLabel::RecipientInfo,
+            Resource::ProviderInfo(_) => &ObjectLabel::ProviderInfo,
             Resource::ColumnInfo(_) => &ObjectLabel::ColumnInfo,
         }
     }
@@ -73,6 +74,7 @@ impl ResourceExt for Resource {
             Resource::TableInfo(obj) => obj.resource_name(),
             Resource::ExternalLocationInfo(obj) => obj.resource_name(),
             Resource::RecipientInfo(obj) => obj.resource_name(),
+            Resource::ProviderInfo(obj) => obj.resource_name(),
             Resource::ColumnInfo(obj) => obj.resource_name(),
         }
     }
@@ -88,6 +90,7 @@ impl ResourceExt for Resource {
             Resource::TableInfo(obj) => obj.resource_ref(),
             Resource::ExternalLocationInfo(obj) => obj.resource_ref(),
             Resource::RecipientInfo(obj) => obj.resource_ref(),
+            Resource::ProviderInfo(obj) => obj.resource_ref(),
             Resource::ColumnInfo(obj) => obj.resource_ref(),
         }
     }
@@ -107,6 +110,7 @@ impl TryFrom<Resource> for Object {
             Resource::TableInfo(obj) => obj.try_into(),
             Resource::ExternalLocationInfo(obj) => obj.try_into(),
             Resource::RecipientInfo(obj) => obj.try_into(),
+            Resource::ProviderInfo(obj) => obj.try_into(),
             Resource::ColumnInfo(obj) => obj.try_into(),
         }
     }
@@ -128,11 +132,25 @@ impl TryFrom<Object> for Resource {
                 Ok(Resource::ExternalLocationInfo(obj.try_into()?))
             }
             ObjectLabel::RecipientInfo => Ok(Resource::RecipientInfo(obj.try_into()?)),
+            ObjectLabel::ProviderInfo => Ok(Resource::ProviderInfo(obj.try_into()?)),
             ObjectLabel::ColumnInfo => Ok(Resource::ColumnInfo(obj.try_into()?)),
         }
     }
 }
 
+impl TableInfo {
+    /// The name under which the table is registered in the store.
+    ///
+    /// Dropped tables are registered under their id, so the name of a dropped
+    /// table can be reused right away while the table can still be restored.
+    fn store_name(&self) -> &str {
+        match (self.deleted_at, &self.table_id) {
+            (Some(_), Some(table_id)) => table_id,
+            _ => &self.name,
+        }
+    }
+}
+
 impl From<TableInfo> for TableSummary {
     fn from(table: TableInfo) -> Self {
         TableSummary {
@@ -154,8 +172,9 @@ object_conversions!(
     SharingTable, ObjectLabel::SharingTable, id, [share, schema, name], true;
     CatalogInfo, ObjectLabel::CatalogInfo, id, [name], true;
     SchemaInfo, ObjectLabel::SchemaInfo, schema_id, [catalog_name, name], true;
-    TableInfo, ObjectLabel::TableInfo, table_id, [catalog_name, schema_name, name], true;
+    TableInfo, ObjectLabel::TableInfo, table_id, [catalog_name, schema_name, store_name()], true;
     ColumnInfo, ObjectLabel::ColumnInfo, column_id, [name], true;
     CredentialInfo, ObjectLabel::CredentialInfo, id, [name];
     RecipientInfo, ObjectLabel::RecipientInfo, id, [name], true;
+    ProviderInfo, ObjectLabel::ProviderInfo, id, [name], true;
 );
@@ -3,9 +3,11 @@ use uuid::Uuid;
 use crate::models::ObjectLabel;
 
 pub use name::*;
+pub use pattern::*;
 pub use store::*;
 
 mod name;
+mod pattern;
 mod store;
 
 /// Unique identifier for a resource.
@@ -73,6 +75,7 @@ pub enum ResourceIdent {
     Schema(ResourceRef),
     Table(ResourceRef),
     Recipient(ResourceRef),
+    Provider(ResourceRef),
     Column(ResourceRef),
 }
 
@@ -124,6 +127,10 @@ impl ResourceIdent {
     pub fn recipient(name: impl Into<ResourceRef>) -> Self {
         Self::Recipient(name.into())
     }
+
+    pub fn provider(name: impl Into<ResourceRef>) -> Self {
+        Self::Provider(name.into())
+    }
 }
 
 impl std::fmt::Display for ResourceIdent {
@@ -138,6 +145,7 @@ impl std::fmt::Display for ResourceIdent {
             ResourceIdent::Schema(r) => write!(f, "schema:{}", r),
             ResourceIdent::Table(r) => write!(f, "table:{}", r),
             ResourceIdent::Recipient(r) => write!(f, "recipient:{}", r),
+            ResourceIdent::Provider(r) => write!(f, "provider:{}", r),
             ResourceIdent::Column(r) => write!(f, "column:{}", r),
         }
     }
@@ -155,6 +163,7 @@ impl AsRef<ResourceRef> for ResourceIdent {
             ResourceIdent::Schema(r) => r,
             ResourceIdent::Table(r) => r,
             ResourceIdent::Recipient(r) => r,
+            ResourceIdent::Provider(r) => r,
             ResourceIdent::Column(r) => r,
         }
     }
@@ -172,6 +181,7 @@ impl AsRef<ObjectLabel> for ResourceIdent {
             ResourceIdent::Schema(_) => &ObjectLabel::SchemaInfo,
             ResourceIdent::Table(_) => &ObjectLabel::TableInfo,
             ResourceIdent::Recipient(_) => &ObjectLabel::RecipientInfo,
+            ResourceIdent::Provider(_) => &ObjectLabel::ProviderInfo,
             ResourceIdent::Column(_) => &ObjectLabel::ColumnInfo,
         }
     }
@@ -189,6 +199,7 @@ impl From<ResourceIdent> for ResourceRef {
             ResourceIdent::Schema(r) => r,
             ResourceIdent::Table(r) => r,
             ResourceIdent::Recipient(r) => r,
+            ResourceIdent::Provider(r) => r,
             ResourceIdent::Column(r) => r,
         }
     }
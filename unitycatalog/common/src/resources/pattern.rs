@@ -0,0 +1,137 @@
+/// A SQL `LIKE` pattern for matching resource name segments.
+///
+/// `%` matches any sequence of characters and `_` matches a single character.
+/// The glob wildcard `*` is accepted as an alias for `%`. A backslash escapes the
+/// following character. Matching is case-insensitive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePattern(String);
+
+enum Token {
+    Any,
+    One,
+    Char(char),
+}
+
+impl NamePattern {
+    pub fn new(pattern: impl AsRef<str>) -> Self {
+        let mut like = String::with_capacity(pattern.as_ref().len());
+        let mut chars = pattern.as_ref().chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    like.push('\\');
+                    if let Some(escaped) = chars.next() {
+                        like.push(escaped);
+                    }
+                }
+                '*' => like.push('%'),
+                c => like.push(c),
+            }
+        }
+        Self(like)
+    }
+
+    /// The pattern in SQL `LIKE` syntax.
+    pub fn as_like(&self) -> &str {
+        &self.0
+    }
+
+    /// Check if a name matches the pattern.
+    pub fn matches(&self, name: &str) -> bool {
+        let pattern = self.tokens();
+        let name: Vec<char> = name.chars().flat_map(char::to_lowercase).collect();
+
+        // greedy matching, backtracking to the most recent `%` on mismatch.
+        let (mut p, mut n) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+        while n < name.len() {
+            match pattern.get(p) {
+                Some(Token::Any) => {
+                    backtrack = Some((p, n));
+                    p += 1;
+                    continue;
+                }
+                Some(Token::One) => {
+                    p += 1;
+                    n += 1;
+                    continue;
+                }
+                Some(Token::Char(c)) if *c == name[n] => {
+                    p += 1;
+                    n += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            match backtrack {
+                Some((any_p, any_n)) => {
+                    p = any_p + 1;
+                    n = any_n + 1;
+                    backtrack = Some((any_p, any_n + 1));
+                }
+                None => return false,
+            }
+        }
+        pattern[p..].iter().all(|t| matches!(t, Token::Any))
+    }
+
+    fn tokens(&self) -> Vec<Token> {
+        let mut tokens = Vec::with_capacity(self.0.len());
+        let mut chars = self.0.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => tokens.push(Token::Any),
+                '_' => tokens.push(Token::One),
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        tokens.extend(escaped.to_lowercase().map(Token::Char));
+                    }
+                }
+                c => tokens.extend(c.to_lowercase().map(Token::Char)),
+            }
+        }
+        tokens
+    }
+}
+
+impl<T: AsRef<str>> From<T> for NamePattern {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_pattern() {
+        let cases = [
+            ("sales", "sales", true),
+            ("sales", "Sales", true),
+            ("sales", "sales_raw", false),
+            ("sales%", "sales_raw", true),
+            ("sales*", "sales_raw", true),
+            ("%_raw", "sales_raw", true),
+            ("%_raw", "_raw", false),
+            ("s_les", "sales", true),
+            ("s_les", "sles", false),
+            ("%a%a%", "banana", true),
+            ("%x%", "banana", false),
+            ("%", "", true),
+            ("sales\\_raw", "sales_raw", true),
+            ("sales\\_raw", "salesxraw", false),
+            ("100\\%", "100%", true),
+            ("\\Sales", "sales", true),
+        ];
+        for (pattern, name, expected) in cases {
+            assert_eq!(
+                NamePattern::new(pattern).matches(name),
+                expected,
+                "pattern '{pattern}' on '{name}'"
+            );
+        }
+        assert_eq!(NamePattern::new("sales_*").as_like(), "sales_%");
+        assert_eq!(NamePattern::new("a\\*").as_like(), "a\\*");
+    }
+}
@@ -2,9 +2,11 @@ use std::sync::Arc;
 
 use itertools::Itertools;
 
-use super::{ResourceIdent, ResourceName, ResourceRef};
-use crate::Result;
+use super::{NamePattern, ResourceExt, ResourceIdent, ResourceName, ResourceRef};
+use crate::models::external_locations::v1::ExternalLocationInfo;
 use crate::models::{AssociationLabel, ObjectLabel, PropertyMap, Resource};
+use crate::services::{StorageLocationUrl, normalize_location_url};
+use crate::{Error, Result};
 
 #[async_trait::async_trait]
 pub trait ResourceStoreReader: Send + Sync + 'static {
@@ -46,6 +48,82 @@ pub trait ResourceStoreReader: Send + Sync + 'static {
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)>;
+
+    /// List resources with names matching the given patterns.
+    ///
+    /// Works like [`list`](Self::list), but only returns resources where each segment of
+    /// the full name matches the pattern at the same position. Positions without a
+    /// pattern match any name.
+    ///
+    /// The default implementation filters each page returned by [`list`](Self::list),
+    /// so pages may hold fewer than `max_results` resources. Stores should override it
+    /// to filter before paginating.
+    ///
+    /// ## Arguments
+    /// - `label`: The label of the resources to list.
+    /// - `namespace`: The namespace to list resources in.
+    /// - `patterns`: The patterns to match against the segments of the full name.
+    /// - `max_results`: The maximum number of results to return.
+    /// - `page_token`: The token to use to get the next page of results.
+    async fn list_matching(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        patterns: &[Option<NamePattern>],
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        let (resources, next_page_token) =
+            self.list(label, namespace, max_results, page_token).await?;
+        let resources = resources
+            .into_iter()
+            .filter(|resource| name_matches(&resource.resource_name(), patterns))
+            .collect();
+        Ok((resources, next_page_token))
+    }
+
+    /// Find the external location that contains a storage location.
+    ///
+    /// If several external locations contain the location, the one with the longest
+    /// url is returned. The default implementation scans all external locations,
+    /// stores should override it with an indexed lookup.
+    ///
+    /// ## Arguments
+    /// - `location`: The storage location to find the external location for.
+    ///
+    /// ## Errors
+    /// - [NotFound](crate::Error::NotFound) If no external location contains the location.
+    async fn find_external_location(
+        &self,
+        location: &StorageLocationUrl,
+    ) -> Result<(Resource, ResourceRef)> {
+        let prefixes = location.prefixes();
+        let mut best: Option<(usize, Resource)> = None;
+        let mut page_token = None;
+        loop {
+            let (resources, next_page_token) = self
+                .list(&ObjectLabel::ExternalLocationInfo, None, None, page_token)
+                .await?;
+            for resource in resources {
+                let info: ExternalLocationInfo = resource.clone().try_into()?;
+                let url = normalize_location_url(&info.url);
+                let rank = prefixes.iter().position(|p| p == url);
+                match (rank, &best) {
+                    (Some(rank), Some((best_rank, _))) if rank >= *best_rank => {}
+                    (Some(rank), _) => best = Some((rank, resource)),
+                    (None, _) => {}
+                }
+            }
+            page_token = next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        let (_, resource) = best.ok_or(Error::NotFound)?;
+        let info: ExternalLocationInfo = resource.clone().try_into()?;
+        let id = ResourceIdent::external_location(ResourceName::new([info.name]));
+        self.get(&id).await
+    }
 }
 
 /// Generic store that can be used to store and retrieve resources.
@@ -153,6 +231,14 @@ pub trait ResourceStore: ResourceStoreReader + Send + Sync + 'static {
     ) -> Result<(Vec<ResourceIdent>, Option<String>)>;
 }
 
+/// Check if the segments of a name match the patterns at the same positions.
+pub fn name_matches(name: &ResourceName, patterns: &[Option<NamePattern>]) -> bool {
+    patterns
+        .iter()
+        .zip(name.iter())
+        .all(|(pattern, segment)| pattern.as_ref().is_none_or(|p| p.matches(segment)))
+}
+
 pub trait ProvidesResourceStore: Send + Sync + 'static {
     fn store(&self) -> &dyn ResourceStore;
 }
@@ -176,6 +262,24 @@ impl<T: ResourceStoreReader> ResourceStoreReader for Arc<T> {
     ) -> Result<(Vec<Resource>, Option<String>)> {
         T::list(self, label, namespace, max_results, page_token).await
     }
+
+    async fn list_matching(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        patterns: &[Option<NamePattern>],
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        T::list_matching(self, label, namespace, patterns, max_results, page_token).await
+    }
+
+    async fn find_external_location(
+        &self,
+        location: &StorageLocationUrl,
+    ) -> Result<(Resource, ResourceRef)> {
+        T::find_external_location(self, location).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -248,6 +352,26 @@ impl<T: ProvidesResourceStore> ResourceStoreReader for T {
             .list(label, namespace, max_results, page_token)
             .await
     }
+
+    async fn list_matching(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        patterns: &[Option<NamePattern>],
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        self.store()
+            .list_matching(label, namespace, patterns, max_results, page_token)
+            .await
+    }
+
+    async fn find_external_location(
+        &self,
+        location: &StorageLocationUrl,
+    ) -> Result<(Resource, ResourceRef)> {
+        self.store().find_external_location(location).await
+    }
 }
 
 #[async_trait::async_trait]
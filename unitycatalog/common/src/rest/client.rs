@@ -6,6 +6,8 @@ use reqwest::IntoUrl;
 pub use crate::api::catalogs::CatalogClient;
 pub use crate::api::credentials::CredentialsClient;
 pub use crate::api::external_locations::ExternalLocationsClient;
+pub use crate::api::providers::ProvidersClient;
+pub use crate::api::query::QueryClient;
 pub use crate::api::recipients::RecipientsClient;
 pub use crate::api::schemas::SchemasClient;
 pub use crate::api::shares::SharesClient;
@@ -43,6 +45,14 @@ impl UnityCatalogClient {
         ExternalLocationsClient::new(self.client.clone(), self.base_url.clone())
     }
 
+    pub fn providers(&self) -> ProvidersClient {
+        ProvidersClient::new(self.client.clone(), self.base_url.clone())
+    }
+
+    pub fn query(&self) -> QueryClient {
+        QueryClient::new(self.client.clone(), self.base_url.clone())
+    }
+
     pub fn recipients(&self) -> RecipientsClient {
         RecipientsClient::new(self.client.clone(), self.base_url.clone())
     }
@@ -0,0 +1,202 @@
+use axum::extract::{Extension, Json, Path, Query, State};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{Router, get};
+use http::StatusCode;
+use serde::Deserialize;
+
+use crate::Error;
+use crate::api::RequestContext;
+use crate::api::iceberg::*;
+use crate::services::policy::Recipient;
+
+/// Separator of the levels of a namespace in paths and query parameters.
+const NAMESPACE_SEPARATOR: char = '\u{1f}';
+
+/// Create a new [Router] for the Iceberg REST catalog API.
+pub fn get_router<T: IcebergCatalogHandler + Clone>(handler: T) -> Router {
+    Router::new()
+        .route("/v1/config", get(get_config))
+        .route("/v1/namespaces", get(list_namespaces::<T>))
+        .route("/v1/namespaces/{namespace}", get(get_namespace::<T>))
+        .route(
+            "/v1/namespaces/{namespace}/tables",
+            get(list_iceberg_tables::<T>),
+        )
+        .route(
+            "/v1/namespaces/{namespace}/tables/{table}",
+            get(load_iceberg_table::<T>),
+        )
+        .with_state(handler)
+}
+
+/// Errors in the format of the Iceberg REST catalog API.
+struct IcebergError {
+    error: Error,
+    /// The object a request refers to, reported if it does not exist.
+    missing: Missing,
+}
+
+/// Kinds of objects that may be reported as missing.
+enum Missing {
+    Namespace(Vec<String>),
+    Table(Vec<String>, String),
+}
+
+impl IcebergError {
+    fn namespace(namespace: &[String]) -> impl FnOnce(Error) -> Self {
+        let namespace = namespace.to_vec();
+        move |error| Self {
+            error,
+            missing: Missing::Namespace(namespace),
+        }
+    }
+
+    fn table(namespace: &[String], table: &str) -> impl FnOnce(Error) -> Self {
+        let namespace = namespace.to_vec();
+        let table = table.to_string();
+        move |error| Self {
+            error,
+            missing: Missing::Table(namespace, table),
+        }
+    }
+}
+
+impl IntoResponse for IcebergError {
+    fn into_response(self) -> Response {
+        let message = match (&self.error, &self.missing) {
+            (Error::NotFound, Missing::Namespace(namespace)) => {
+                format!("Namespace does not exist: {}", namespace.join("."))
+            }
+            (Error::NotFound, Missing::Table(namespace, table)) => {
+                format!("Table does not exist: {}.{}", namespace.join("."), table)
+            }
+            (error, _) => error.to_string(),
+        };
+        // reuse the status mapping (and logging) of the unity catalog api.
+        let status = self.error.into_response().status();
+        let error_type = match (status, &self.missing) {
+            (StatusCode::BAD_REQUEST, _) => "BadRequestException",
+            (StatusCode::UNAUTHORIZED, _) => "NotAuthorizedException",
+            (StatusCode::FORBIDDEN, _) => "ForbiddenException",
+            (StatusCode::NOT_FOUND, Missing::Namespace(_)) => "NoSuchNamespaceException",
+            (StatusCode::NOT_FOUND, Missing::Table(..)) => "NoSuchTableException",
+            (StatusCode::CONFLICT, _) => "AlreadyExistsException",
+            _ => "ServerErrorException",
+        };
+        // internal errors are logged above, their details are not exposed to clients.
+        let message = if status.is_server_error() {
+            status.canonical_reason().unwrap_or_default().to_string()
+        } else {
+            message
+        };
+        let body = serde_json::json!({
+            "error": {
+                "message": message,
+                "type": error_type,
+                "code": status.as_u16(),
+            }
+        });
+        (status, Json(body)).into_response()
+    }
+}
+
+type IcebergResult<T> = std::result::Result<T, IcebergError>;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageQuery {
+    parent: Option<String>,
+    page_token: Option<String>,
+    page_size: Option<usize>,
+}
+
+fn parse_namespace(namespace: &str) -> Vec<String> {
+    namespace
+        .split(NAMESPACE_SEPARATOR)
+        .filter(|level| !level.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+async fn get_config() -> Json<CatalogConfig> {
+    Json(CatalogConfig::default())
+}
+
+async fn list_namespaces<T: IcebergCatalogHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    Query(query): Query<PageQuery>,
+) -> IcebergResult<Json<ListNamespacesResponse>> {
+    let request = ListNamespacesRequest {
+        parent: query
+            .parent
+            .as_deref()
+            .map(parse_namespace)
+            .unwrap_or_default(),
+        page_size: query.page_size,
+        page_token: query.page_token,
+    };
+    let ctx = RequestContext { recipient };
+    let missing = IcebergError::namespace(&request.parent);
+    Ok(Json(
+        handler
+            .list_namespaces(request, ctx)
+            .await
+            .map_err(missing)?,
+    ))
+}
+
+async fn get_namespace<T: IcebergCatalogHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    Path(namespace): Path<String>,
+) -> IcebergResult<Json<GetNamespaceResponse>> {
+    let request = GetNamespaceRequest {
+        namespace: parse_namespace(&namespace),
+    };
+    let ctx = RequestContext { recipient };
+    let missing = IcebergError::namespace(&request.namespace);
+    Ok(Json(
+        handler.get_namespace(request, ctx).await.map_err(missing)?,
+    ))
+}
+
+async fn list_iceberg_tables<T: IcebergCatalogHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    Path(namespace): Path<String>,
+    Query(query): Query<PageQuery>,
+) -> IcebergResult<Json<ListIcebergTablesResponse>> {
+    let request = ListIcebergTablesRequest {
+        namespace: parse_namespace(&namespace),
+        page_size: query.page_size,
+        page_token: query.page_token,
+    };
+    let ctx = RequestContext { recipient };
+    let missing = IcebergError::namespace(&request.namespace);
+    Ok(Json(
+        handler
+            .list_iceberg_tables(request, ctx)
+            .await
+            .map_err(missing)?,
+    ))
+}
+
+async fn load_iceberg_table<T: IcebergCatalogHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    Path((namespace, table)): Path<(String, String)>,
+) -> IcebergResult<Json<LoadTableResult>> {
+    let request = LoadIcebergTableRequest {
+        namespace: parse_namespace(&namespace),
+        table,
+    };
+    let ctx = RequestContext { recipient };
+    let missing = IcebergError::table(&request.namespace, &request.table);
+    Ok(Json(
+        handler
+            .load_iceberg_table(request, ctx)
+            .await
+            .map_err(missing)?,
+    ))
+}
@@ -9,6 +9,8 @@ use crate::models::external_locations::v1::*;
 
 pub async fn test_credentials_router(app: Router) {
     test_external_locations_router_crud(app.clone()).await;
+    test_validate_credentials(app.clone()).await;
+    test_update_credential(app.clone()).await;
 }
 
 async fn test_external_locations_router_crud(app: Router) {
@@ -38,6 +40,7 @@ async fn test_external_locations_router_crud(app: Router) {
         name: "test-location".to_string(),
         url: "az://bucket".to_string(),
         credential_name: "some_credential".to_string(),
+        skip_validation: Some(true),
         ..Default::default()
     };
 
@@ -101,3 +104,302 @@ async fn test_external_locations_router_crud(app: Router) {
         "deleted location not found"
     );
 }
+
+async fn test_validate_credentials(app: Router) {
+    let credential = CreateCredentialRequest {
+        name: "memory_credential".to_string(),
+        purpose: Purpose::Storage as i32,
+        credential: Some(Credential::NoCredential(NoCredential {})),
+        ..Default::default()
+    };
+    let create_credential = create_request(Method::POST, "/credentials", Some(credential));
+    let create_credential_response = app.clone().oneshot(create_credential).await.unwrap();
+    assert_eq!(
+        create_credential_response.status(),
+        StatusCode::OK,
+        "create credential"
+    );
+
+    // Validate a location before creating it
+    let validate = ValidateCredentialRequest {
+        credential_name: "memory_credential".to_string(),
+        url: Some("memory:///validate".to_string()),
+        ..Default::default()
+    };
+    let validate_request = create_request(Method::POST, "/validate-credentials", Some(validate));
+    let validate_response = app.clone().oneshot(validate_request).await.unwrap();
+    assert_eq!(validate_response.status(), StatusCode::OK, "validate url");
+    let body: ValidateCredentialResponse = collect_body(validate_response).await;
+    let results: Vec<_> = body
+        .results
+        .iter()
+        .map(|r| (r.operation(), r.result()))
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            (ValidationOperation::List, ValidationResultStatus::Pass),
+            (ValidationOperation::Read, ValidationResultStatus::Skip),
+            (ValidationOperation::Write, ValidationResultStatus::Pass),
+            (ValidationOperation::Delete, ValidationResultStatus::Pass),
+        ]
+    );
+
+    // Locations are validated on create
+    let storage_location = CreateExternalLocationRequest {
+        name: "memory_location".to_string(),
+        url: "memory:///validate".to_string(),
+        credential_name: "memory_credential".to_string(),
+        read_only: Some(true),
+        ..Default::default()
+    };
+    let create_location =
+        create_request(Method::POST, "/external-locations", Some(storage_location));
+    let create_location_response = app.clone().oneshot(create_location).await.unwrap();
+    assert_eq!(
+        create_location_response.status(),
+        StatusCode::OK,
+        "create location"
+    );
+
+    // Validate an existing location
+    let validate = ValidateCredentialRequest {
+        credential_name: "memory_credential".to_string(),
+        external_location_name: Some("memory_location".to_string()),
+        ..Default::default()
+    };
+    let validate_request = create_request(Method::POST, "/validate-credentials", Some(validate));
+    let validate_response = app.clone().oneshot(validate_request).await.unwrap();
+    assert_eq!(
+        validate_response.status(),
+        StatusCode::OK,
+        "validate location"
+    );
+    let body: ValidateCredentialResponse = collect_body(validate_response).await;
+    assert!(
+        body.results[2..]
+            .iter()
+            .all(|r| r.result() == ValidationResultStatus::Skip),
+        "read-only locations skip write probes"
+    );
+
+    // Local paths outside of the allowed base directories are rejected
+    let validate = ValidateCredentialRequest {
+        credential_name: "memory_credential".to_string(),
+        url: Some("file:///not/allowed".to_string()),
+        ..Default::default()
+    };
+    let validate_request = create_request(Method::POST, "/validate-credentials", Some(validate));
+    let validate_response = app.clone().oneshot(validate_request).await.unwrap();
+    assert_eq!(
+        validate_response.status(),
+        StatusCode::BAD_REQUEST,
+        "validate disallowed path"
+    );
+}
+
+/// Expects a table stored at `memory:///usage/events` to exist.
+pub async fn test_external_location_usage(app: Router) {
+    let credential = CreateCredentialRequest {
+        name: "usage_credential".to_string(),
+        purpose: Purpose::Storage as i32,
+        credential: Some(Credential::NoCredential(NoCredential {})),
+        ..Default::default()
+    };
+    let create_credential = create_request(Method::POST, "/credentials", Some(credential));
+    let create_credential_response = app.clone().oneshot(create_credential).await.unwrap();
+    assert_eq!(
+        create_credential_response.status(),
+        StatusCode::OK,
+        "create credential"
+    );
+
+    let storage_location = CreateExternalLocationRequest {
+        name: "usage_location".to_string(),
+        url: "memory:///usage".to_string(),
+        credential_name: "usage_credential".to_string(),
+        ..Default::default()
+    };
+    let create_location = create_request(
+        Method::POST,
+        "/external-locations",
+        Some(storage_location.clone()),
+    );
+    let create_location_response = app.clone().oneshot(create_location).await.unwrap();
+    assert_eq!(
+        create_location_response.status(),
+        StatusCode::OK,
+        "create location"
+    );
+
+    // Locations must not overlap with existing locations
+    let nested_location = CreateExternalLocationRequest {
+        name: "nested_location".to_string(),
+        url: "memory:///usage/events".to_string(),
+        ..storage_location.clone()
+    };
+    let create_location =
+        create_request(Method::POST, "/external-locations", Some(nested_location));
+    let create_location_response = app.clone().oneshot(create_location).await.unwrap();
+    assert_eq!(
+        create_location_response.status(),
+        StatusCode::BAD_REQUEST,
+        "create nested location"
+    );
+
+    let sibling_location = CreateExternalLocationRequest {
+        name: "sibling_location".to_string(),
+        url: "memory:///usage_sibling".to_string(),
+        ..storage_location.clone()
+    };
+    let create_location =
+        create_request(Method::POST, "/external-locations", Some(sibling_location));
+    let create_location_response = app.clone().oneshot(create_location).await.unwrap();
+    assert_eq!(
+        create_location_response.status(),
+        StatusCode::OK,
+        "create sibling location"
+    );
+
+    // List tables stored in the location
+    let list_tables = create_request(
+        Method::GET,
+        "/external-locations/usage_location/tables",
+        None::<()>,
+    );
+    let list_tables_response = app.clone().oneshot(list_tables).await.unwrap();
+    assert_eq!(list_tables_response.status(), StatusCode::OK, "list tables");
+    let body: ListExternalLocationTablesResponse = collect_body(list_tables_response).await;
+    assert_eq!(body.tables.len(), 1);
+    assert_eq!(body.tables[0].name, "events");
+
+    // Out of range page sizes are clamped
+    let list_tables = create_request(
+        Method::GET,
+        "/external-locations/usage_location/tables?max_results=-1",
+        None::<()>,
+    );
+    let list_tables_response = app.clone().oneshot(list_tables).await.unwrap();
+    assert_eq!(
+        list_tables_response.status(),
+        StatusCode::OK,
+        "list tables with negative max_results"
+    );
+    let body: ListExternalLocationTablesResponse = collect_body(list_tables_response).await;
+    assert_eq!(body.tables.len(), 1);
+
+    let list_tables = create_request(
+        Method::GET,
+        "/external-locations/sibling_location/tables",
+        None::<()>,
+    );
+    let list_tables_response = app.clone().oneshot(list_tables).await.unwrap();
+    let body: ListExternalLocationTablesResponse = collect_body(list_tables_response).await;
+    assert!(body.tables.is_empty(), "sibling location has no tables");
+
+    // Locations in use can only be deleted with force
+    let delete_location = create_request(
+        Method::DELETE,
+        "/external-locations/usage_location",
+        None::<()>,
+    );
+    let delete_location_response = app.clone().oneshot(delete_location).await.unwrap();
+    assert_eq!(
+        delete_location_response.status(),
+        StatusCode::BAD_REQUEST,
+        "delete location in use"
+    );
+
+    let delete_location = create_request(
+        Method::DELETE,
+        "/external-locations/usage_location?force=true",
+        None::<()>,
+    );
+    let delete_location_response = app.clone().oneshot(delete_location).await.unwrap();
+    assert_eq!(
+        delete_location_response.status(),
+        StatusCode::OK,
+        "force delete location"
+    );
+}
+
+async fn test_update_credential(app: Router) {
+    let credential = CreateCredentialRequest {
+        name: "rotated_credential".to_string(),
+        purpose: Purpose::Storage as i32,
+        comment: Some("initial".to_string()),
+        read_only: Some(true),
+        credential: Some(Credential::AzureStorageKey(AzureStorageKey {
+            account_name: "account".to_string(),
+            account_key: "key_v1".to_string(),
+        })),
+        ..Default::default()
+    };
+    let create_credential = create_request(Method::POST, "/credentials", Some(credential));
+    let create_credential_response = app.clone().oneshot(create_credential).await.unwrap();
+    assert_eq!(
+        create_credential_response.status(),
+        StatusCode::OK,
+        "create credential"
+    );
+    let created: CredentialInfo = collect_body(create_credential_response).await;
+    assert!(created.updated_at.is_none());
+
+    // Rotate the secret material
+    let update = UpdateCredentialRequest {
+        name: "rotated_credential".to_string(),
+        credential: Some(update_credential_request::Credential::AzureStorageKey(
+            AzureStorageKey {
+                account_name: "account".to_string(),
+                account_key: "key_v2".to_string(),
+            },
+        )),
+        ..Default::default()
+    };
+    let update_credential = create_request(
+        Method::PATCH,
+        "/credentials/rotated_credential",
+        Some(update),
+    );
+    let update_credential_response = app.clone().oneshot(update_credential).await.unwrap();
+    assert_eq!(
+        update_credential_response.status(),
+        StatusCode::OK,
+        "update credential"
+    );
+    let updated: CredentialInfo = collect_body(update_credential_response).await;
+    assert!(updated.updated_at.is_some());
+    assert!(
+        updated.credential.is_none(),
+        "secret material is not returned"
+    );
+    assert_eq!(updated.comment.as_deref(), Some("initial"));
+    assert!(updated.read_only, "unset fields are preserved");
+
+    let get_credential = create_request(Method::GET, "/credentials/rotated_credential", None::<()>);
+    let get_credential_response = app.clone().oneshot(get_credential).await.unwrap();
+    let current: CredentialInfo = collect_body(get_credential_response).await;
+    assert!(matches!(
+        current.credential,
+        Some(credential_info::Credential::AzureStorageKey(AzureStorageKey { account_key, .. }))
+            if account_key == "key_v2"
+    ));
+
+    // Credentials cannot be renamed
+    let update = UpdateCredentialRequest {
+        name: "rotated_credential".to_string(),
+        new_name: Some("renamed_credential".to_string()),
+        ..Default::default()
+    };
+    let update_credential = create_request(
+        Method::PATCH,
+        "/credentials/rotated_credential",
+        Some(update),
+    );
+    let update_credential_response = app.clone().oneshot(update_credential).await.unwrap();
+    assert_eq!(
+        update_credential_response.status(),
+        StatusCode::BAD_REQUEST,
+        "rename credential"
+    );
+}
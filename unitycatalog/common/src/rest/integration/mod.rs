@@ -7,9 +7,11 @@ use http_body_util::BodyExt;
 
 pub use self::catalogs::*;
 pub use self::external_locations::*;
+pub use self::tables::*;
 
 mod catalogs;
 mod external_locations;
+mod tables;
 
 pub async fn collect_body<T>(response: axum::http::Response<Body>) -> T
 where
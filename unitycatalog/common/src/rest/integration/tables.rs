@@ -0,0 +1,296 @@
+use axum::http::{Method, StatusCode};
+use tower::ServiceExt;
+
+use super::super::integration::{collect_body, create_request};
+use super::*;
+use crate::models::catalogs::v1::*;
+use crate::models::credentials::v1::create_credential_request::Credential;
+use crate::models::credentials::v1::*;
+use crate::models::external_locations::v1::*;
+use crate::models::schemas::v1::*;
+use crate::models::tables::v1::*;
+
+/// Create the managed tables `managed.inherited.events` and `managed.rooted.events`.
+///
+/// Expects routers for catalogs, schemas, credentials, external locations and tables.
+pub async fn setup_managed_tables(app: Router) {
+    let credential = CreateCredentialRequest {
+        name: "managed_credential".to_string(),
+        purpose: Purpose::Storage as i32,
+        credential: Some(Credential::NoCredential(NoCredential {})),
+        ..Default::default()
+    };
+    let create_credential = create_request(Method::POST, "/credentials", Some(credential));
+    let response = app.clone().oneshot(create_credential).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "create credential");
+
+    let location = CreateExternalLocationRequest {
+        name: "managed_location".to_string(),
+        url: "memory:///managed".to_string(),
+        credential_name: "managed_credential".to_string(),
+        ..Default::default()
+    };
+    let create_location = create_request(Method::POST, "/external-locations", Some(location));
+    let response = app.clone().oneshot(create_location).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "create location");
+
+    let catalog = CreateCatalogRequest {
+        name: "managed".to_string(),
+        storage_root: Some("memory:///managed/catalog/".to_string()),
+        ..Default::default()
+    };
+    let create_catalog = create_request(Method::POST, "/catalogs", Some(catalog));
+    let response = app.clone().oneshot(create_catalog).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "create catalog");
+
+    for (name, storage_root) in [
+        ("inherited", None),
+        ("rooted", Some("memory:///managed/schema".to_string())),
+    ] {
+        let schema = CreateSchemaRequest {
+            name: name.to_string(),
+            catalog_name: "managed".to_string(),
+            storage_root,
+            ..Default::default()
+        };
+        let create_schema = create_request(Method::POST, "/schemas", Some(schema));
+        let response = app.clone().oneshot(create_schema).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "create schema");
+    }
+
+    for (schema_name, root) in [
+        ("inherited", "memory:///managed/catalog"),
+        ("rooted", "memory:///managed/schema"),
+    ] {
+        let table = CreateTableRequest {
+            name: "events".to_string(),
+            catalog_name: "managed".to_string(),
+            schema_name: schema_name.to_string(),
+            table_type: TableType::Managed as i32,
+            data_source_format: DataSourceFormat::Delta as i32,
+            columns: event_columns(),
+            ..Default::default()
+        };
+        let create_table = create_request(Method::POST, "/tables", Some(table));
+        let response = app.clone().oneshot(create_table).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "create managed table");
+        let info: TableInfo = collect_body(response).await;
+        let table_id = info.table_id.clone().expect("table id");
+        assert_eq!(
+            info.storage_location,
+            Some(format!("{}/tables/{}", root, table_id))
+        );
+        let columns: Vec<_> = info
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.type_name(), c.partition_index))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id", ColumnTypeName::Long, None),
+                ("day", ColumnTypeName::Date, Some(0)),
+            ]
+        );
+
+        let uri = format!("/tables/managed.{}.events", schema_name);
+        let get_table = create_request(Method::GET, &uri, None::<()>);
+        let response = app.clone().oneshot(get_table).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "get managed table");
+        let body: TableInfo = collect_body(response).await;
+        assert_eq!(body.storage_location, info.storage_location);
+    }
+}
+
+/// The columns of the managed `events` tables.
+fn event_columns() -> Vec<ColumnInfo> {
+    vec![
+        ColumnInfo {
+            name: "id".to_string(),
+            type_name: ColumnTypeName::Long as i32,
+            position: Some(0),
+            nullable: Some(false),
+            ..Default::default()
+        },
+        ColumnInfo {
+            name: "day".to_string(),
+            type_name: ColumnTypeName::Date as i32,
+            position: Some(1),
+            partition_index: Some(0),
+            ..Default::default()
+        },
+    ]
+}
+
+/// Expects the tables created by [setup_managed_tables].
+pub async fn test_managed_tables(app: Router) {
+    // Managed tables get their location assigned by the server.
+    let table = CreateTableRequest {
+        name: "located".to_string(),
+        catalog_name: "managed".to_string(),
+        schema_name: "inherited".to_string(),
+        table_type: TableType::Managed as i32,
+        data_source_format: DataSourceFormat::Delta as i32,
+        storage_location: Some("memory:///managed/elsewhere".to_string()),
+        columns: event_columns(),
+        ..Default::default()
+    };
+    let create_table = create_request(Method::POST, "/tables", Some(table));
+    let response = app.clone().oneshot(create_table).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "explicit location"
+    );
+
+    // Decimal precision and scale must fit the delta decimal type.
+    let mut columns = event_columns();
+    columns.push(ColumnInfo {
+        name: "amount".to_string(),
+        type_name: ColumnTypeName::Decimal as i32,
+        type_precision: Some(300),
+        type_scale: Some(2),
+        position: Some(2),
+        ..Default::default()
+    });
+    let table = CreateTableRequest {
+        name: "amounts".to_string(),
+        catalog_name: "managed".to_string(),
+        schema_name: "inherited".to_string(),
+        table_type: TableType::Managed as i32,
+        data_source_format: DataSourceFormat::Delta as i32,
+        columns,
+        ..Default::default()
+    };
+    let create_table = create_request(Method::POST, "/tables", Some(table));
+    let response = app.clone().oneshot(create_table).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "decimal precision out of range"
+    );
+}
+
+/// Expects the tables created by [setup_managed_tables].
+///
+/// Leaves the managed table `managed.rooted.events` dropped twice.
+pub async fn test_dropped_tables(app: Router) {
+    // Dropped managed tables are hidden until they are restored.
+    let delete_table = create_request(
+        Method::DELETE,
+        "/tables/managed.inherited.events",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(delete_table).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "drop managed table");
+
+    let get_table = create_request(Method::GET, "/tables/managed.inherited.events", None::<()>);
+    let response = app.clone().oneshot(get_table).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::NOT_FOUND,
+        "get dropped table"
+    );
+
+    let list_tables = create_request(
+        Method::GET,
+        "/tables?catalog_name=managed&schema_name=inherited",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(list_tables).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "list tables");
+    let body: ListTablesResponse = collect_body(response).await;
+    assert!(body.tables.is_empty());
+
+    let undrop_table = create_request(
+        Method::POST,
+        "/tables/managed.inherited.events/undrop",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(undrop_table).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "undrop managed table");
+    let body: TableInfo = collect_body(response).await;
+    assert_eq!(body.deleted_at, None);
+    assert_eq!(body.name, "events");
+
+    let get_table = create_request(Method::GET, "/tables/managed.inherited.events", None::<()>);
+    let response = app.clone().oneshot(get_table).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "get restored table");
+
+    let undrop_table = create_request(
+        Method::POST,
+        "/tables/managed.inherited.events/undrop",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(undrop_table).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "undrop active table"
+    );
+
+    let delete_table = create_request(Method::DELETE, "/tables/managed.rooted.events", None::<()>);
+    let response = app.clone().oneshot(delete_table).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "drop managed table");
+
+    // The name of a dropped table can be reused, which blocks restoring the dropped one.
+    let table = CreateTableRequest {
+        name: "events".to_string(),
+        catalog_name: "managed".to_string(),
+        schema_name: "rooted".to_string(),
+        table_type: TableType::Managed as i32,
+        data_source_format: DataSourceFormat::Delta as i32,
+        columns: event_columns(),
+        ..Default::default()
+    };
+    let create_table = create_request(Method::POST, "/tables", Some(table));
+    let response = app.clone().oneshot(create_table).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "recreate dropped table");
+
+    let undrop_table = create_request(
+        Method::POST,
+        "/tables/managed.rooted.events/undrop",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(undrop_table).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "undrop replaced table"
+    );
+
+    let delete_table = create_request(Method::DELETE, "/tables/managed.rooted.events", None::<()>);
+    let response = app.clone().oneshot(delete_table).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "drop recreated table");
+}
+
+/// Expects the tables created by [setup_managed_tables].
+pub async fn test_table_summaries(app: Router) {
+    for (query, expected) in [
+        (
+            "",
+            vec!["managed.inherited.events", "managed.rooted.events"],
+        ),
+        (
+            "&schema_name_pattern=root%25",
+            vec!["managed.rooted.events"],
+        ),
+        (
+            "&schema_name_pattern=_nherited&table_name_pattern=EV*",
+            vec!["managed.inherited.events"],
+        ),
+        ("&table_name_pattern=event", vec![]),
+    ] {
+        let list_summaries = create_request(
+            Method::GET,
+            &format!("/table-summaries?catalog_name=managed{}", query),
+            None::<()>,
+        );
+        let response = app.clone().oneshot(list_summaries).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "list table summaries");
+        let body: ListTableSummariesResponse = collect_body(response).await;
+        let mut names: Vec<_> = body.tables.into_iter().map(|t| t.full_name).collect();
+        names.sort();
+        assert_eq!(names, expected, "table summaries{}", query);
+    }
+}
@@ -8,6 +8,9 @@ mod routers {
     pub use super::catalogs::get_router as get_catalog_router;
     pub use super::credentials::get_router as get_credentials_router;
     pub use super::external_locations::get_router as get_external_locations_router;
+    pub use super::iceberg::get_router as get_iceberg_router;
+    pub use super::providers::get_router as get_providers_router;
+    pub use super::query::get_router as get_query_router;
     pub use super::recipients::get_router as get_recipients_router;
     pub use super::schemas::get_router as get_schemas_router;
     pub use super::shares::get_router as get_shares_router;
@@ -19,9 +22,13 @@ mod routers {
 mod auth;
 #[cfg(feature = "rest-client")]
 pub mod client;
+#[cfg(feature = "axum")]
+mod iceberg;
 #[cfg(any(all(test, feature = "axum"), feature = "integration"))]
 pub mod integration;
 #[cfg(feature = "axum")]
+mod query;
+#[cfg(feature = "axum")]
 mod sharing;
 
 #[cfg(feature = "axum")]
@@ -75,18 +82,37 @@ mod recipients {
     }
 }
 
+#[cfg(feature = "axum")]
+mod providers {
+    use crate::api::providers::*;
+    use axum::routing::{Router, delete, get, patch, post};
+
+    /// Create a new [Router] for the Delta Sharing REST API.
+    pub fn get_router<T: ProvidersHandler + Clone>(handler: T) -> Router {
+        Router::new()
+            .route("/providers", post(create_provider::<T>))
+            .route("/providers", get(list_providers::<T>))
+            .route("/providers/{name}", get(get_provider::<T>))
+            .route("/providers/{name}", patch(update_provider::<T>))
+            .route("/providers/{name}", delete(delete_provider::<T>))
+            .route("/providers/{name}/shares", get(list_provider_shares::<T>))
+            .with_state(handler)
+    }
+}
+
 #[cfg(feature = "axum")]
 mod credentials {
     use crate::api::credentials::*;
-    use axum::routing::{Router, delete, get, post};
+    use axum::routing::{Router, delete, get, patch, post};
 
     pub fn get_router<T: CredentialsHandler + Clone>(handler: T) -> Router {
         Router::new()
             .route("/credentials", get(list_credentials::<T>))
             .route("/credentials", post(create_credential::<T>))
             .route("/credentials/{name}", get(get_credential::<T>))
-            //.route("/credentials/{name}", patch(update_credential::<T>))
+            .route("/credentials/{name}", patch(update_credential::<T>))
             .route("/credentials/{name}", delete(delete_credential::<T>))
+            .route("/validate-credentials", post(validate_credential::<T>))
             .with_state(handler)
     }
 }
@@ -113,6 +139,10 @@ mod external_locations {
                 "/external-locations/{name}",
                 delete(delete_external_location::<T>),
             )
+            .route(
+                "/external-locations/{name}/tables",
+                get(list_external_location_tables::<T>),
+            )
             .with_state(handler)
     }
 }
@@ -146,6 +176,10 @@ mod tables {
             .route("/tables/{name}", get(get_table::<T>))
             // .route("/tables/{name}", patch(update_table::<T>))
             .route("/tables/{name}", delete(delete_table::<T>))
+            .route("/tables/{name}/undrop", post(undrop_table::<T>))
+            .route("/tables/{name}/history", get(get_table_history::<T>))
+            .route("/tables/{name}/detail", get(get_table_detail::<T>))
+            .route("/table-summaries", get(list_table_summaries::<T>))
             .with_state(handler)
     }
 }
@@ -154,17 +188,43 @@ mod tables {
 mod tests {
     use std::sync::Arc;
 
+    use datafusion::arrow::ipc::reader::StreamReader;
+    use datafusion::prelude::SessionConfig;
+    use futures_util::TryStreamExt;
+    use http_body_util::BodyExt;
+    use object_store::ObjectStore;
+    use tower::ServiceExt;
+
+    use super::integration::{collect_body, create_request};
     use super::*;
+    use crate::api::iceberg::{ListIcebergTablesResponse, ListNamespacesResponse, LoadTableResult};
+    use crate::api::query::QueryRequest;
+    use crate::api::tables::{purge_dropped_tables, sync_delta_tables};
     use crate::memory::InMemoryResourceStore;
+    use crate::models::AssociationLabel;
+    use crate::models::catalogs::v1::{CatalogInfo, CatalogType, CreateCatalogRequest};
+    use crate::models::google::protobuf::value::Kind;
+    use crate::models::providers::v1::{CreateProviderRequest, ListProviderSharesResponse};
+    use crate::models::recipients::v1::AuthenticationType;
+    use crate::models::tables::v1::{
+        ColumnTypeName, CreateTableRequest, DataSourceFormat, GetTableHistoryResponse,
+        ListTablesResponse, TableDetail, TableInfo, TableType, dependency,
+    };
     use crate::rest::auth::{AnonymousAuthenticator, AuthenticationLayer};
-    use crate::services::policy::{ConstantPolicy, Policy, ProvidesPolicy};
+    use crate::services::delta_sharing::mock::{TOKEN, mock_sharing_server};
+    use crate::services::kernel::engine::{ObjectStoreCache, ProvidesObjectStoreCache};
+    use crate::services::policy::{ConstantPolicy, Policy, ProvidesPolicy, Recipient};
     use crate::services::secrets::{ProvidesSecretManager, SecretManager};
-    use crate::{ProvidesResourceStore, ResourceStore};
+    use crate::services::system::find_shadowed_catalog;
+    use crate::services::{LocalStorageConfig, ProvidesLocalStorage, ServerHandler};
+    use crate::{ProvidesResourceStore, ResourceIdent, ResourceName, ResourceStore};
 
     #[derive(Clone)]
     struct Handler {
         store: InMemoryResourceStore,
         policy: Arc<dyn Policy>,
+        local_storage: LocalStorageConfig,
+        object_stores: ObjectStoreCache,
     }
 
     impl Default for Handler {
@@ -172,6 +232,8 @@ mod tests {
             Self {
                 store: InMemoryResourceStore::new(),
                 policy: Arc::new(ConstantPolicy::default()),
+                local_storage: LocalStorageConfig::default(),
+                object_stores: ObjectStoreCache::default(),
             }
         }
     }
@@ -194,6 +256,18 @@ mod tests {
         }
     }
 
+    impl ProvidesLocalStorage for Handler {
+        fn local_storage(&self) -> &LocalStorageConfig {
+            &self.local_storage
+        }
+    }
+
+    impl ProvidesObjectStoreCache for Handler {
+        fn object_store_cache(&self) -> &ObjectStoreCache {
+            &self.object_stores
+        }
+    }
+
     #[tokio::test]
     async fn test_catalog_router() {
         let handler = Handler::default();
@@ -211,4 +285,1039 @@ mod tests {
             .layer(AuthenticationLayer::new(AnonymousAuthenticator));
         super::integration::test_credentials_router(app).await;
     }
+
+    #[tokio::test]
+    async fn test_external_location_usage() {
+        let handler = Handler::default();
+        let table = TableInfo {
+            name: "events".to_string(),
+            schema_name: "default".to_string(),
+            catalog_name: "main".to_string(),
+            full_name: Some("main.default.events".to_string()),
+            table_type: TableType::External as i32,
+            data_source_format: DataSourceFormat::Delta as i32,
+            storage_location: Some("memory:///usage/events".to_string()),
+            ..Default::default()
+        };
+        handler.store.create(table.into()).await.unwrap();
+        let app = get_credentials_router(handler.clone())
+            .merge(get_external_locations_router(handler))
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        super::integration::test_external_location_usage(app).await;
+    }
+
+    #[tokio::test]
+    async fn test_shared_catalogs() {
+        let store = InMemoryResourceStore::new();
+        let handler = ServerHandler::try_new_tokio(
+            Arc::new(ConstantPolicy::default()),
+            Arc::new(store.clone()),
+            Arc::new(store),
+        )
+        .unwrap();
+        let app = get_providers_router(handler.clone())
+            .merge(get_catalog_router(handler.clone()))
+            .merge(get_tables_router(handler.clone()))
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        let endpoint = mock_sharing_server().await;
+
+        // The profile is stored as secret, only its endpoint is returned.
+        let profile = serde_json::json!({
+            "shareCredentialsVersion": 1,
+            "endpoint": endpoint.as_str(),
+            "bearerToken": TOKEN,
+        });
+        let create_provider = CreateProviderRequest {
+            name: "remote".to_string(),
+            authentication_type: AuthenticationType::Token as i32,
+            recipient_profile_str: profile.to_string(),
+            ..Default::default()
+        };
+        let request = create_request(http::Method::POST, "/providers", Some(create_provider));
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        let body: serde_json::Value = collect_body(response).await;
+        assert_eq!(body["recipientProfile"]["endpoint"], endpoint.as_str());
+        assert!(!body.to_string().contains(TOKEN));
+        let (_, secret) = handler.get_secret("providers/remote").await.unwrap();
+        assert!(String::from_utf8(secret.to_vec()).unwrap().contains(TOKEN));
+
+        let request = create_request(http::Method::GET, "/providers/remote/shares", None::<()>);
+        let shares: ListProviderSharesResponse =
+            collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(shares.shares.len(), 1);
+        assert_eq!(shares.shares[0].name, "delta_share");
+
+        // Catalogs created from a share mirror its schemas and tables.
+        let create_catalog = |name: &str, share_name: &str| CreateCatalogRequest {
+            name: name.to_string(),
+            provider_name: Some("remote".to_string()),
+            share_name: Some(share_name.to_string()),
+            ..Default::default()
+        };
+        let request = create_request(
+            http::Method::POST,
+            "/catalogs",
+            Some(create_catalog("shared", "delta_share")),
+        );
+        let info: CatalogInfo = collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(
+            info.catalog_type,
+            Some(CatalogType::DeltasharingCatalog as i32)
+        );
+        let request = create_request(
+            http::Method::GET,
+            "/tables/shared.default.events",
+            None::<()>,
+        );
+        let table: TableInfo = collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(table.data_source_format(), DataSourceFormat::Deltasharing);
+        assert_eq!(table.columns.len(), 2);
+        assert_eq!(table.columns[1].name, "date");
+        assert_eq!(table.columns[1].partition_index, Some(0));
+
+        // Unknown shares are reported as errors of the sharing server and do not
+        // leave behind empty catalogs.
+        let request = create_request(
+            http::Method::POST,
+            "/catalogs",
+            Some(create_catalog("missing", "missing_share")),
+        );
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_GATEWAY);
+        let request = create_request(http::Method::GET, "/catalogs/missing", None::<()>);
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+
+        // Shared tables are read through the query API of the sharing server.
+        let ctx = handler.session_context(SessionConfig::new(), &Recipient::anonymous());
+        let batches = ctx
+            .sql("SELECT sum(id) FROM shared.default.events")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let sum = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<datafusion::arrow::array::Int64Array>()
+            .unwrap()
+            .value(0);
+        assert_eq!(sum, 3);
+
+        // Files whose partition values do not match the filters are skipped.
+        let count = |sql: &'static str| {
+            let ctx = ctx.clone();
+            async move {
+                let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+                batches.iter().map(|batch| batch.num_rows()).sum::<usize>()
+            }
+        };
+        assert_eq!(
+            count("SELECT id FROM shared.default.events WHERE date = '2021-04-28'").await,
+            2
+        );
+        assert_eq!(
+            count("SELECT id FROM shared.default.events WHERE date > '2021-04-28'").await,
+            0
+        );
+        let plan = ctx
+            .sql("EXPLAIN SELECT id FROM shared.default.events WHERE date > '2021-04-28'")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let plan = datafusion::arrow::util::pretty::pretty_format_batches(&plan)
+            .unwrap()
+            .to_string();
+        assert!(plan.contains("SharedScanExec"));
+        assert!(plan.contains("partition_filter="));
+
+        // Providers cannot be deleted while catalogs are created from their shares.
+        let request = create_request(http::Method::DELETE, "/providers/remote", None::<()>);
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        assert!(handler.get_secret("providers/remote").await.is_ok());
+        let request = create_request(
+            http::Method::DELETE,
+            "/catalogs/shared?force=true",
+            None::<()>,
+        );
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+
+        let request = create_request(http::Method::DELETE, "/providers/remote", None::<()>);
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert!(matches!(
+            handler.get_secret("providers/remote").await,
+            Err(crate::Error::NotFound)
+        ));
+    }
+
+    /// A server handler with the managed tables `managed.inherited.events` and
+    /// `managed.rooted.events`, and a router for its catalog resources.
+    async fn managed_tables() -> (ServerHandler, axum::Router) {
+        let store = InMemoryResourceStore::new();
+        let handler = ServerHandler::try_new_tokio(
+            Arc::new(ConstantPolicy::default()),
+            Arc::new(store.clone()),
+            Arc::new(store),
+        )
+        .unwrap();
+        let app = get_catalog_router(handler.clone())
+            .merge(get_schemas_router(handler.clone()))
+            .merge(get_credentials_router(handler.clone()))
+            .merge(get_external_locations_router(handler.clone()))
+            .merge(get_tables_router(handler.clone()))
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        super::integration::setup_managed_tables(app.clone()).await;
+        (handler, app)
+    }
+
+    fn events_ident() -> ResourceIdent {
+        ResourceIdent::table(ResourceName::new(["managed", "inherited", "events"]))
+    }
+
+    /// Commit new metadata to the delta log of `managed.inherited.events`, adding the
+    /// column `value`.
+    async fn commit_events_metadata(
+        handler: &ServerHandler,
+        version: u64,
+        configuration: serde_json::Value,
+    ) {
+        let table: TableInfo = handler
+            .get(&events_ident())
+            .await
+            .unwrap()
+            .0
+            .try_into()
+            .unwrap();
+        let location = url::Url::parse(&table.storage_location.unwrap()).unwrap();
+        let log = object_store::path::Path::from_url_path(location.path())
+            .unwrap()
+            .child("_delta_log");
+        let schema = r#"{"type":"struct","fields":[
+            {"name":"id","type":"long","nullable":false,"metadata":{}},
+            {"name":"day","type":"date","nullable":true,"metadata":{}},
+            {"name":"value","type":"double","nullable":true,"metadata":{}}
+        ]}"#;
+        let commit = serde_json::json!({
+            "metaData": {
+                "id": "evolved",
+                "format": { "provider": "parquet", "options": {} },
+                "schemaString": schema,
+                "partitionColumns": ["day"],
+                "configuration": configuration,
+                "createdTime": 0,
+            }
+        });
+        handler
+            .local_storage()
+            .memory_store()
+            .put(
+                &log.child(format!("{version:020}.json")),
+                serde_json::to_vec(&commit).unwrap().into(),
+            )
+            .await
+            .unwrap();
+    }
+
+    fn property(info: &TableInfo, key: &str) -> Option<String> {
+        info.properties
+            .as_ref()
+            .and_then(|props| props.fields.get(key))
+            .and_then(|value| match &value.kind {
+                Some(Kind::StringValue(value)) => Some(value.clone()),
+                _ => None,
+            })
+    }
+
+    /// Create the external tables `json_events` and `csv_events` in `managed.inherited`,
+    /// stored as plain data files.
+    async fn create_landing_tables(handler: &ServerHandler, app: &axum::Router) {
+        let memory_store = handler.local_storage().memory_store();
+        for (path, data) in [
+            (
+                "managed/landing/json/part-0.json",
+                "{\"id\": 1, \"name\": \"a\"}\n",
+            ),
+            ("managed/landing/json/_SUCCESS", ""),
+            ("managed/landing/csv/part-0.csv", "id,name\n1,a\n"),
+        ] {
+            memory_store
+                .put(&object_store::path::Path::from(path), data.into())
+                .await
+                .unwrap();
+        }
+        for (name, format, folder) in [
+            ("json_events", DataSourceFormat::Json, "json"),
+            ("csv_events", DataSourceFormat::Csv, "csv"),
+        ] {
+            let table = CreateTableRequest {
+                name: name.to_string(),
+                catalog_name: "managed".to_string(),
+                schema_name: "inherited".to_string(),
+                table_type: TableType::External as i32,
+                data_source_format: format as i32,
+                storage_location: Some(format!("memory:///managed/landing/{}", folder)),
+                ..Default::default()
+            };
+            let create_table = create_request(http::Method::POST, "/tables", Some(table));
+            let response = app.clone().oneshot(create_table).await.unwrap();
+            assert_eq!(response.status(), http::StatusCode::OK, "create {name}");
+        }
+    }
+
+    /// Create the iceberg table `managed.inherited.iceberg_events` with two metadata
+    /// versions, the second adding the partition column `region`.
+    async fn create_iceberg_table(handler: &ServerHandler, app: &axum::Router) -> TableInfo {
+        for (version, fields) in [
+            (
+                1,
+                r#"[{"id": 1, "name": "id", "required": true, "type": "long"}]"#,
+            ),
+            (
+                2,
+                r#"[{"id": 1, "name": "id", "required": true, "type": "long"},
+                    {"id": 2, "name": "region", "required": false, "type": "string"}]"#,
+            ),
+        ] {
+            let metadata = format!(
+                r#"{{
+                    "format-version": 2,
+                    "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+                    "current-schema-id": 0,
+                    "schemas": [{{"type": "struct", "schema-id": 0, "fields": {fields}}}],
+                    "default-spec-id": 0,
+                    "partition-specs": [{{"spec-id": 0, "fields": [
+                        {{"name": "region", "transform": "identity", "source-id": 2, "field-id": 1000}}
+                    ]}}],
+                    "current-snapshot-id": {version}
+                }}"#
+            );
+            let path = format!("managed/landing/iceberg/metadata/v{version}.metadata.json");
+            handler
+                .local_storage()
+                .memory_store()
+                .put(&object_store::path::Path::from(path), metadata.into())
+                .await
+                .unwrap();
+        }
+        let table = CreateTableRequest {
+            name: "iceberg_events".to_string(),
+            catalog_name: "managed".to_string(),
+            schema_name: "inherited".to_string(),
+            table_type: TableType::External as i32,
+            data_source_format: DataSourceFormat::Iceberg as i32,
+            storage_location: Some("memory:///managed/landing/iceberg".to_string()),
+            ..Default::default()
+        };
+        let create_table = create_request(http::Method::POST, "/tables", Some(table));
+        let response = app.clone().oneshot(create_table).await.unwrap();
+        assert_eq!(
+            response.status(),
+            http::StatusCode::OK,
+            "create iceberg table"
+        );
+        collect_body(response).await
+    }
+
+    fn event_days_view(definition: &str) -> CreateTableRequest {
+        CreateTableRequest {
+            name: "event_days".to_string(),
+            catalog_name: "managed".to_string(),
+            schema_name: "inherited".to_string(),
+            table_type: TableType::View as i32,
+            view_definition: Some(definition.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Create the view `managed.inherited.event_days` over `managed.inherited.events`.
+    async fn create_event_days_view(app: &axum::Router) -> TableInfo {
+        let create_view = create_request(
+            http::Method::POST,
+            "/tables",
+            Some(event_days_view(
+                "SELECT day, count(*) AS total FROM events GROUP BY day",
+            )),
+        );
+        let response = app.clone().oneshot(create_view).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        collect_body(response).await
+    }
+
+    fn query_app(handler: &ServerHandler) -> axum::Router {
+        get_query_router(handler.clone()).layer(AuthenticationLayer::new(AnonymousAuthenticator))
+    }
+
+    fn query_request(statement: &str, max_rows: Option<i32>) -> QueryRequest {
+        QueryRequest {
+            statement: statement.to_string(),
+            catalog_name: Some("managed".to_string()),
+            schema_name: Some("inherited".to_string()),
+            max_rows,
+            ..Default::default()
+        }
+    }
+
+    async fn read_body(response: http::Response<axum::body::Body>) -> bytes::Bytes {
+        assert_eq!(response.status(), http::StatusCode::OK);
+        response.into_body().collect().await.unwrap().to_bytes()
+    }
+
+    fn accept_arrow(
+        mut request: http::Request<axum::body::Body>,
+    ) -> http::Request<axum::body::Body> {
+        request.headers_mut().insert(
+            http::header::ACCEPT,
+            http::HeaderValue::from_static("application/vnd.apache.arrow.stream"),
+        );
+        request
+    }
+
+    #[tokio::test]
+    async fn test_managed_tables() {
+        let (_, app) = managed_tables().await;
+        super::integration::test_managed_tables(app).await;
+    }
+
+    #[tokio::test]
+    async fn test_dropped_tables() {
+        let (handler, app) = managed_tables().await;
+        super::integration::test_dropped_tables(app).await;
+
+        let purged = purge_dropped_tables(&handler, chrono::Duration::zero())
+            .await
+            .unwrap();
+        assert_eq!(purged, 2);
+        let dropped = ResourceIdent::table(ResourceName::new(["managed", "rooted", "events"]));
+        assert!(matches!(
+            handler.get(&dropped).await,
+            Err(crate::Error::NotFound)
+        ));
+        let prefix = object_store::path::Path::from("managed/schema");
+        let remaining: Vec<_> = handler
+            .local_storage()
+            .memory_store()
+            .list(Some(&prefix))
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(remaining.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_delta_tables() {
+        let (handler, app) = managed_tables().await;
+        // tables record the version their metadata was taken from.
+        let stored: TableInfo = handler
+            .get(&events_ident())
+            .await
+            .unwrap()
+            .0
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            property(&stored, "delta.lastUpdateVersion").as_deref(),
+            Some("0")
+        );
+        assert_eq!(sync_delta_tables(&handler).await.unwrap(), 0);
+
+        // Reads serve the stored metadata, which is refreshed by the sync.
+        commit_events_metadata(
+            &handler,
+            1,
+            serde_json::json!({ "delta.appendOnly": "true" }),
+        )
+        .await;
+        let get_table = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.events",
+            None::<()>,
+        );
+        let info: TableInfo = collect_body(app.clone().oneshot(get_table).await.unwrap()).await;
+        assert_eq!(info.columns, stored.columns);
+
+        assert_eq!(sync_delta_tables(&handler).await.unwrap(), 1);
+        let stored: TableInfo = handler
+            .get(&events_ident())
+            .await
+            .unwrap()
+            .0
+            .try_into()
+            .unwrap();
+        let columns: Vec<_> = stored.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(columns, vec!["id", "day", "value"]);
+        assert_eq!(
+            property(&stored, "delta.appendOnly").as_deref(),
+            Some("true")
+        );
+        assert_eq!(property(&stored, "delta.minReaderVersion"), None);
+        assert_eq!(
+            property(&stored, "delta.lastUpdateVersion").as_deref(),
+            Some("1")
+        );
+        assert_eq!(sync_delta_tables(&handler).await.unwrap(), 0);
+
+        // Properties removed from the delta log are removed from the table.
+        commit_events_metadata(&handler, 2, serde_json::json!({})).await;
+        assert_eq!(sync_delta_tables(&handler).await.unwrap(), 1);
+        let stored: TableInfo = handler
+            .get(&events_ident())
+            .await
+            .unwrap()
+            .0
+            .try_into()
+            .unwrap();
+        assert_eq!(property(&stored, "delta.appendOnly"), None);
+        assert_eq!(
+            property(&stored, "delta.lastUpdateVersion").as_deref(),
+            Some("2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delta_metadata() {
+        let (handler, app) = managed_tables().await;
+        commit_events_metadata(
+            &handler,
+            1,
+            serde_json::json!({ "delta.appendOnly": "true" }),
+        )
+        .await;
+
+        // Live metadata is read from the latest snapshot without writing to the store.
+        let get_table = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.events?include_delta_metadata=true",
+            None::<()>,
+        );
+        let info: TableInfo = collect_body(app.clone().oneshot(get_table).await.unwrap()).await;
+        let columns: Vec<_> = info.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(columns, vec!["id", "day", "value"]);
+        let metadata = info.delta_metadata.expect("delta metadata");
+        assert_eq!(metadata.version, 1);
+        assert_eq!(metadata.partition_columns, vec!["day".to_string()]);
+        assert_eq!(
+            metadata
+                .properties
+                .get("delta.appendOnly")
+                .map(String::as_str),
+            Some("true")
+        );
+        assert_eq!((metadata.num_files, metadata.size_in_bytes), (0, 0));
+
+        let list_tables = create_request(
+            http::Method::GET,
+            "/tables?catalog_name=managed&schema_name=inherited&include_delta_metadata=true",
+            None::<()>,
+        );
+        let body: ListTablesResponse =
+            collect_body(app.clone().oneshot(list_tables).await.unwrap()).await;
+        assert_eq!(body.tables.len(), 1);
+        assert_eq!(body.tables[0].delta_metadata.as_ref(), Some(&metadata));
+        let stored: TableInfo = handler
+            .get(&events_ident())
+            .await
+            .unwrap()
+            .0
+            .try_into()
+            .unwrap();
+        assert_eq!(stored.delta_metadata, None);
+        assert_eq!(stored.columns.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_table_summaries() {
+        let (_, app) = managed_tables().await;
+        super::integration::test_table_summaries(app).await;
+    }
+
+    #[tokio::test]
+    async fn test_inferred_schemas() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+
+        // Schemas of tables stored as plain data files are inferred from the files.
+        for name in ["json_events", "csv_events"] {
+            let uri = format!("/tables/managed.inherited.{name}");
+            let get_table = create_request(http::Method::GET, &uri, None::<()>);
+            let info: TableInfo = collect_body(app.clone().oneshot(get_table).await.unwrap()).await;
+            let columns: Vec<_> = info
+                .columns
+                .iter()
+                .map(|c| (c.name.as_str(), c.type_name()))
+                .collect();
+            assert_eq!(
+                columns,
+                vec![
+                    ("id", ColumnTypeName::Long),
+                    ("name", ColumnTypeName::String)
+                ],
+                "{name}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_tables() {
+        let (handler, app) = managed_tables().await;
+
+        // Iceberg tables are read from their latest metadata file.
+        let info = create_iceberg_table(&handler, &app).await;
+        let columns: Vec<_> = info
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.type_name(), c.partition_index))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id", ColumnTypeName::Long, None),
+                ("region", ColumnTypeName::String, Some(0))
+            ]
+        );
+
+        let get_table = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.iceberg_events",
+            None::<()>,
+        );
+        let info: TableInfo = collect_body(app.clone().oneshot(get_table).await.unwrap()).await;
+        let metadata = info.iceberg_metadata.expect("iceberg metadata");
+        assert_eq!(
+            metadata.metadata_location,
+            "memory:///managed/landing/iceberg/metadata/v2.metadata.json"
+        );
+        assert_eq!(metadata.current_snapshot_id, Some(2));
+        assert_eq!(metadata.partition_fields[0].source_column, "region");
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_router() {
+        let (handler, app) = managed_tables().await;
+        create_iceberg_table(&handler, &app).await;
+
+        // Catalogs and schemas are iceberg namespaces, iceberg tables can be loaded.
+        let iceberg = get_iceberg_router(handler.clone())
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        let request = create_request(
+            http::Method::GET,
+            "/v1/namespaces?parent=managed",
+            None::<()>,
+        );
+        let body: ListNamespacesResponse =
+            collect_body(iceberg.clone().oneshot(request).await.unwrap()).await;
+        let mut namespaces = body.namespaces;
+        namespaces.sort();
+        assert_eq!(
+            namespaces,
+            vec![vec!["managed", "inherited"], vec!["managed", "rooted"]]
+        );
+
+        let request = create_request(
+            http::Method::GET,
+            "/v1/namespaces/managed%1Finherited/tables",
+            None::<()>,
+        );
+        let body: ListIcebergTablesResponse =
+            collect_body(iceberg.clone().oneshot(request).await.unwrap()).await;
+        let names: Vec<_> = body.identifiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["iceberg_events"]);
+
+        let request = create_request(
+            http::Method::GET,
+            "/v1/namespaces/managed%1Finherited/tables/iceberg_events",
+            None::<()>,
+        );
+        let body: LoadTableResult =
+            collect_body(iceberg.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(
+            body.metadata_location,
+            "memory:///managed/landing/iceberg/metadata/v2.metadata.json"
+        );
+        assert_eq!(body.metadata["current-snapshot-id"], 2);
+
+        let request = create_request(
+            http::Method::GET,
+            "/v1/namespaces/managed%1Finherited/tables/events",
+            None::<()>,
+        );
+        let response = iceberg.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = collect_body(response).await;
+        assert_eq!(body["error"]["type"], "BadRequestException");
+        assert!(
+            body["error"]["message"]
+                .as_str()
+                .is_some_and(|m| m.contains("cannot be read as iceberg table"))
+        );
+
+        for (uri, error_type) in [
+            (
+                "/v1/namespaces/managed%1Finherited/tables/missing",
+                "NoSuchTableException",
+            ),
+            (
+                "/v1/namespaces/managed%1Fmissing",
+                "NoSuchNamespaceException",
+            ),
+            ("/v1/namespaces?parent=missing", "NoSuchNamespaceException"),
+            (
+                "/v1/namespaces/managed%1Fmissing/tables",
+                "NoSuchNamespaceException",
+            ),
+            ("/v1/namespaces/missing/tables", "NoSuchNamespaceException"),
+        ] {
+            let request = create_request(http::Method::GET, uri, None::<()>);
+            let response = iceberg.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), http::StatusCode::NOT_FOUND, "{uri}");
+            let body: serde_json::Value = collect_body(response).await;
+            assert_eq!(body["error"]["type"], error_type, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_views() {
+        let (handler, app) = managed_tables().await;
+
+        // Views are planned against the tables they reference.
+        let info = create_event_days_view(&app).await;
+        let columns: Vec<_> = info
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.type_name()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("day", ColumnTypeName::Date),
+                ("total", ColumnTypeName::Long)
+            ]
+        );
+        let dependencies: Vec<_> = info
+            .view_dependencies
+            .unwrap_or_default()
+            .dependencies
+            .into_iter()
+            .filter_map(|d| match d.dependency {
+                Some(dependency::Dependency::Table(table)) => Some(table.table_full_name),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(dependencies, vec!["managed.inherited.events"]);
+        let view_ident =
+            ResourceIdent::table(ResourceName::new(["managed", "inherited", "event_days"]));
+        let (depends_on, _) = handler
+            .list_associations(&view_ident, &AssociationLabel::DependsOn, None, None, None)
+            .await
+            .unwrap();
+        let events: TableInfo = handler
+            .get(&events_ident())
+            .await
+            .unwrap()
+            .0
+            .try_into()
+            .unwrap();
+        assert_eq!(depends_on, vec![(&events).into()]);
+
+        for definition in [
+            "SELECT missing FROM events",
+            "SELECT * FROM missing_table",
+            "DELETE FROM events",
+        ] {
+            let create_view = create_request(
+                http::Method::POST,
+                "/tables",
+                Some(event_days_view(definition)),
+            );
+            let response = app.clone().oneshot(create_view).await.unwrap();
+            assert_eq!(
+                response.status(),
+                http::StatusCode::BAD_REQUEST,
+                "{definition}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_catalog() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+        create_event_days_view(&app).await;
+
+        // Tables and views can be queried through DataFusion.
+        let ctx = handler.session_context(SessionConfig::new(), &Recipient::anonymous());
+        for (query, expected) in [
+            ("SELECT count(*) FROM managed.inherited.json_events", 1),
+            ("SELECT count(*) FROM managed.inherited.csv_events", 1),
+            ("SELECT count(*) FROM managed.inherited.events", 0),
+            ("SELECT count(*) FROM managed.inherited.event_days", 0),
+        ] {
+            let batches = ctx.sql(query).await.unwrap().collect().await.unwrap();
+            let count = batches[0]
+                .column(0)
+                .as_any()
+                .downcast_ref::<datafusion::arrow::array::Int64Array>()
+                .unwrap()
+                .value(0);
+            assert_eq!(count, expected, "{query}");
+        }
+        assert!(
+            ctx.sql("SELECT * FROM managed.inherited.missing")
+                .await
+                .is_err()
+        );
+        // the object stores of tables are not shared through the session.
+        let memory = url::Url::parse("memory:///").unwrap();
+        assert!(ctx.runtime_env().object_store(&memory).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_query_endpoint() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+        let query_app = query_app(&handler);
+
+        // Queries are executed via the query endpoint, with results as json or arrow.
+        let request = create_request(
+            http::Method::POST,
+            "/query",
+            Some(query_request("SELECT id, name FROM json_events", None)),
+        );
+        let body = read_body(query_app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(body.as_ref(), b"{\"id\":1,\"name\":\"a\"}\n");
+
+        let union = "SELECT id FROM json_events UNION ALL SELECT id FROM csv_events";
+        let request = create_request(
+            http::Method::POST,
+            "/query",
+            Some(query_request(union, Some(1))),
+        );
+        let body = read_body(query_app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(body.iter().filter(|b| **b == b'\n').count(), 1);
+
+        let request = accept_arrow(create_request(
+            http::Method::POST,
+            "/query",
+            Some(query_request(
+                "SELECT * FROM managed.inherited.csv_events",
+                None,
+            )),
+        ));
+        let body = read_body(query_app.clone().oneshot(request).await.unwrap()).await;
+        let reader = StreamReader::try_new(std::io::Cursor::new(body), None).unwrap();
+        let num_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(num_rows, 1);
+
+        let unqualified = QueryRequest {
+            statement: "SELECT * FROM csv_events".to_string(),
+            ..Default::default()
+        };
+        let request = create_request(http::Method::POST, "/query", Some(unqualified));
+        let response = query_app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        let request = create_request(
+            http::Method::POST,
+            "/query",
+            Some(query_request(
+                "INSERT INTO csv_events VALUES (2, 'b')",
+                None,
+            )),
+        );
+        let response = query_app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_preview_table() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+        create_event_days_view(&app).await;
+        let query_app = query_app(&handler);
+
+        // Delta tables can be previewed with a subset of their columns.
+        let request = accept_arrow(create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.events/preview?limit=10&columns=day",
+            None::<()>,
+        ));
+        let body = read_body(query_app.clone().oneshot(request).await.unwrap()).await;
+        let reader = StreamReader::try_new(std::io::Cursor::new(body), None).unwrap();
+        let fields: Vec<_> = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(fields, vec!["day"]);
+        assert_eq!(
+            reader.map(|batch| batch.unwrap().num_rows()).sum::<usize>(),
+            0
+        );
+        for uri in [
+            "/tables/managed.inherited.events/preview?columns=missing",
+            "/tables/managed.inherited.csv_events/preview",
+            "/tables/managed.inherited.event_days/preview",
+        ] {
+            let request = create_request(http::Method::GET, uri, None::<()>);
+            let response = query_app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), http::StatusCode::BAD_REQUEST, "{uri}");
+        }
+        let request = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.missing/preview",
+            None::<()>,
+        );
+        let response = query_app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_table_history() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+
+        // The history of delta tables is read from their delta log.
+        let request = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.events/history",
+            None::<()>,
+        );
+        let history: GetTableHistoryResponse =
+            collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(history.commits.len(), 1);
+        assert_eq!(history.commits[0].version, 0);
+        assert_eq!(
+            history.commits[0].operation.as_deref(),
+            Some("CREATE TABLE")
+        );
+        assert!(
+            history.commits[0]
+                .engine_info
+                .as_ref()
+                .is_some_and(|info| info.starts_with("unitycatalog-rs/"))
+        );
+        assert_eq!(history.next_end_version, None);
+        for uri in [
+            "/tables/managed.inherited.events/history?start_version=-1",
+            "/tables/managed.inherited.csv_events/history",
+        ] {
+            let request = create_request(http::Method::GET, uri, None::<()>);
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), http::StatusCode::BAD_REQUEST, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_table_detail() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+
+        // Table details are computed from the latest snapshot of delta tables.
+        let request = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.events/detail",
+            None::<()>,
+        );
+        let detail: TableDetail = collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(detail.full_name, "managed.inherited.events");
+        assert_eq!(detail.version, 0);
+        assert_eq!(detail.num_files, 0);
+        assert_eq!(detail.partition_skew, 0.0);
+        assert_eq!(detail.file_size_histogram.len(), 7);
+        let request = create_request(
+            http::Method::GET,
+            "/tables/managed.inherited.csv_events/detail",
+            None::<()>,
+        );
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_system_catalog() {
+        let (handler, app) = managed_tables().await;
+        create_landing_tables(&handler, &app).await;
+        let query_app = query_app(&handler);
+
+        // The system catalog exposes the metadata of readable resources as tables.
+        let request = create_request(
+            http::Method::POST,
+            "/query",
+            Some(query_request(
+                "SELECT table_name, data_source_format FROM system.information_schema.tables \
+                 WHERE table_schema = 'inherited' ORDER BY table_name",
+                None,
+            )),
+        );
+        let body = read_body(query_app.clone().oneshot(request).await.unwrap()).await;
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(r#"{"table_name":"csv_events","data_source_format":"CSV"}"#));
+        let request = create_request(
+            http::Method::POST,
+            "/query",
+            Some(query_request(
+                "SELECT column_name FROM system.information_schema.columns \
+                 WHERE table_name = 'csv_events' AND column_name = 'id'",
+                None,
+            )),
+        );
+        let body = read_body(query_app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(body.as_ref(), b"{\"column_name\":\"id\"}\n");
+
+        let request = create_request(
+            http::Method::GET,
+            "/tables?catalog_name=system&schema_name=information_schema",
+            None::<()>,
+        );
+        let body: ListTablesResponse =
+            collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(body.tables.len(), 5);
+        let request = create_request(
+            http::Method::GET,
+            "/tables/system.information_schema.columns",
+            None::<()>,
+        );
+        let info: TableInfo = collect_body(app.clone().oneshot(request).await.unwrap()).await;
+        assert_eq!(info.columns[3].name, "column_name");
+        let table = CreateTableRequest {
+            name: "tables".to_string(),
+            catalog_name: "system".to_string(),
+            schema_name: "information_schema".to_string(),
+            table_type: TableType::External as i32,
+            ..Default::default()
+        };
+        let request = create_request(http::Method::POST, "/tables", Some(table));
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        for (method, uri) in [
+            (http::Method::DELETE, "/catalogs/system"),
+            (http::Method::DELETE, "/schemas/system.information_schema"),
+        ] {
+            let request = create_request(method, uri, None::<()>);
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), http::StatusCode::BAD_REQUEST, "{uri}");
+        }
+        // the name of the system catalog is reserved, but catalogs stored under
+        // that name before it was reserved are found.
+        let catalog = CreateCatalogRequest {
+            name: "System".to_string(),
+            ..Default::default()
+        };
+        let request = create_request(http::Method::POST, "/catalogs", Some(catalog));
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        assert!(find_shadowed_catalog(&handler).await.unwrap().is_none());
+        let catalog = CatalogInfo {
+            name: "system".to_string(),
+            ..Default::default()
+        };
+        handler.create(catalog.into()).await.unwrap();
+        assert!(find_shadowed_catalog(&handler).await.unwrap().is_some());
+    }
 }
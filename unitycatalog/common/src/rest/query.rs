@@ -0,0 +1,134 @@
+use axum::body::Body;
+use axum::extract::{Extension, Json, Path, Query, State};
+use axum::response::Response;
+use axum::routing::{Router, get, post};
+use bytes::Bytes;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::arrow::json::LineDelimitedWriter;
+use datafusion::error::DataFusionError;
+use datafusion::execution::SendableRecordBatchStream;
+use futures_util::StreamExt;
+use http::header::{ACCEPT, CONTENT_TYPE};
+use http::{HeaderMap, HeaderValue};
+use serde::Deserialize;
+
+use crate::Result;
+use crate::api::RequestContext;
+use crate::api::query::{
+    ARROW_STREAM_CONTENT_TYPE, NDJSON_CONTENT_TYPE, PreviewTableRequest, QueryHandler, QueryRequest,
+};
+use crate::services::policy::Recipient;
+
+/// Create a new [Router] for SQL queries and table previews.
+///
+/// Both are executed by the query engine, so they are served apart from the tables router.
+pub fn get_router<T: QueryHandler + Clone>(handler: T) -> Router {
+    Router::new()
+        .route("/query", post(execute_query::<T>))
+        .route("/tables/{name}/preview", get(preview_table::<T>))
+        .with_state(handler)
+}
+
+#[derive(Debug, Deserialize)]
+struct PreviewTableParams {
+    limit: Option<i32>,
+    /// Comma separated names of the columns to include.
+    columns: Option<String>,
+}
+
+/// Execute a query and stream its results.
+///
+/// Results are sent in the Arrow IPC streaming format if requested via the `Accept` header,
+/// otherwise as newline delimited JSON.
+async fn execute_query<T: QueryHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    headers: HeaderMap,
+    Json(request): Json<QueryRequest>,
+) -> Result<Response> {
+    let ctx = RequestContext { recipient };
+    let stream = handler.execute_query(request, ctx).await?;
+    stream_response(stream, &headers)
+}
+
+/// Read the first rows of a table.
+///
+/// Rows are encoded like query results, depending on the `Accept` header.
+async fn preview_table<T: QueryHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    Path(full_name): Path<String>,
+    Query(params): Query<PreviewTableParams>,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let ctx = RequestContext { recipient };
+    let request = PreviewTableRequest {
+        full_name,
+        limit: params.limit,
+        columns: params
+            .columns
+            .map(|columns| {
+                columns
+                    .split(',')
+                    .map(|column| column.trim().to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    let stream = handler.preview_table(request, ctx).await?;
+    stream_response(stream, &headers)
+}
+
+fn stream_response(stream: SendableRecordBatchStream, headers: &HeaderMap) -> Result<Response> {
+    let accepts_arrow = headers
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains(ARROW_STREAM_CONTENT_TYPE));
+    let (content_type, body) = if accepts_arrow {
+        (ARROW_STREAM_CONTENT_TYPE, arrow_body(stream)?)
+    } else {
+        (NDJSON_CONTENT_TYPE, json_body(stream))
+    };
+    let mut response = Response::new(body);
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    Ok(response)
+}
+
+fn arrow_body(stream: SendableRecordBatchStream) -> Result<Body> {
+    let mut writer =
+        StreamWriter::try_new(Vec::new(), &stream.schema()).map_err(DataFusionError::from)?;
+    let header = Bytes::from(std::mem::take(writer.get_mut()));
+    let batches = futures_util::stream::unfold(Some((stream, writer)), |state| async move {
+        let (mut stream, mut writer) = state?;
+        match stream.next().await {
+            Some(Ok(batch)) => {
+                let chunk = writer
+                    .write(&batch)
+                    .map(|_| Bytes::from(std::mem::take(writer.get_mut())))
+                    .map_err(DataFusionError::from);
+                Some((chunk, Some((stream, writer))))
+            }
+            Some(Err(e)) => Some((Err(e), None)),
+            None => {
+                let chunk = writer
+                    .finish()
+                    .map(|_| Bytes::from(std::mem::take(writer.get_mut())))
+                    .map_err(DataFusionError::from);
+                Some((chunk, None))
+            }
+        }
+    });
+    let body = futures_util::stream::once(async move { Ok(header) }).chain(batches);
+    Ok(Body::from_stream(body))
+}
+
+fn json_body(stream: SendableRecordBatchStream) -> Body {
+    Body::from_stream(stream.map(|batch| {
+        let mut writer = LineDelimitedWriter::new(Vec::new());
+        writer.write(&batch?)?;
+        writer.finish()?;
+        Ok::<_, DataFusionError>(Bytes::from(writer.into_inner()))
+    }))
+}
@@ -0,0 +1,1375 @@
+//! DataFusion catalogs over the catalogs, schemas and tables in the resource store.
+//!
+//! Catalogs, schemas and tables are resolved when a query references them, so creating
+//! a session does not touch the store. Loading a table requires the `Select` permission.
+//! As DataFusion lists catalogs, schemas and tables synchronously, they are not
+//! enumerated by the providers; the `information_schema` of the system catalog lists
+//! the resources the recipient can read.
+//!
+//! The tables of the read-only `system` catalog are generated when a query references
+//! them, and only contain the resources the recipient can read.
+use std::any::Any;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{
+    ArrayRef, AsArray, BooleanArray, Int32Array, RecordBatch, StringArray,
+    TimestampMicrosecondArray,
+};
+use datafusion::arrow::compute::{cast, filter_record_batch};
+use datafusion::arrow::datatypes::{Schema as ArrowSchema, SchemaRef};
+use datafusion::catalog::{
+    CatalogProvider, CatalogProviderList, SchemaProvider, Session, TableProvider,
+};
+use datafusion::datasource::MemTable;
+use datafusion::datasource::file_format::FileFormat;
+use datafusion::datasource::file_format::csv::CsvFormat;
+use datafusion::datasource::file_format::json::JsonFormat;
+use datafusion::datasource::file_format::parquet::ParquetFormat;
+use datafusion::datasource::listing::{
+    ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+};
+use datafusion::datasource::{TableType as DFTableType, ViewTable};
+use datafusion::execution::context::SessionState;
+use datafusion::execution::object_store::{DefaultObjectStoreRegistry, ObjectStoreRegistry};
+use datafusion::execution::runtime_env::RuntimeEnv;
+use datafusion::execution::{SendableRecordBatchStream, SessionStateBuilder, TaskContext};
+use datafusion::logical_expr::expr_rewriter::unnormalize_col;
+use datafusion::logical_expr::utils::conjunction;
+use datafusion::logical_expr::{Expr, TableProviderFilterPushDown};
+use datafusion::physical_expr::{EquivalenceProperties, PhysicalExpr};
+use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::stream::RecordBatchReceiverStream;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties,
+};
+use datafusion::prelude::SessionContext;
+use datafusion_common::{DFSchema, DataFusionError, Result as DFResult};
+use delta_kernel::engine::arrow_conversion::TryFromKernel as _;
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::engine::default::DefaultEngine;
+use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+use delta_kernel::expressions::ExpressionRef;
+use delta_kernel::schema::StructType;
+use delta_kernel::{Engine, Snapshot, Version};
+use delta_kernel_datafusion::ObjectStoreFactory;
+use futures_util::StreamExt;
+use object_store::DynObjectStore;
+use strum::IntoEnumIterator;
+use tokio::sync::mpsc::Sender;
+use url::Url;
+
+use crate::api::tables::{columns_to_schema, get_active_table};
+use crate::models::catalogs::v1::{CatalogInfo, CatalogType};
+use crate::models::schemas::v1::SchemaInfo;
+use crate::models::sharing::v1::{JsonPredicate, SharingTable};
+use crate::models::tables::v1::{DataSourceFormat, TableInfo, TableType};
+use crate::models::{ObjectLabel, Resource};
+use crate::resources::{ResourceIdent, ResourceName, ResourceStore};
+use crate::services::delta_sharing::{DeltaSharingClient, SharedFile, provider_client};
+use crate::services::kernel::{
+    filter_to_json_predicate, filters_to_json_predicate, json_predicate_to_expression,
+};
+use crate::services::location::StorageLocationUrl;
+use crate::services::policy::{Decision, Permission, Policy, Recipient, process_resources};
+use crate::services::secrets::SecretManager;
+use crate::services::system::{
+    INFORMATION_SCHEMA, InformationSchemaTable, SYSTEM_CATALOG, information_schema_info,
+    is_system_catalog, system_catalog_info, system_tables,
+};
+use crate::{Error, Result};
+
+/// Register the catalogs of the resource store with a DataFusion session.
+///
+/// Catalogs are resolved lazily, see the [module documentation](self).
+pub fn register_catalogs<H>(ctx: &SessionContext, handler: Arc<H>, recipient: &Recipient)
+where
+    H: ResourceStore + Policy + SecretManager + ObjectStoreFactory,
+{
+    let information_schema = InformationSchemaProvider {
+        handler: handler.clone(),
+        recipient: recipient.clone(),
+    };
+    ctx.register_catalog_list(Arc::new(UnityCatalogList {
+        handler,
+        recipient: recipient.clone(),
+        system: Arc::new(SystemCatalogProvider {
+            information_schema: Arc::new(information_schema),
+        }),
+    }));
+}
+
+/// The catalogs in the resource store, exposed as DataFusion catalogs.
+struct UnityCatalogList<H> {
+    handler: Arc<H>,
+    recipient: Recipient,
+    system: Arc<dyn CatalogProvider>,
+}
+
+impl<H> std::fmt::Debug for UnityCatalogList<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnityCatalogList").finish()
+    }
+}
+
+impl<H> CatalogProviderList for UnityCatalogList<H>
+where
+    H: ResourceStore + Policy + SecretManager + ObjectStoreFactory,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn register_catalog(
+        &self,
+        _name: String,
+        _catalog: Arc<dyn CatalogProvider>,
+    ) -> Option<Arc<dyn CatalogProvider>> {
+        // catalogs are managed through the catalogs api, not through queries.
+        None
+    }
+
+    fn catalog_names(&self) -> Vec<String> {
+        vec![SYSTEM_CATALOG.to_string()]
+    }
+
+    fn catalog(&self, name: &str) -> Option<Arc<dyn CatalogProvider>> {
+        if is_system_catalog(name) {
+            return Some(self.system.clone());
+        }
+        Some(Arc::new(UnityCatalogProvider {
+            handler: self.handler.clone(),
+            recipient: self.recipient.clone(),
+            catalog_name: name.to_string(),
+        }))
+    }
+}
+
+/// A catalog in the resource store, exposed as DataFusion catalog.
+pub struct UnityCatalogProvider<H> {
+    handler: Arc<H>,
+    recipient: Recipient,
+    catalog_name: String,
+}
+
+impl<H> std::fmt::Debug for UnityCatalogProvider<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnityCatalogProvider")
+            .field("catalog_name", &self.catalog_name)
+            .finish()
+    }
+}
+
+impl<H> CatalogProvider for UnityCatalogProvider<H>
+where
+    H: ResourceStore + Policy + SecretManager + ObjectStoreFactory,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        Some(Arc::new(UnitySchemaProvider {
+            handler: self.handler.clone(),
+            recipient: self.recipient.clone(),
+            catalog_name: self.catalog_name.clone(),
+            schema_name: name.to_string(),
+        }))
+    }
+}
+
+/// A schema in the resource store, exposed as DataFusion schema.
+pub struct UnitySchemaProvider<H> {
+    handler: Arc<H>,
+    recipient: Recipient,
+    catalog_name: String,
+    schema_name: String,
+}
+
+impl<H> std::fmt::Debug for UnitySchemaProvider<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnitySchemaProvider")
+            .field("catalog_name", &self.catalog_name)
+            .field("schema_name", &self.schema_name)
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl<H> SchemaProvider for UnitySchemaProvider<H>
+where
+    H: ResourceStore + Policy + SecretManager + ObjectStoreFactory,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    async fn table(&self, name: &str) -> DFResult<Option<Arc<dyn TableProvider>>> {
+        let ident = ResourceIdent::table(ResourceName::new([
+            self.catalog_name.as_str(),
+            self.schema_name.as_str(),
+            name,
+        ]));
+        self.handler
+            .authorize_checked(&ident, &Permission::Select, &self.recipient)
+            .await
+            .map_err(to_datafusion)?;
+        let info = match get_active_table(self.handler.as_ref(), &ident).await {
+            Ok(info) => info,
+            Err(Error::NotFound) => return Ok(None),
+            Err(e) => return Err(to_datafusion(e)),
+        };
+        table_provider(self.handler.as_ref(), info)
+            .await
+            .map(Some)
+            .map_err(to_datafusion)
+    }
+
+    fn table_exist(&self, _name: &str) -> bool {
+        // existence can only be checked asynchronously, see `table`.
+        false
+    }
+}
+
+/// The read-only system catalog.
+#[derive(Debug)]
+struct SystemCatalogProvider {
+    information_schema: Arc<dyn SchemaProvider>,
+}
+
+impl CatalogProvider for SystemCatalogProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema_names(&self) -> Vec<String> {
+        vec![INFORMATION_SCHEMA.to_string()]
+    }
+
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        (name == INFORMATION_SCHEMA).then(|| self.information_schema.clone())
+    }
+}
+
+/// The information schema of the system catalog.
+///
+/// Tables are generated when they are referenced, so every query sees the current state.
+struct InformationSchemaProvider<H> {
+    handler: Arc<H>,
+    recipient: Recipient,
+}
+
+impl<H> std::fmt::Debug for InformationSchemaProvider<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InformationSchemaProvider").finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl<H: ResourceStore + Policy> SchemaProvider for InformationSchemaProvider<H> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        InformationSchemaTable::iter()
+            .map(|table| table.as_ref().to_string())
+            .collect()
+    }
+
+    async fn table(&self, name: &str) -> DFResult<Option<Arc<dyn TableProvider>>> {
+        let Some(table) = InformationSchemaTable::find(INFORMATION_SCHEMA, name) else {
+            return Ok(None);
+        };
+        let batch = information_schema_batch(self.handler.as_ref(), &self.recipient, table)
+            .await
+            .map_err(to_datafusion)?;
+        Ok(Some(Arc::new(MemTable::try_new(
+            batch.schema(),
+            vec![vec![batch]],
+        )?)))
+    }
+
+    fn table_exist(&self, name: &str) -> bool {
+        InformationSchemaTable::find(INFORMATION_SCHEMA, name).is_some()
+    }
+}
+
+/// Permissions reported in the `table_privileges` table of the information schema.
+const TABLE_PRIVILEGES: [Permission; 4] = [
+    Permission::Read,
+    Permission::Select,
+    Permission::Write,
+    Permission::Manage,
+];
+
+/// Generate the rows of a table in the information schema.
+///
+/// Only resources the recipient can read are included.
+pub(crate) async fn information_schema_batch<H: ResourceStore + Policy>(
+    handler: &H,
+    recipient: &Recipient,
+    table: InformationSchemaTable,
+) -> Result<RecordBatch> {
+    let schema = arrow_schema(&table.schema())?;
+    let columns: Vec<ArrayRef> = match table {
+        InformationSchemaTable::Catalogs => {
+            let mut catalogs: Vec<CatalogInfo> =
+                list_all(handler, recipient, &ObjectLabel::CatalogInfo, None)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.try_into())
+                    .collect::<Result<_>>()?;
+            catalogs.push(system_catalog_info());
+            vec![
+                strings(catalogs.iter().map(|c| Some(c.name.as_str()))),
+                strings(catalogs.iter().map(|c| {
+                    c.catalog_type
+                        .and_then(|t| CatalogType::try_from(t).ok())
+                        .map(|t| t.as_str_name())
+                })),
+                strings(catalogs.iter().map(|c| c.comment.as_deref())),
+                strings(catalogs.iter().map(|c| c.owner.as_deref())),
+                timestamps(catalogs.iter().map(|c| c.created_at)),
+            ]
+        }
+        InformationSchemaTable::Schemas => {
+            let mut schemas: Vec<SchemaInfo> =
+                list_all(handler, recipient, &ObjectLabel::SchemaInfo, None)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.try_into())
+                    .collect::<Result<_>>()?;
+            schemas.push(information_schema_info());
+            vec![
+                strings(schemas.iter().map(|s| Some(s.catalog_name.as_str()))),
+                strings(schemas.iter().map(|s| Some(s.name.as_str()))),
+                strings(schemas.iter().map(|s| s.comment.as_deref())),
+                strings(schemas.iter().map(|s| s.owner.as_deref())),
+                timestamps(schemas.iter().map(|s| s.created_at)),
+            ]
+        }
+        InformationSchemaTable::Tables => {
+            let tables = readable_tables(handler, recipient).await?;
+            vec![
+                strings(tables.iter().map(|t| Some(t.catalog_name.as_str()))),
+                strings(tables.iter().map(|t| Some(t.schema_name.as_str()))),
+                strings(tables.iter().map(|t| Some(t.name.as_str()))),
+                strings(tables.iter().map(|t| Some(t.table_type().as_str_name()))),
+                strings(tables.iter().map(|t| {
+                    (t.data_source_format != DataSourceFormat::Unspecified as i32)
+                        .then(|| t.data_source_format().as_str_name())
+                })),
+                strings(tables.iter().map(|t| t.storage_location.as_deref())),
+                strings(tables.iter().map(|t| t.comment.as_deref())),
+                strings(tables.iter().map(|t| t.owner.as_deref())),
+                timestamps(tables.iter().map(|t| t.created_at)),
+            ]
+        }
+        InformationSchemaTable::Columns => {
+            let tables = readable_tables(handler, recipient).await?;
+            let columns: Vec<_> = tables
+                .iter()
+                .flat_map(|t| t.columns.iter().map(move |c| (t, c)))
+                .collect();
+            vec![
+                strings(columns.iter().map(|(t, _)| Some(t.catalog_name.as_str()))),
+                strings(columns.iter().map(|(t, _)| Some(t.schema_name.as_str()))),
+                strings(columns.iter().map(|(t, _)| Some(t.name.as_str()))),
+                strings(columns.iter().map(|(_, c)| Some(c.name.as_str()))),
+                Arc::new(Int32Array::from_iter(
+                    columns.iter().map(|(_, c)| c.position),
+                )),
+                strings(columns.iter().map(|(_, c)| Some(c.type_text.as_str()))),
+                Arc::new(BooleanArray::from_iter(
+                    columns.iter().map(|(_, c)| c.nullable),
+                )),
+                Arc::new(Int32Array::from_iter(
+                    columns.iter().map(|(_, c)| c.partition_index),
+                )),
+                strings(columns.iter().map(|(_, c)| c.comment.as_deref())),
+            ]
+        }
+        InformationSchemaTable::TablePrivileges => {
+            // system tables are not resources, so only stored tables carry privileges.
+            let tables: Vec<_> = readable_tables(handler, recipient)
+                .await?
+                .into_iter()
+                .filter(|t| t.catalog_name != SYSTEM_CATALOG)
+                .collect();
+            let idents: Vec<_> = tables
+                .iter()
+                .map(|t| {
+                    ResourceIdent::table(ResourceName::new([
+                        t.catalog_name.as_str(),
+                        t.schema_name.as_str(),
+                        t.name.as_str(),
+                    ]))
+                })
+                .collect();
+            let mut privileges = Vec::new();
+            for permission in &TABLE_PRIVILEGES {
+                let decisions = handler
+                    .authorize_many(&idents, permission, recipient)
+                    .await?;
+                for (table, decision) in tables.iter().zip(decisions) {
+                    if decision == Decision::Allow {
+                        privileges.push((table, permission.as_ref().to_uppercase()));
+                    }
+                }
+            }
+            vec![
+                strings(privileges.iter().map(|_| recipient.user_name())),
+                strings(
+                    privileges
+                        .iter()
+                        .map(|(t, _)| Some(t.catalog_name.as_str())),
+                ),
+                strings(privileges.iter().map(|(t, _)| Some(t.schema_name.as_str()))),
+                strings(privileges.iter().map(|(t, _)| Some(t.name.as_str()))),
+                strings(privileges.iter().map(|(_, p)| Some(p.as_str()))),
+            ]
+        }
+    };
+    Ok(RecordBatch::try_new(schema, columns).map_err(DataFusionError::from)?)
+}
+
+/// All active tables the recipient can read, including the tables of the system catalog.
+async fn readable_tables<H: ResourceStore + Policy>(
+    handler: &H,
+    recipient: &Recipient,
+) -> Result<Vec<TableInfo>> {
+    let mut tables = Vec::new();
+    for resource in list_all(handler, recipient, &ObjectLabel::TableInfo, None).await? {
+        let info: TableInfo = resource.try_into()?;
+        if info.deleted_at.is_none() {
+            tables.push(info);
+        }
+    }
+    tables.extend(system_tables()?);
+    Ok(tables)
+}
+
+fn strings<'a>(values: impl Iterator<Item = Option<&'a str>>) -> ArrayRef {
+    Arc::new(StringArray::from_iter(values))
+}
+
+/// Convert epoch milliseconds to the microsecond timestamps used by delta.
+fn timestamps(values: impl Iterator<Item = Option<i64>>) -> ArrayRef {
+    Arc::new(
+        TimestampMicrosecondArray::from_iter(values.map(|v| v.map(|ms| ms * 1000)))
+            .with_timezone("UTC"),
+    )
+}
+
+/// Create a table provider for the data source format of a table.
+async fn table_provider<H: ResourceStore + SecretManager + ObjectStoreFactory>(
+    handler: &H,
+    info: TableInfo,
+) -> Result<Arc<dyn TableProvider>> {
+    if info.table_type == TableType::View as i32 {
+        let Some(definition) = info.view_definition else {
+            return Err(Error::invalid_argument("view is missing its definition"));
+        };
+        return Ok(Arc::new(UnityViewProvider {
+            schema: arrow_schema(&columns_to_schema(&info.columns)?)?,
+            definition,
+            catalog_name: info.catalog_name,
+            schema_name: info.schema_name,
+        }));
+    }
+    if info.data_source_format() == DataSourceFormat::Deltasharing {
+        return shared_table_provider(handler, info).await;
+    }
+    let Some(location) = info.storage_location.as_deref() else {
+        return Err(Error::invalid_argument("missing storage location"));
+    };
+    let location = StorageLocationUrl::parse(location)?;
+    let store = handler.create_object_store(location.location()).await?;
+    let format = info.data_source_format();
+    let file_format: Arc<dyn FileFormat> = match format {
+        DataSourceFormat::Delta => {
+            return Ok(Arc::new(
+                DeltaTableProvider::try_new(store, &location).await?,
+            ));
+        }
+        DataSourceFormat::Parquet => Arc::new(ParquetFormat::default()),
+        DataSourceFormat::Csv => Arc::new(CsvFormat::default().with_has_header(true)),
+        DataSourceFormat::Json => Arc::new(JsonFormat::default()),
+        _ => {
+            return Err(Error::invalid_argument(format!(
+                "cannot query tables with data source format: {:?}",
+                format
+            )));
+        }
+    };
+    let table_url = ListingTableUrl::parse(location.table_root().as_str())?;
+    let options =
+        ListingOptions::new(file_format.clone()).with_file_extension(file_format.get_ext());
+    let config = ListingTableConfig::new(table_url.clone())
+        .with_listing_options(options)
+        .with_schema(arrow_schema(&columns_to_schema(&info.columns)?)?);
+    Ok(Arc::new(FileTableProvider {
+        table: ListingTable::try_new(config)?,
+        url: table_url,
+        store,
+    }))
+}
+
+/// A table stored as plain data files.
+///
+/// The object store of a table carries the credentials of its location, so it is never
+/// registered with the session. Instead the table is planned and executed with a runtime
+/// that only knows the store of this table.
+#[derive(Debug)]
+struct FileTableProvider {
+    table: ListingTable,
+    url: ListingTableUrl,
+    store: Arc<DynObjectStore>,
+}
+
+impl FileTableProvider {
+    /// A copy of `runtime` that resolves the url of this table to its object store.
+    fn runtime_env(&self, runtime: &RuntimeEnv) -> Arc<RuntimeEnv> {
+        let registry = DefaultObjectStoreRegistry::new();
+        registry.register_store(self.url.object_store().as_ref(), self.store.clone());
+        Arc::new(RuntimeEnv {
+            memory_pool: runtime.memory_pool.clone(),
+            disk_manager: runtime.disk_manager.clone(),
+            cache_manager: runtime.cache_manager.clone(),
+            object_store_registry: Arc::new(registry),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TableProvider for FileTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.table.schema()
+    }
+
+    fn table_type(&self) -> DFTableType {
+        DFTableType::Base
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> DFResult<Vec<TableProviderFilterPushDown>> {
+        self.table.supports_filters_pushdown(filters)
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let Some(state) = state.as_any().downcast_ref::<SessionState>() else {
+            return Err(DataFusionError::Internal(
+                "tables can only be planned in a SessionState".to_string(),
+            ));
+        };
+        let runtime = self.runtime_env(state.runtime_env());
+        // files are listed while planning, so planning needs the store as well.
+        let table_state = SessionStateBuilder::new_from_existing(state.clone())
+            .with_runtime_env(runtime.clone())
+            .build();
+        let input = self
+            .table
+            .scan(&table_state, projection, filters, limit)
+            .await?;
+        Ok(Arc::new(TableStoreExec { input, runtime }))
+    }
+}
+
+/// Executes the scan of a [`FileTableProvider`] with the runtime holding its object store.
+#[derive(Debug)]
+struct TableStoreExec {
+    input: Arc<dyn ExecutionPlan>,
+    runtime: Arc<RuntimeEnv>,
+}
+
+impl DisplayAs for TableStoreExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "TableStoreExec")
+    }
+}
+
+impl ExecutionPlan for TableStoreExec {
+    fn name(&self) -> &str {
+        "TableStoreExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        self.input.properties()
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.input]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if children.len() != 1 {
+            return Err(DataFusionError::Internal(
+                "TableStoreExec requires exactly one child".to_string(),
+            ));
+        }
+        Ok(Arc::new(TableStoreExec {
+            input: children.swap_remove(0),
+            runtime: self.runtime.clone(),
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DFResult<SendableRecordBatchStream> {
+        let context = TaskContext::new(
+            context.task_id(),
+            context.session_id(),
+            context.session_config().clone(),
+            context.scalar_functions().clone(),
+            context.aggregate_functions().clone(),
+            context.window_functions().clone(),
+            self.runtime.clone(),
+        );
+        self.input.execute(partition, Arc::new(context))
+    }
+}
+
+/// A delta table at a fixed version.
+///
+/// The table is read with the kernel's default engine, which applies deletion vectors,
+/// column mapping and partition values.
+#[derive(Debug, Clone)]
+struct DeltaTableProvider {
+    store: Arc<DynObjectStore>,
+    table_root: Url,
+    version: Version,
+    schema: StructType,
+    arrow_schema: SchemaRef,
+}
+
+impl DeltaTableProvider {
+    async fn try_new(store: Arc<DynObjectStore>, location: &StorageLocationUrl) -> Result<Self> {
+        let table_root = location.table_root();
+        let (version, schema) = {
+            let store = store.clone();
+            let table_root = table_root.clone();
+            // log replay is blocking, so the snapshot is read on a dedicated blocking thread.
+            tokio::task::spawn_blocking(move || -> Result<_> {
+                let engine = DefaultEngine::new(store, Arc::new(TokioBackgroundExecutor::new()));
+                let snapshot = Snapshot::try_new(table_root, &engine, None)?;
+                Ok((
+                    snapshot.version(),
+                    StructType::new(snapshot.schema().fields().cloned()),
+                ))
+            })
+            .await
+            .map_err(|e| Error::generic(e.to_string()))??
+        };
+        Ok(Self {
+            arrow_schema: arrow_schema(&schema)?,
+            store,
+            table_root,
+            version,
+            schema,
+        })
+    }
+
+    /// Plan a scan of the table, reading only the projected columns.
+    ///
+    /// The predicate is only used to skip files, rows that do not match it may be returned.
+    fn scan_exec(
+        &self,
+        projection: Option<&Vec<usize>>,
+        predicate: Option<ExpressionRef>,
+        limit: Option<usize>,
+    ) -> Result<DeltaScanExec> {
+        let (schema, arrow_schema) = match projection {
+            Some(projection) => {
+                let fields: Vec<_> = projection
+                    .iter()
+                    .filter_map(|idx| self.schema.fields().nth(*idx).cloned())
+                    .collect();
+                let arrow_schema = Arc::new(
+                    self.arrow_schema
+                        .project(projection)
+                        .map_err(DataFusionError::from)?,
+                );
+                (Arc::new(StructType::new(fields)), arrow_schema)
+            }
+            None => (Arc::new(self.schema.clone()), self.arrow_schema.clone()),
+        };
+        let properties = PlanProperties::new(
+            EquivalenceProperties::new(arrow_schema.clone()),
+            Partitioning::UnknownPartitioning(1),
+            EmissionType::Incremental,
+            Boundedness::Bounded,
+        );
+        Ok(DeltaScanExec {
+            table: self.clone(),
+            schema,
+            arrow_schema,
+            predicate,
+            limit,
+            properties,
+        })
+    }
+}
+
+/// Streams the rows of a delta table scan.
+///
+/// The kernel reads data with blocking calls, so the scan runs on a blocking thread
+/// once the plan is executed and hands its batches over through a bounded channel.
+/// Dropping the stream stops the scan.
+#[derive(Debug)]
+struct DeltaScanExec {
+    table: DeltaTableProvider,
+    schema: Arc<StructType>,
+    arrow_schema: SchemaRef,
+    predicate: Option<ExpressionRef>,
+    limit: Option<usize>,
+    properties: PlanProperties,
+}
+
+/// Number of batches buffered between the kernel scan and the consumer of the stream.
+const SCAN_BUFFER_SIZE: usize = 2;
+
+impl DeltaScanExec {
+    /// Read the table and send its batches, until the limit is reached or the receiver
+    /// is dropped.
+    fn read(
+        table: &DeltaTableProvider,
+        schema: Arc<StructType>,
+        arrow_schema: SchemaRef,
+        predicate: Option<ExpressionRef>,
+        limit: Option<usize>,
+        tx: &Sender<DFResult<RecordBatch>>,
+    ) -> Result<()> {
+        let engine: Arc<dyn Engine> = Arc::new(DefaultEngine::new(
+            table.store.clone(),
+            Arc::new(TokioBackgroundExecutor::new()),
+        ));
+        let snapshot = Snapshot::try_new(
+            table.table_root.clone(),
+            engine.as_ref(),
+            Some(table.version),
+        )?;
+        let scan = snapshot
+            .into_scan_builder()
+            .with_schema(schema)
+            .with_predicate(predicate)
+            .build()?;
+
+        let mut remaining = limit;
+        for result in scan.execute(engine)? {
+            if remaining == Some(0) {
+                break;
+            }
+            let result = result?;
+            let mask = result.full_mask();
+            let data = ArrowEngineData::try_from_engine_data(result.raw_data?)?;
+            let batch: RecordBatch = (*data).into();
+            let batch = match mask {
+                Some(mask) => filter_record_batch(&batch, &BooleanArray::from(mask))
+                    .map_err(DataFusionError::from)?,
+                None => batch,
+            };
+            let mut batch = RecordBatch::try_new(arrow_schema.clone(), batch.columns().to_vec())
+                .map_err(DataFusionError::from)?;
+            if let Some(remaining) = remaining.as_mut() {
+                batch = batch.slice(0, batch.num_rows().min(*remaining));
+                *remaining -= batch.num_rows();
+            }
+            if tx.blocking_send(Ok(batch)).is_err() {
+                // the stream was dropped, e.g. because the query was cancelled.
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DisplayAs for DeltaScanExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "DeltaScanExec: table={}, version={}",
+            self.table.table_root, self.table.version
+        )?;
+        if let Some(limit) = self.limit {
+            write!(f, ", limit={limit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ExecutionPlan for DeltaScanExec {
+    fn name(&self) -> &str {
+        "DeltaScanExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        _partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> DFResult<SendableRecordBatchStream> {
+        let mut builder =
+            RecordBatchReceiverStream::builder(self.arrow_schema.clone(), SCAN_BUFFER_SIZE);
+        let tx = builder.tx();
+        let table = self.table.clone();
+        let schema = self.schema.clone();
+        let arrow_schema = self.arrow_schema.clone();
+        let predicate = self.predicate.clone();
+        let limit = self.limit;
+        builder.spawn_blocking(move || {
+            if let Err(e) = Self::read(&table, schema, arrow_schema, predicate, limit, &tx) {
+                // the receiver may be gone already, in which case nobody waits for the error.
+                let _ = tx.blocking_send(Err(to_datafusion(e)));
+            }
+            Ok(())
+        });
+        Ok(builder.build())
+    }
+
+    fn fetch(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// Read the first rows of a delta table, keeping only the named columns.
+pub(crate) async fn preview_delta_table<H: ObjectStoreFactory>(
+    handler: &H,
+    info: &TableInfo,
+    columns: Option<&[String]>,
+    limit: usize,
+) -> Result<SendableRecordBatchStream> {
+    if info.table_type == TableType::View as i32
+        || info.data_source_format() != DataSourceFormat::Delta
+    {
+        return Err(Error::invalid_argument(
+            "only delta tables can be previewed",
+        ));
+    }
+    let Some(location) = info.storage_location.as_deref() else {
+        return Err(Error::invalid_argument("missing storage location"));
+    };
+    let location = StorageLocationUrl::parse(location)?;
+    let store = handler.create_object_store(location.location()).await?;
+    let provider = DeltaTableProvider::try_new(store, &location).await?;
+    let projection = columns
+        .map(|columns| {
+            columns
+                .iter()
+                .map(|name| {
+                    provider
+                        .arrow_schema
+                        .index_of(name)
+                        .map_err(|_| Error::invalid_argument(format!("unknown column '{name}'")))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
+    let exec = provider.scan_exec(projection.as_ref(), None, Some(limit))?;
+    Ok(exec.execute(0, Arc::new(TaskContext::default()))?)
+}
+
+#[async_trait::async_trait]
+impl TableProvider for DeltaTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.arrow_schema.clone()
+    }
+
+    fn table_type(&self) -> DFTableType {
+        DFTableType::Base
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> DFResult<Vec<TableProviderFilterPushDown>> {
+        // filters are used to skip files, rows still have to be filtered.
+        Ok(filters
+            .iter()
+            .map(
+                |filter| match filter_to_json_predicate(filter, &self.arrow_schema) {
+                    Some(_) => TableProviderFilterPushDown::Inexact,
+                    None => TableProviderFilterPushDown::Unsupported,
+                },
+            )
+            .collect())
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let predicate = filters_to_json_predicate(filters, &self.arrow_schema)
+            .and_then(|predicate| json_predicate_to_expression(&predicate).ok())
+            .map(Arc::new);
+        let exec = self
+            .scan_exec(projection, predicate, limit)
+            .map_err(to_datafusion)?;
+        Ok(Arc::new(exec))
+    }
+}
+
+/// Create a table provider for a table of a catalog mounted from a share.
+async fn shared_table_provider<H: ResourceStore + SecretManager>(
+    handler: &H,
+    info: TableInfo,
+) -> Result<Arc<dyn TableProvider>> {
+    let ident = ResourceIdent::catalog(ResourceName::new([info.catalog_name.as_str()]));
+    let catalog: CatalogInfo = handler.get(&ident).await?.0.try_into()?;
+    let (Some(provider_name), Some(share_name)) = (catalog.provider_name, catalog.share_name)
+    else {
+        return Err(Error::invalid_argument(
+            "shared tables must belong to a catalog created from a share",
+        ));
+    };
+    let schema = arrow_schema(&columns_to_schema(&info.columns)?)?;
+    let mut partition_columns: Vec<_> = info
+        .columns
+        .iter()
+        .filter_map(|column| Some((column.partition_index?, column.name.as_str())))
+        .collect();
+    partition_columns.sort();
+    // partition values may be missing for some files, so the columns are nullable.
+    let partition_fields = partition_columns
+        .into_iter()
+        .map(|(_, name)| {
+            let field = schema
+                .field_with_name(name)
+                .map_err(DataFusionError::from)?;
+            Ok(field.clone().with_nullable(true))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Arc::new(SharedTableProvider {
+        client: provider_client(handler, &provider_name).await?,
+        schema,
+        partition_schema: Arc::new(ArrowSchema::new(partition_fields)),
+        table: SharingTable {
+            name: info.name,
+            schema: info.schema_name,
+            share: share_name,
+            ..Default::default()
+        },
+    }))
+}
+
+/// A table of a share mounted from a provider.
+///
+/// The data files of the latest version are listed by the query API of the sharing server
+/// and read from their pre-signed urls. Files are read with the schema the table was
+/// mounted with.
+#[derive(Clone)]
+struct SharedTableProvider {
+    client: DeltaSharingClient,
+    table: SharingTable,
+    schema: SchemaRef,
+    /// The partition columns of the table, in partition order.
+    partition_schema: SchemaRef,
+}
+
+impl SharedTableProvider {
+    /// Whether the filter only references partition columns, so it can be evaluated
+    /// against the partition values of a file.
+    fn is_partition_filter(&self, filter: &Expr) -> bool {
+        let columns = filter.column_refs();
+        !columns.is_empty()
+            && columns
+                .iter()
+                .all(|column| self.partition_schema.field_with_name(&column.name).is_ok())
+    }
+}
+
+impl std::fmt::Debug for SharedTableProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedTableProvider")
+            .field("table", &self.table)
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl TableProvider for SharedTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> DFTableType {
+        DFTableType::Base
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> DFResult<Vec<TableProviderFilterPushDown>> {
+        // filters are sent to the server as hints and used to skip files,
+        // rows still have to be filtered.
+        Ok(filters
+            .iter()
+            .map(|filter| {
+                if self.is_partition_filter(filter)
+                    || filter_to_json_predicate(filter, &self.schema).is_some()
+                {
+                    TableProviderFilterPushDown::Inexact
+                } else {
+                    TableProviderFilterPushDown::Unsupported
+                }
+            })
+            .collect())
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let predicate = filters_to_json_predicate(filters, &self.schema);
+        let partition_filter = conjunction(
+            filters
+                .iter()
+                .filter(|filter| self.is_partition_filter(filter))
+                .map(|filter| unnormalize_col(filter.clone())),
+        );
+        let partition_filter = match partition_filter {
+            Some(filter) => {
+                let schema = DFSchema::try_from(self.partition_schema.as_ref().clone())?;
+                Some(state.create_physical_expr(filter, &schema)?)
+            }
+            None => None,
+        };
+        let arrow_schema = match projection {
+            Some(projection) => Arc::new(self.schema.project(projection)?),
+            None => self.schema.clone(),
+        };
+        let properties = PlanProperties::new(
+            EquivalenceProperties::new(arrow_schema.clone()),
+            Partitioning::UnknownPartitioning(1),
+            EmissionType::Incremental,
+            Boundedness::Bounded,
+        );
+        Ok(Arc::new(SharedScanExec {
+            table: self.clone(),
+            projection: projection.cloned(),
+            arrow_schema,
+            predicate,
+            partition_filter,
+            limit,
+            properties,
+        }))
+    }
+}
+
+/// Streams the rows of a shared table.
+///
+/// The data files are listed once the plan is executed, with the filters sent as
+/// predicate hints. Files whose partition values do not match the filters are skipped,
+/// the others are read one at a time. Dropping the stream stops the scan.
+#[derive(Debug)]
+struct SharedScanExec {
+    table: SharedTableProvider,
+    projection: Option<Vec<usize>>,
+    arrow_schema: SchemaRef,
+    predicate: Option<JsonPredicate>,
+    partition_filter: Option<Arc<dyn PhysicalExpr>>,
+    limit: Option<usize>,
+    properties: PlanProperties,
+}
+
+impl SharedScanExec {
+    /// Read the table and send its batches, until the limit is reached or the receiver
+    /// is dropped.
+    async fn read(
+        table: &SharedTableProvider,
+        projection: Option<&[usize]>,
+        predicate: Option<&JsonPredicate>,
+        partition_filter: Option<&Arc<dyn PhysicalExpr>>,
+        limit: Option<usize>,
+        tx: &Sender<DFResult<RecordBatch>>,
+    ) -> Result<()> {
+        let snapshot = table
+            .client
+            .query_table(&table.table, limit, predicate)
+            .await?;
+        let files = match partition_filter {
+            Some(filter) => prune_files(snapshot.files, filter, &table.partition_schema)?,
+            None => snapshot.files,
+        };
+
+        let mut remaining = limit;
+        for file in files {
+            let mut batches = table.client.read_file(&file, &table.schema).await?;
+            while let Some(batch) = batches.next().await {
+                if remaining == Some(0) {
+                    return Ok(());
+                }
+                let mut batch = batch?;
+                if let Some(projection) = projection {
+                    batch = batch.project(projection).map_err(DataFusionError::from)?;
+                }
+                if let Some(remaining) = remaining.as_mut() {
+                    batch = batch.slice(0, batch.num_rows().min(*remaining));
+                    *remaining -= batch.num_rows();
+                }
+                if tx.send(Ok(batch)).await.is_err() {
+                    // the stream was dropped, e.g. because the query was cancelled.
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Keep the files whose partition values match the filter.
+///
+/// The filter is evaluated on a batch with one row of partition values per file.
+/// Files for which it evaluates to null are skipped, as none of their rows can match.
+fn prune_files(
+    files: Vec<SharedFile>,
+    filter: &Arc<dyn PhysicalExpr>,
+    schema: &SchemaRef,
+) -> Result<Vec<SharedFile>> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let values: StringArray = files
+                .iter()
+                .map(|file| file.partition_values.get(field.name()).cloned().flatten())
+                .collect();
+            cast(&values, field.data_type())
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(DataFusionError::from)?;
+    let batch = RecordBatch::try_new(schema.clone(), columns).map_err(DataFusionError::from)?;
+    let mask = filter.evaluate(&batch)?.into_array(files.len())?;
+    let mask = mask
+        .as_boolean_opt()
+        .ok_or_else(|| Error::generic("partition filter must evaluate to booleans"))?;
+    Ok(files
+        .into_iter()
+        .zip(mask.iter())
+        .filter_map(|(file, matches)| matches.unwrap_or(false).then_some(file))
+        .collect())
+}
+
+impl DisplayAs for SharedScanExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "SharedScanExec: share={}, table={}.{}",
+            self.table.table.share, self.table.table.schema, self.table.table.name
+        )?;
+        if let Some(filter) = &self.partition_filter {
+            write!(f, ", partition_filter={filter}")?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, ", limit={limit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ExecutionPlan for SharedScanExec {
+    fn name(&self) -> &str {
+        "SharedScanExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        _partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> DFResult<SendableRecordBatchStream> {
+        let mut builder =
+            RecordBatchReceiverStream::builder(self.arrow_schema.clone(), SCAN_BUFFER_SIZE);
+        let tx = builder.tx();
+        let table = self.table.clone();
+        let projection = self.projection.clone();
+        let predicate = self.predicate.clone();
+        let partition_filter = self.partition_filter.clone();
+        let limit = self.limit;
+        builder.spawn(async move {
+            let result = Self::read(
+                &table,
+                projection.as_deref(),
+                predicate.as_ref(),
+                partition_filter.as_ref(),
+                limit,
+                &tx,
+            )
+            .await;
+            if let Err(e) = result {
+                // the receiver may be gone already, in which case nobody waits for the error.
+                let _ = tx.send(Err(to_datafusion(e))).await;
+            }
+            Ok(())
+        });
+        Ok(builder.build())
+    }
+
+    fn fetch(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// A view, planned against the catalog and schema it was defined in.
+#[derive(Debug)]
+struct UnityViewProvider {
+    schema: SchemaRef,
+    definition: String,
+    catalog_name: String,
+    schema_name: String,
+}
+
+#[async_trait::async_trait]
+impl TableProvider for UnityViewProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> DFTableType {
+        DFTableType::View
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        Some(&self.definition)
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let Some(state) = state.as_any().downcast_ref::<SessionState>() else {
+            return Err(DataFusionError::Internal(
+                "views can only be planned in a SessionState".to_string(),
+            ));
+        };
+        // unqualified table names in the view refer to the view's catalog and schema.
+        let config = state
+            .config()
+            .clone()
+            .with_default_catalog_and_schema(&self.catalog_name, &self.schema_name);
+        let view_state = SessionStateBuilder::new_from_existing(state.clone())
+            .with_config(config)
+            .build();
+        let plan = view_state.create_logical_plan(&self.definition).await?;
+        ViewTable::new(plan, Some(self.definition.clone()))
+            .scan(&view_state, projection, filters, limit)
+            .await
+    }
+}
+
+fn arrow_schema(schema: &StructType) -> Result<SchemaRef> {
+    Ok(Arc::new(
+        ArrowSchema::try_from_kernel(schema).map_err(|e| Error::generic(e.to_string()))?,
+    ))
+}
+
+fn to_datafusion(error: Error) -> DataFusionError {
+    DataFusionError::External(Box::new(error))
+}
+
+/// List all resources of a kind in a namespace the recipient can read.
+pub(crate) async fn list_all<H: ResourceStore + Policy>(
+    handler: &H,
+    recipient: &Recipient,
+    label: &ObjectLabel,
+    namespace: Option<&ResourceName>,
+) -> Result<Vec<Resource>> {
+    let mut all = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (mut resources, next_page_token) =
+            handler.list(label, namespace, None, page_token).await?;
+        process_resources(handler, recipient, &Permission::Read, &mut resources).await?;
+        all.extend(resources);
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(all),
+        }
+    }
+}
@@ -0,0 +1,778 @@
+//! Client for remote Delta Sharing servers.
+//!
+//! Catalogs created from a share of a provider mirror the schemas and tables of the share.
+//! The data of their tables is listed by the query API of the sharing server and read
+//! from the pre-signed urls it returns.
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use cloud_client::CloudClient;
+use datafusion::arrow::array::{ArrayRef, RecordBatch, StringArray, new_null_array};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::error::ArrowError;
+use datafusion::parquet::arrow::ParquetRecordBatchStreamBuilder;
+use datafusion::parquet::arrow::arrow_reader::ArrowReaderOptions;
+use datafusion::parquet::arrow::async_reader::AsyncFileReader;
+use datafusion::parquet::errors::{ParquetError, Result as ParquetResult};
+use datafusion::parquet::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use datafusion_common::DataFusionError;
+use delta_kernel::schema::StructType;
+use futures_util::FutureExt;
+use futures_util::future::BoxFuture;
+use futures_util::stream::{BoxStream, StreamExt};
+use reqwest::header::RANGE;
+use reqwest::{Response, StatusCode};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use url::Url;
+
+use crate::models::Profile;
+use crate::models::sharing::v1::{
+    JsonPredicate, ListShareTablesResponse, ListSharesResponse, ListSharingSchemasResponse,
+    SharingSchema, SharingTable,
+};
+use crate::services::secrets::SecretManager;
+use crate::{Error, Result};
+
+/// Highest version of the profile file format we can read.
+const MAX_SHARE_CREDENTIALS_VERSION: i32 = 1;
+
+/// Highest reader version of the sharing protocol we can read.
+const MAX_READER_VERSION: i32 = 1;
+
+/// Bytes fetched from the end of a data file when reading its footer.
+///
+/// Large enough to hold the metadata of most files, so it takes a single request.
+const FOOTER_PREFETCH_SIZE: usize = 64 * 1024;
+
+/// Name of the secret holding the sharing profile of a provider.
+///
+/// Credentials use their bare name as secret name, so provider secrets are prefixed.
+pub(crate) fn profile_secret_name(provider_name: &str) -> String {
+    format!("providers/{provider_name}")
+}
+
+/// Parse and validate the contents of a sharing profile file.
+pub(crate) fn parse_profile(profile: &str) -> Result<Profile> {
+    let profile: Profile = serde_json::from_str(profile)
+        .map_err(|e| Error::invalid_argument(format!("invalid sharing profile: {e}")))?;
+    if profile.share_credentials_version > MAX_SHARE_CREDENTIALS_VERSION {
+        return Err(Error::invalid_argument(format!(
+            "unsupported share credentials version: {}",
+            profile.share_credentials_version
+        )));
+    }
+    if profile.bearer_token.is_empty() {
+        return Err(Error::invalid_argument(
+            "sharing profile is missing a bearer token",
+        ));
+    }
+    Url::parse(&profile.endpoint)
+        .map_err(|e| Error::invalid_argument(format!("invalid sharing endpoint: {e}")))?;
+    check_expiration(&profile)?;
+    Ok(profile)
+}
+
+/// Reject profiles whose bearer token has expired.
+fn check_expiration(profile: &Profile) -> Result<()> {
+    let Some(expiration_time) = &profile.expiration_time else {
+        return Ok(());
+    };
+    let expiration_time = DateTime::parse_from_rfc3339(expiration_time)
+        .map_err(|e| Error::invalid_argument(format!("invalid profile expiration time: {e}")))?;
+    if expiration_time <= Utc::now() {
+        return Err(Error::invalid_argument(format!(
+            "sharing profile expired at {expiration_time}"
+        )));
+    }
+    Ok(())
+}
+
+/// Create a client for the sharing server of a provider.
+pub(crate) async fn provider_client<H: SecretManager>(
+    handler: &H,
+    provider_name: &str,
+) -> Result<DeltaSharingClient> {
+    let (_, profile) = handler
+        .get_secret(&profile_secret_name(provider_name))
+        .await?;
+    let profile: Profile = serde_json::from_slice(&profile)?;
+    // the token may have expired since the profile was stored.
+    check_expiration(&profile)?;
+    DeltaSharingClient::try_new(&profile)
+}
+
+/// Metadata of a shared table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedTableMetadata {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+}
+
+impl SharedTableMetadata {
+    /// The schema of the table.
+    pub fn schema(&self) -> Result<StructType> {
+        Ok(serde_json::from_str(&self.schema_string)?)
+    }
+}
+
+/// A data file of a shared table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedFile {
+    /// Pre-signed url to read the file.
+    pub url: String,
+    pub id: String,
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    pub size: i64,
+}
+
+/// The metadata and data files of a shared table.
+#[derive(Debug, Clone)]
+pub struct SharedTableSnapshot {
+    pub metadata: SharedTableMetadata,
+    pub files: Vec<SharedFile>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Protocol {
+    min_reader_version: i32,
+}
+
+/// A line in the responses of the table metadata and query APIs.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    protocol: Option<Protocol>,
+    meta_data: Option<SharedTableMetadata>,
+    file: Option<SharedFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorResponse {
+    error_code: String,
+    message: String,
+}
+
+/// Client for the REST API of a Delta Sharing server.
+#[derive(Clone)]
+pub struct DeltaSharingClient {
+    client: CloudClient,
+    /// Pre-signed urls must be requested without the bearer token of the profile.
+    files: CloudClient,
+    endpoint: Url,
+}
+
+impl DeltaSharingClient {
+    pub fn try_new(profile: &Profile) -> Result<Self> {
+        let mut endpoint = Url::parse(&profile.endpoint)?;
+        if !endpoint.path().ends_with('/') {
+            endpoint.set_path(&format!("{}/", endpoint.path()));
+        }
+        Ok(Self {
+            client: CloudClient::new_with_token(&profile.bearer_token),
+            files: CloudClient::new_unauthenticated(),
+            endpoint,
+        })
+    }
+
+    fn url(&self, segments: &[&str]) -> Result<Url> {
+        let mut url = self.endpoint.clone();
+        url.path_segments_mut()
+            .map_err(|_| Error::invalid_argument("invalid sharing endpoint"))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn table_url(&self, table: &SharingTable, action: &str) -> Result<Url> {
+        self.url(&[
+            "shares",
+            &table.share,
+            "schemas",
+            &table.schema,
+            "tables",
+            &table.name,
+            action,
+        ])
+    }
+
+    /// List a page of the shares the profile can access.
+    pub async fn list_shares(
+        &self,
+        max_results: Option<i32>,
+        page_token: Option<String>,
+    ) -> Result<ListSharesResponse> {
+        self.get_page(self.url(&["shares"])?, max_results, page_token)
+            .await
+    }
+
+    /// List all schemas in a share.
+    pub async fn list_schemas(&self, share: &str) -> Result<Vec<SharingSchema>> {
+        let url = self.url(&["shares", share, "schemas"])?;
+        self.list_pages(url, |page: ListSharingSchemasResponse| {
+            (page.items, page.next_page_token)
+        })
+        .await
+    }
+
+    /// List all tables in a share.
+    pub async fn list_share_tables(&self, share: &str) -> Result<Vec<SharingTable>> {
+        let url = self.url(&["shares", share, "all-tables"])?;
+        self.list_pages(url, |page: ListShareTablesResponse| {
+            (page.items, page.next_page_token)
+        })
+        .await
+    }
+
+    /// Get the metadata of a table.
+    pub async fn table_metadata(&self, table: &SharingTable) -> Result<SharedTableMetadata> {
+        let response = self
+            .client
+            .get(self.table_url(table, "metadata")?)
+            .send()
+            .await?;
+        let data = check_response(response).await?.bytes().await?;
+        Ok(parse_actions(&data)?.metadata)
+    }
+
+    /// List the data files of the latest version of a table.
+    ///
+    /// The limit and predicate are hints, the server may return files with more rows
+    /// or rows that do not match the predicate.
+    pub async fn query_table(
+        &self,
+        table: &SharingTable,
+        limit: Option<usize>,
+        predicate: Option<&JsonPredicate>,
+    ) -> Result<SharedTableSnapshot> {
+        let mut body = json!({});
+        if let Some(limit) = limit {
+            body["limitHint"] = json!(limit);
+        }
+        if let Some(predicate) = predicate {
+            // the protocol expects the predicate serialized as a string.
+            body["jsonPredicateHints"] = json!(serde_json::to_string(predicate)?);
+        }
+        let response = self
+            .client
+            .post(self.table_url(table, "query")?)
+            .json(&body)
+            .send()
+            .await?;
+        let data = check_response(response).await?.bytes().await?;
+        parse_actions(&data)
+    }
+
+    /// Stream the record batches of a data file of a table.
+    ///
+    /// The file is read with range requests, so only the footer and the row group being
+    /// decoded are held in memory. Partition values are added as columns and the data is
+    /// cast to the table schema. Columns missing in the file are filled with nulls.
+    pub async fn read_file(
+        &self,
+        file: &SharedFile,
+        schema: &SchemaRef,
+    ) -> Result<BoxStream<'static, Result<RecordBatch>>> {
+        let reader = SharedFileReader {
+            client: self.files.clone(),
+            url: file.url.clone(),
+            size: file.size as u64,
+        };
+        let stream = ParquetRecordBatchStreamBuilder::new(reader)
+            .await
+            .and_then(|builder| builder.build())
+            .map_err(DataFusionError::from)?;
+        let schema = schema.clone();
+        let partition_values = file.partition_values.clone();
+        Ok(stream
+            .map(move |batch| {
+                let batch = batch.map_err(DataFusionError::from)?;
+                with_partition_values(&batch, &schema, &partition_values)
+            })
+            .boxed())
+    }
+
+    async fn get_page<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        max_results: Option<i32>,
+        page_token: Option<String>,
+    ) -> Result<T> {
+        let mut query = Vec::new();
+        if let Some(max_results) = max_results {
+            query.push(("maxResults", max_results.to_string()));
+        }
+        if let Some(page_token) = page_token {
+            query.push(("pageToken", page_token));
+        }
+        let response = self.client.get(url).query(&query).send().await?;
+        Ok(check_response(response).await?.json().await?)
+    }
+
+    async fn list_pages<P: DeserializeOwned, T>(
+        &self,
+        url: Url,
+        split: impl Fn(P) -> (Vec<T>, Option<String>),
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page = self.get_page(url.clone(), None, page_token.take()).await?;
+            let (page_items, next_page_token) = split(page);
+            items.extend(page_items);
+            match next_page_token.filter(|token| !token.is_empty()) {
+                Some(token) => page_token = Some(token),
+                None => return Ok(items),
+            }
+        }
+    }
+}
+
+/// Reads a data file from its pre-signed url.
+struct SharedFileReader {
+    client: CloudClient,
+    url: String,
+    size: u64,
+}
+
+impl SharedFileReader {
+    async fn fetch(&self, range: Range<u64>) -> Result<Bytes> {
+        let response = self
+            .client
+            .get(self.url.as_str())
+            .header(
+                RANGE,
+                format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+            )
+            .send()
+            .await?;
+        let partial = response.status() == StatusCode::PARTIAL_CONTENT;
+        let data = check_response(response).await?.bytes().await?;
+        if partial {
+            return Ok(data);
+        }
+        // the server ignored the range and returned the whole file.
+        let end = (range.end as usize).min(data.len());
+        let start = (range.start as usize).min(end);
+        Ok(data.slice(start..end))
+    }
+}
+
+impl AsyncFileReader for SharedFileReader {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, ParquetResult<Bytes>> {
+        async move {
+            self.fetch(range)
+                .await
+                .map_err(|e| ParquetError::External(Box::new(e)))
+        }
+        .boxed()
+    }
+
+    fn get_metadata<'a>(
+        &'a mut self,
+        _options: Option<&'a ArrowReaderOptions>,
+    ) -> BoxFuture<'a, ParquetResult<Arc<ParquetMetaData>>> {
+        async move {
+            let size = self.size;
+            let metadata = ParquetMetaDataReader::new()
+                .with_prefetch_hint(Some(FOOTER_PREFETCH_SIZE))
+                .load_and_finish(self, size)
+                .await?;
+            Ok(Arc::new(metadata))
+        }
+        .boxed()
+    }
+}
+
+/// Turn failed responses of the sharing server into errors.
+///
+/// Errors of the remote server are reported as upstream errors, so they are not
+/// mistaken for errors of the request made to this server.
+async fn check_response(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let error = response.json::<ErrorResponse>().await.unwrap_or_default();
+    Err(Error::upstream(format!(
+        "sharing server request failed with status {status}: {} {}",
+        error.error_code, error.message
+    )))
+}
+
+/// Parse the newline-delimited actions returned by the table metadata and query APIs.
+fn parse_actions(data: &[u8]) -> Result<SharedTableSnapshot> {
+    let mut metadata = None;
+    let mut files = Vec::new();
+    for line in data.split(|b| *b == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let action: Action = serde_json::from_slice(line)?;
+        if let Some(protocol) = action.protocol {
+            if protocol.min_reader_version > MAX_READER_VERSION {
+                return Err(Error::invalid_argument(format!(
+                    "unsupported sharing reader version: {}",
+                    protocol.min_reader_version
+                )));
+            }
+        }
+        if let Some(meta_data) = action.meta_data {
+            metadata = Some(meta_data);
+        }
+        if let Some(file) = action.file {
+            files.push(file);
+        }
+    }
+    let metadata =
+        metadata.ok_or_else(|| Error::generic("sharing server did not return table metadata"))?;
+    Ok(SharedTableSnapshot { metadata, files })
+}
+
+/// Align a batch read from a data file with the table schema.
+fn with_partition_values(
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+    partition_values: &HashMap<String, Option<String>>,
+) -> Result<RecordBatch> {
+    let num_rows = batch.num_rows();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| -> std::result::Result<ArrayRef, ArrowError> {
+            let name = field.name();
+            match (partition_values.get(name), batch.column_by_name(name)) {
+                (Some(value), _) => {
+                    let values: ArrayRef =
+                        Arc::new(StringArray::from(vec![value.as_deref(); num_rows]));
+                    cast(&values, field.data_type())
+                }
+                (None, Some(column)) if column.data_type() == field.data_type() => {
+                    Ok(column.clone())
+                }
+                (None, Some(column)) => cast(column, field.data_type()),
+                (None, None) => Ok(new_null_array(field.data_type(), num_rows)),
+            }
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(DataFusionError::from)?;
+    Ok(RecordBatch::try_new(schema.clone(), columns).map_err(DataFusionError::from)?)
+}
+
+/// A mock sharing server offering the share `delta_share`, with a single table
+/// `default.events` partitioned by `date`.
+#[cfg(all(test, feature = "axum"))]
+pub(crate) mod mock {
+    use std::sync::Arc;
+
+    use axum::extract::State;
+    use axum::http::{HeaderMap, header};
+    use axum::response::{IntoResponse, Response};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use bytes::Bytes;
+    use datafusion::arrow::array::{Int32Array, RecordBatch};
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::parquet::arrow::ArrowWriter;
+    use reqwest::StatusCode;
+    use serde_json::{Value, json};
+    use url::Url;
+
+    pub(crate) const TOKEN: &str = "provider-token";
+
+    const SCHEMA: &str = r#"{"type":"struct","fields":[{"name":"id","type":"long","nullable":true,"metadata":{}},{"name":"date","type":"date","nullable":true,"metadata":{}}]}"#;
+
+    fn authorize(headers: &HeaderMap) -> Result<(), Response> {
+        let expected = format!("Bearer {TOKEN}");
+        match headers.get(header::AUTHORIZATION) {
+            Some(value) if value.as_bytes() == expected.as_bytes() => Ok(()),
+            _ => Err((
+                StatusCode::UNAUTHORIZED,
+                Json(json!({ "errorCode": "UNAUTHENTICATED", "message": "invalid token" })),
+            )
+                .into_response()),
+        }
+    }
+
+    fn page(items: Value) -> Json<Value> {
+        Json(json!({ "items": items }))
+    }
+
+    fn actions(actions: &[Value]) -> String {
+        actions.iter().map(|a| format!("{a}\n")).collect()
+    }
+
+    fn metadata() -> Value {
+        json!({ "metaData": {
+            "id": "f8d5c169-3d01-4ca3-ad9e-7dc3355aedb2",
+            "description": "events shared by the provider",
+            "format": { "provider": "parquet" },
+            "schemaString": SCHEMA,
+            "partitionColumns": ["date"],
+        }})
+    }
+
+    /// A data file holding the non-partition column `id`, written with a narrower type.
+    fn parquet_file() -> Bytes {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])
+                .unwrap();
+        let mut data = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut data, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        data.into()
+    }
+
+    async fn query(
+        State(address): State<String>,
+        headers: HeaderMap,
+        Json(body): Json<Value>,
+    ) -> Result<String, Response> {
+        authorize(&headers)?;
+        // predicate hints are sent as serialized json predicates.
+        if let Some(hints) = body.get("jsonPredicateHints") {
+            let hints = hints
+                .as_str()
+                .and_then(|hints| serde_json::from_str::<Value>(hints).ok());
+            if hints.is_none_or(|hints| hints.get("op").is_none()) {
+                return Err(StatusCode::BAD_REQUEST.into_response());
+            }
+        }
+        let protocol = json!({ "protocol": { "minReaderVersion": 1 } });
+        let file = json!({ "file": {
+            "url": format!("{address}/files/part-0.parquet?signature=abc"),
+            "id": "8b0086f2",
+            "partitionValues": { "date": "2021-04-28" },
+            "size": parquet_file().len(),
+        }});
+        Ok(actions(&[protocol, metadata(), file]))
+    }
+
+    /// Start the mock sharing server and return its endpoint.
+    pub(crate) async fn mock_sharing_server() -> Url {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let tables = "/delta-sharing/shares/delta_share/schemas/default/tables/events";
+        let router = Router::new()
+            .route(
+                "/delta-sharing/shares",
+                get(|headers: HeaderMap| async move {
+                    authorize(&headers)?;
+                    Ok::<_, Response>(page(json!([{ "name": "delta_share" }])))
+                }),
+            )
+            .route(
+                "/delta-sharing/shares/delta_share/schemas",
+                get(|headers: HeaderMap| async move {
+                    authorize(&headers)?;
+                    Ok::<_, Response>(page(json!([{ "name": "default", "share": "delta_share" }])))
+                }),
+            )
+            .route(
+                "/delta-sharing/shares/delta_share/all-tables",
+                get(|headers: HeaderMap| async move {
+                    authorize(&headers)?;
+                    Ok::<_, Response>(page(json!([
+                        { "name": "events", "schema": "default", "share": "delta_share" }
+                    ])))
+                }),
+            )
+            .route(
+                &format!("{tables}/metadata"),
+                get(|headers: HeaderMap| async move {
+                    authorize(&headers)?;
+                    let protocol = json!({ "protocol": { "minReaderVersion": 1 } });
+                    Ok::<_, Response>(actions(&[protocol, metadata()]))
+                }),
+            )
+            .route(&format!("{tables}/query"), post(query))
+            .route(
+                "/files/part-0.parquet",
+                // pre-signed urls must not receive the bearer token of the profile.
+                get(|headers: HeaderMap| async move {
+                    if headers.contains_key(header::AUTHORIZATION) {
+                        return Err(StatusCode::BAD_REQUEST);
+                    }
+                    let data = parquet_file();
+                    let Some(range) = headers
+                        .get(header::RANGE)
+                        .and_then(|range| range.to_str().ok())
+                        .and_then(|range| range.strip_prefix("bytes="))
+                        .and_then(|range| range.split_once('-'))
+                    else {
+                        return Ok((StatusCode::OK, data));
+                    };
+                    let start: usize = range.0.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+                    let end: usize = range.1.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+                    let end = end.min(data.len() - 1);
+                    Ok((StatusCode::PARTIAL_CONTENT, data.slice(start..=end)))
+                }),
+            )
+            .with_state(address.clone());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("{address}/delta-sharing").parse().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profile() {
+        let profile = parse_profile(
+            r#"{"shareCredentialsVersion":1,"endpoint":"https://sharing.example.com/delta-sharing/","bearerToken":"token"}"#,
+        )
+        .unwrap();
+        assert_eq!(profile.bearer_token, "token");
+        assert!(profile.expiration_time.is_none());
+
+        let err = parse_profile(
+            r#"{"shareCredentialsVersion":2,"endpoint":"https://sharing.example.com","bearerToken":"token"}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        let err = parse_profile(
+            r#"{"shareCredentialsVersion":1,"endpoint":"https://sharing.example.com"}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        assert!(parse_profile("not a profile").is_err());
+
+        let profile = parse_profile(
+            r#"{"shareCredentialsVersion":1,"endpoint":"https://sharing.example.com","bearerToken":"token","expirationTime":"2999-01-01T00:00:00.0Z"}"#,
+        )
+        .unwrap();
+        assert!(profile.expiration_time.is_some());
+        let err = parse_profile(
+            r#"{"shareCredentialsVersion":1,"endpoint":"https://sharing.example.com","bearerToken":"token","expirationTime":"2021-11-12T00:12:29.0Z"}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(msg) if msg.contains("expired")));
+        let err = parse_profile(
+            r#"{"shareCredentialsVersion":1,"endpoint":"https://sharing.example.com","bearerToken":"token","expirationTime":"tomorrow"}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+    }
+
+    #[test]
+    fn test_parse_actions() {
+        let response = br#"{"protocol":{"minReaderVersion":1}}
+{"metaData":{"id":"f8d5c169","format":{"provider":"parquet"},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":[]}}
+{"file":{"url":"https://bucket.example.com/part-0.parquet?sig=abc","id":"8b0086f2","partitionValues":{},"size":573,"stats":"{\"numRecords\":1}"}}
+"#;
+        let snapshot = parse_actions(response).unwrap();
+        assert!(snapshot.metadata.partition_columns.is_empty());
+        assert_eq!(snapshot.metadata.schema().unwrap().fields().count(), 1);
+        assert_eq!(snapshot.files.len(), 1);
+        assert_eq!(snapshot.files[0].size, 573);
+
+        let response = br#"{"protocol":{"minReaderVersion":3}}"#;
+        assert!(matches!(
+            parse_actions(response).unwrap_err(),
+            Error::InvalidArgument(_)
+        ));
+        let response = br#"{"protocol":{"minReaderVersion":1}}"#;
+        assert!(parse_actions(response).is_err());
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_sharing_client() {
+        use datafusion::arrow::array::{Date32Array, Int64Array};
+        use datafusion::arrow::datatypes::{DataType, Field, Schema};
+        use futures_util::TryStreamExt;
+
+        use super::mock::{TOKEN, mock_sharing_server};
+
+        let profile = Profile {
+            share_credentials_version: 1,
+            endpoint: mock_sharing_server().await.to_string(),
+            bearer_token: TOKEN.to_string(),
+            expiration_time: None,
+        };
+        let client = DeltaSharingClient::try_new(&profile).unwrap();
+
+        let shares = client.list_shares(None, None).await.unwrap();
+        assert_eq!(shares.items[0].name, "delta_share");
+        let schemas = client.list_schemas("delta_share").await.unwrap();
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].name, "default");
+        let tables = client.list_share_tables("delta_share").await.unwrap();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].name, "events");
+        let metadata = client.table_metadata(&tables[0]).await.unwrap();
+        assert_eq!(metadata.partition_columns, vec!["date"]);
+        // errors of the sharing server are not reported as errors of the request.
+        assert!(matches!(
+            client.list_schemas("missing").await.unwrap_err(),
+            Error::Upstream(_)
+        ));
+
+        // partition values are added and file columns are cast to the table schema.
+        let predicate = JsonPredicate {
+            op: "isNull".to_string(),
+            children: vec![JsonPredicate {
+                op: "column".to_string(),
+                name: Some("date".to_string()),
+                value_type: Some("date".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let snapshot = client
+            .query_table(&tables[0], Some(10), Some(&predicate))
+            .await
+            .unwrap();
+        assert_eq!(snapshot.files.len(), 1);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("date", DataType::Date32, true),
+        ]));
+        let batches: Vec<_> = client
+            .read_file(&snapshot.files[0], &schema)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        let ids = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(ids.values(), &[1, 2]);
+        let dates = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(dates.value_as_date(1).unwrap().to_string(), "2021-04-28");
+
+        let client = DeltaSharingClient::try_new(&Profile {
+            bearer_token: "invalid".to_string(),
+            ..profile
+        })
+        .unwrap();
+        let err = client.list_schemas("delta_share").await.unwrap_err();
+        assert!(matches!(err, Error::Upstream(msg) if msg.contains("UNAUTHENTICATED")));
+    }
+}
@@ -0,0 +1,43 @@
+//! Creation of new (empty) delta tables for managed storage locations.
+use std::sync::Arc;
+
+use delta_kernel::committer::FileSystemCommitter;
+use delta_kernel::engine::default::DefaultEngine;
+use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+use delta_kernel::schema::StructType;
+use delta_kernel::transaction::CommitResult;
+use delta_kernel::transaction::create_table::create_table;
+use object_store::DynObjectStore;
+use url::Url;
+
+use crate::{Error, Result};
+
+const ENGINE_INFO: &str = concat!("unitycatalog-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Create an empty delta table at `table_root`.
+///
+/// The initial commit is written by the kernel, so protocol and metadata match what
+/// the kernel itself would produce. Fails with [`Error::AlreadyExists`] if the location
+/// already contains a delta log.
+///
+/// Committing is blocking, so the transaction runs on a dedicated blocking thread.
+pub(crate) async fn create_delta_table(
+    store: Arc<DynObjectStore>,
+    table_root: Url,
+    schema: StructType,
+    partition_columns: Vec<String>,
+) -> Result<()> {
+    tokio::task::spawn_blocking(move || -> Result<()> {
+        let engine = DefaultEngine::new(store, Arc::new(TokioBackgroundExecutor::new()));
+        let result = create_table(table_root.as_str(), Arc::new(schema), ENGINE_INFO)
+            .with_partition_columns(partition_columns)
+            .build(&engine, Box::new(FileSystemCommitter::new()))?
+            .commit(&engine)?;
+        match result {
+            CommitResult::ConflictedTransaction(_) => Err(Error::AlreadyExists),
+            _ => Ok(()),
+        }
+    })
+    .await
+    .map_err(|e| Error::generic(e.to_string()))?
+}
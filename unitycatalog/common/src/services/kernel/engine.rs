@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
-use itertools::Itertools;
+use dashmap::DashMap;
+use delta_kernel::object_store::ObjectStoreScheme;
 use object_store::DynObjectStore;
 use object_store::azure::MicrosoftAzureBuilder;
+use object_store::local::LocalFileSystem;
+use uuid::Uuid;
 
 use crate::api::CredentialsHandler;
 use crate::models::credentials::v1::credential_info::Credential;
@@ -10,41 +13,80 @@ use crate::models::credentials::v1::{
     AzureServicePrincipal, AzureStorageKey, GetCredentialRequest,
 };
 use crate::models::external_locations::v1::ExternalLocationInfo;
-use crate::resources::ResourceStore;
-use crate::services::location::{StorageLocationScheme, StorageLocationUrl};
+use crate::resources::{ResourceRef, ResourceStore};
+use crate::services::location::{
+    LocalStorageConfig, ProvidesLocalStorage, StorageLocationScheme, StorageLocationUrl,
+};
 use crate::{Error, Result};
 
-pub(crate) trait RegistryHandler: ResourceStore + CredentialsHandler {}
-impl<T: ResourceStore + CredentialsHandler> RegistryHandler for T {}
+pub(crate) trait RegistryHandler:
+    ResourceStore + CredentialsHandler + ProvidesLocalStorage + ProvidesObjectStoreCache
+{
+}
+impl<T> RegistryHandler for T where
+    T: ResourceStore + CredentialsHandler + ProvidesLocalStorage + ProvidesObjectStoreCache
+{
+}
+
+/// Cache key for object stores.
+///
+/// Stores are built per external location, store root and credential.
+/// Updating a location or rotating its credential evicts the affected stores.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ObjectStoreKey {
+    location_id: Uuid,
+    store_url: String,
+    credential_name: String,
+}
+
+/// Cache of object stores built for external locations.
+#[derive(Clone, Default)]
+pub struct ObjectStoreCache {
+    stores: Arc<DashMap<ObjectStoreKey, Arc<DynObjectStore>>>,
+}
+
+impl ObjectStoreCache {
+    fn insert(&self, key: ObjectStoreKey, store: Arc<DynObjectStore>) {
+        self.stores.insert(key, store);
+    }
+
+    /// Drop all stores built with the given credential.
+    pub fn invalidate_credential(&self, credential_name: &str) {
+        self.stores
+            .retain(|key, _| key.credential_name != credential_name);
+    }
+
+    /// Drop all stores built for the given external location.
+    pub fn invalidate_location(&self, location_id: &Uuid) {
+        self.stores.retain(|key, _| &key.location_id != location_id);
+    }
+}
+
+/// Auxiliary trait for structs that hold an [`ObjectStoreCache`].
+pub trait ProvidesObjectStoreCache: Send + Sync + 'static {
+    fn object_store_cache(&self) -> &ObjectStoreCache;
+}
 
 pub(crate) async fn get_object_store(
     location: &StorageLocationUrl,
     handler: &dyn RegistryHandler,
 ) -> Result<Arc<DynObjectStore>> {
     tracing::debug!("get_object_store: {:?}", location.location());
-    // TODO(roeap): just listing all external locations could be very inefficient.
-    // introduce an endpoint that allows us to query for specific resource properties instead
-    let (locations, _) = handler
-        .list(&crate::ObjectLabel::ExternalLocationInfo, None, None, None)
-        .await?;
-    let locations: Vec<ExternalLocationInfo> =
-        locations.into_iter().map(|l| l.try_into()).try_collect()?;
-    // find the longest matching location
-    let ext_loc = locations
-        .iter()
-        .filter(|l| {
-            let ext_loc_url = StorageLocationUrl::parse(&l.url).unwrap();
-            location
-                .raw()
-                .as_str()
-                .starts_with(ext_loc_url.raw().as_str())
-                || location
-                    .location()
-                    .as_str()
-                    .starts_with(ext_loc_url.location().as_str())
-        })
-        .max_by(|l, r| l.url.len().cmp(&r.url.len()))
-        .ok_or_else(|| Error::NotFound)?;
+    let (ext_loc, ext_loc_ref) = handler.find_external_location(location).await?;
+    let ext_loc: ExternalLocationInfo = ext_loc.try_into()?;
+    let ResourceRef::Uuid(location_id) = ext_loc_ref else {
+        return Err(Error::generic("external location must be referenced by id"));
+    };
+    let key = ObjectStoreKey {
+        location_id,
+        store_url: location.store_url().as_str().to_string(),
+        credential_name: ext_loc.credential_name.clone(),
+    };
+    let cache = handler.object_store_cache();
+    if let Some(store) = cache.stores.get(&key) {
+        return Ok(store.value().clone());
+    }
+
     let credential = handler
         .get_credential_internal(GetCredentialRequest {
             name: ext_loc.credential_name.clone(),
@@ -53,10 +95,44 @@ pub(crate) async fn get_object_store(
     let Some(cred) = credential.credential else {
         return Err(Error::NotFound);
     };
+    let store = build_object_store(location, cred, handler.local_storage())?;
+    cache.insert(key, store.clone());
+    Ok(store)
+}
+
+/// Create an object store for a storage location using the given credential.
+pub(crate) fn build_object_store(
+    location: &StorageLocationUrl,
+    cred: Credential,
+    local: &LocalStorageConfig,
+) -> Result<Arc<DynObjectStore>> {
     match cred {
         Credential::AzureStorageKey(_)
         | Credential::AzureServicePrincipal(_)
         | Credential::AzureManagedIdentity(_) => get_azure_store(location, cred),
+        Credential::NoCredential(_) => get_local_store(location, local),
+    }
+}
+
+fn get_local_store(
+    location: &StorageLocationUrl,
+    local: &LocalStorageConfig,
+) -> Result<Arc<DynObjectStore>> {
+    tracing::debug!("get_local_store: {:?}", location.location());
+    match location.scheme() {
+        StorageLocationScheme::ObjectStore(ObjectStoreScheme::Local) => {
+            if !local.is_allowed(location.location()) {
+                return Err(Error::invalid_argument(format!(
+                    "Local path '{}' is not contained in an allowed base directory.",
+                    location.location().path()
+                )));
+            }
+            Ok(Arc::new(LocalFileSystem::new()))
+        }
+        StorageLocationScheme::ObjectStore(ObjectStoreScheme::Memory) => Ok(local.memory_store()),
+        _ => Err(Error::invalid_argument(
+            "Storage locations without credentials must use the 'file' or 'memory' scheme.",
+        )),
     }
 }
 
@@ -110,3 +186,31 @@ fn get_azure_store(
     }
     Ok(Arc::new(builder.build()?))
 }
+
+#[cfg(test)]
+mod tests {
+    use object_store::memory::InMemory;
+
+    use super::*;
+
+    #[test]
+    fn object_store_cache_invalidation() {
+        let cache = ObjectStoreCache::default();
+        let key = |location: u128, credential: &str| ObjectStoreKey {
+            location_id: Uuid::from_u128(location),
+            store_url: "memory:///".to_string(),
+            credential_name: credential.to_string(),
+        };
+        cache.insert(key(1, "first"), Arc::new(InMemory::new()));
+        cache.insert(key(2, "first"), Arc::new(InMemory::new()));
+        cache.insert(key(3, "second"), Arc::new(InMemory::new()));
+
+        cache.invalidate_location(&Uuid::from_u128(1));
+        assert!(!cache.stores.contains_key(&key(1, "first")));
+        assert!(cache.stores.contains_key(&key(2, "first")));
+
+        cache.invalidate_credential("first");
+        assert!(!cache.stores.contains_key(&key(2, "first")));
+        assert!(cache.stores.contains_key(&key(3, "second")));
+    }
+}
@@ -0,0 +1,162 @@
+//! Commit history of delta tables.
+//!
+//! The kernel does not expose the commit info actions of the delta log, so the
+//! commit files of the requested versions are read from the log directly.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use delta_kernel::Version;
+use futures_util::{StreamExt, TryStreamExt, stream};
+use object_store::DynObjectStore;
+use object_store::path::Path;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::models::tables::v1::TableCommit;
+use crate::services::location::StorageLocationUrl;
+use crate::{Error, Result};
+
+/// Maximum number of commit files read concurrently.
+const MAX_CONCURRENT_READS: usize = 8;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitInfo {
+    timestamp: Option<i64>,
+    in_commit_timestamp: Option<i64>,
+    operation: Option<String>,
+    #[serde(default)]
+    operation_parameters: HashMap<String, Value>,
+    #[serde(default)]
+    operation_metrics: HashMap<String, Value>,
+    engine_info: Option<String>,
+    user_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    commit_info: Option<CommitInfo>,
+}
+
+/// Read the commits that created the given versions of a delta table.
+///
+/// Commits are returned in the order of `versions`. Versions whose commit file
+/// no longer exists, e.g. after log cleanup, are skipped.
+pub(crate) async fn read_commits(
+    store: Arc<DynObjectStore>,
+    location: &StorageLocationUrl,
+    versions: &[Version],
+) -> Result<Vec<TableCommit>> {
+    let log_dir = Path::from_url_path(location.location().path())
+        .map_err(|e| Error::invalid_argument(e.to_string()))?
+        .child("_delta_log");
+    let commits: Vec<_> = stream::iter(versions.iter().copied())
+        .map(|version| read_commit(store.as_ref(), &log_dir, version))
+        .buffered(MAX_CONCURRENT_READS)
+        .try_collect()
+        .await?;
+    Ok(commits.into_iter().flatten().collect())
+}
+
+async fn read_commit(
+    store: &DynObjectStore,
+    log_dir: &Path,
+    version: Version,
+) -> Result<Option<TableCommit>> {
+    let path = log_dir.child(format!("{version:020}.json"));
+    let result = match store.get(&path).await {
+        Ok(result) => result,
+        Err(object_store::Error::NotFound { .. }) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let last_modified = result.meta.last_modified.timestamp_millis();
+    let info = parse_commit_info(&result.bytes().await?)?.unwrap_or_default();
+    Ok(Some(TableCommit {
+        version: version as i64,
+        // in-commit timestamps take precedence, as they are guaranteed to be monotonic.
+        timestamp: info
+            .in_commit_timestamp
+            .or(info.timestamp)
+            .unwrap_or(last_modified),
+        operation: info.operation,
+        operation_parameters: to_strings(info.operation_parameters),
+        operation_metrics: to_strings(info.operation_metrics),
+        engine_info: info.engine_info,
+        user_name: info.user_name,
+    }))
+}
+
+/// Find the commit info action in a commit file.
+fn parse_commit_info(data: &[u8]) -> Result<Option<CommitInfo>> {
+    for line in data.split(|b| *b == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let action: Action = serde_json::from_slice(line)?;
+        if let Some(info) = action.commit_info {
+            return Ok(Some(info));
+        }
+    }
+    Ok(None)
+}
+
+/// Engines write parameters and metrics as strings or plain JSON values.
+fn to_strings(values: HashMap<String, Value>) -> HashMap<String, String> {
+    values
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(value) => (key, value),
+            value => (key, value.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use object_store::PutPayload;
+    use object_store::memory::InMemory;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_commit_info() {
+        let commit = br#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"commitInfo":{"timestamp":1700000000000,"operation":"WRITE","operationParameters":{"mode":"Append","partitionBy":"[]"},"operationMetrics":{"numFiles":"2"},"engineInfo":"Apache-Spark/3.5.0 Delta-Lake/3.1.0"}}
+{"add":{"path":"part-0.parquet","size":100}}
+"#;
+        let info = parse_commit_info(commit).unwrap().unwrap();
+        assert_eq!(info.timestamp, Some(1700000000000));
+        assert_eq!(info.operation.as_deref(), Some("WRITE"));
+        assert_eq!(info.operation_parameters["mode"], "Append");
+        assert_eq!(
+            info.engine_info.as_deref(),
+            Some("Apache-Spark/3.5.0 Delta-Lake/3.1.0")
+        );
+
+        let commit = br#"{"add":{"path":"part-0.parquet","size":100}}"#;
+        assert!(parse_commit_info(commit).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_commits() {
+        let store = Arc::new(InMemory::new());
+        let location = StorageLocationUrl::parse("memory:///table").unwrap();
+        let commit = r#"{"commitInfo":{"timestamp":1,"operation":"OPTIMIZE","operationMetrics":{"numRemovedFiles":4}}}"#;
+        store
+            .put(
+                &Path::from("table/_delta_log/00000000000000000001.json"),
+                PutPayload::from(commit),
+            )
+            .await
+            .unwrap();
+
+        // version 0 was removed by log cleanup.
+        let commits = read_commits(store, &location, &[1, 0]).await.unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].version, 1);
+        assert_eq!(commits[0].timestamp, 1);
+        assert_eq!(commits[0].operation.as_deref(), Some("OPTIMIZE"));
+        assert_eq!(commits[0].operation_metrics["numRemovedFiles"], "4");
+    }
+}
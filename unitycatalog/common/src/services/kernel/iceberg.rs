@@ -0,0 +1,496 @@
+//! Reading the metadata of iceberg tables.
+//!
+//! Only the table metadata file is read, which holds the schema, partition specs
+//! and the id of the current snapshot. Manifests are never touched.
+use std::collections::HashMap;
+
+use delta_kernel::schema::{ArrayType, DataType, MapType, StructField, StructType};
+use futures_util::TryStreamExt;
+use object_store::DynObjectStore;
+use object_store::path::Path;
+use serde::Deserialize;
+
+use crate::models::tables::v1::{IcebergPartitionField, IcebergTableMetadata};
+use crate::services::location::StorageLocationUrl;
+use crate::{Error, Result};
+
+const METADATA_DIR: &str = "metadata";
+const VERSION_HINT: &str = "version-hint.text";
+const METADATA_SUFFIX: &str = ".metadata.json";
+
+/// The current state of an iceberg table.
+#[derive(Debug, Clone)]
+pub struct IcebergTable {
+    /// Location of the metadata file the table was read from.
+    pub metadata_location: String,
+    metadata: TableMetadata,
+    raw: serde_json::Value,
+}
+
+impl IcebergTable {
+    /// The current schema of the table.
+    pub fn schema(&self) -> Result<StructType> {
+        let schema = self.metadata.current_schema()?;
+        let fields: Vec<StructField> = schema
+            .fields
+            .iter()
+            .map(NestedField::to_struct_field)
+            .collect::<Result<_>>()?;
+        Ok(StructType::new(fields))
+    }
+
+    /// Names of the columns the table is partitioned by with the identity transform.
+    pub fn partition_columns(&self) -> Result<Vec<String>> {
+        Ok(self
+            .partition_fields()?
+            .into_iter()
+            .filter(|f| f.transform == "identity")
+            .map(|f| f.source_column)
+            .collect())
+    }
+
+    /// The metadata file as stored, as returned by iceberg REST catalogs.
+    pub fn metadata_json(&self) -> &serde_json::Value {
+        &self.raw
+    }
+
+    /// Id of the current snapshot, if the table has any snapshots.
+    pub fn current_snapshot_id(&self) -> Option<i64> {
+        // format version 1 uses -1 for tables without snapshots.
+        self.metadata.current_snapshot_id.filter(|id| *id >= 0)
+    }
+
+    /// Summary of the table metadata as exposed in table details.
+    pub fn table_metadata(&self) -> Result<IcebergTableMetadata> {
+        Ok(IcebergTableMetadata {
+            metadata_location: self.metadata_location.clone(),
+            format_version: self.metadata.format_version,
+            table_uuid: self.metadata.table_uuid.clone(),
+            current_snapshot_id: self.current_snapshot_id(),
+            current_schema_id: self.metadata.current_schema()?.schema_id,
+            default_spec_id: self.metadata.default_spec_id,
+            partition_fields: self.partition_fields()?,
+            properties: self.metadata.properties.clone(),
+        })
+    }
+
+    fn partition_fields(&self) -> Result<Vec<IcebergPartitionField>> {
+        let schema = self.metadata.current_schema()?;
+        self.metadata
+            .default_spec()
+            .iter()
+            .map(|field| {
+                let source_column =
+                    column_path(&schema.fields, field.source_id).ok_or_else(|| {
+                        Error::invalid_argument(format!(
+                            "partition field '{}' references unknown column id {}",
+                            field.name, field.source_id
+                        ))
+                    })?;
+                Ok(IcebergPartitionField {
+                    name: field.name.clone(),
+                    source_column,
+                    transform: field.transform.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Find the dot separated path of the column with the given id.
+///
+/// Partitions may be defined on fields nested in structs, so nested fields are searched as well.
+fn column_path(fields: &[NestedField], id: i32) -> Option<String> {
+    fields.iter().find_map(|field| {
+        if field.id == id {
+            return Some(field.name.clone());
+        }
+        match &field.field_type {
+            IcebergType::Nested(NestedType::Struct { fields }) => {
+                column_path(fields, id).map(|path| format!("{}.{}", field.name, path))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Load the current metadata of the iceberg table at `location`.
+///
+/// The current metadata file is taken from `metadata/version-hint.text` if present,
+/// otherwise the metadata file with the highest version is used.
+pub(crate) async fn load_table(
+    store: &DynObjectStore,
+    location: &StorageLocationUrl,
+) -> Result<IcebergTable> {
+    let metadata_dir = Path::from_url_path(location.location().path())
+        .map_err(|e| Error::invalid_argument(e.to_string()))?
+        .child(METADATA_DIR);
+    let path = match read_version_hint(store, &metadata_dir).await? {
+        Some(path) => path,
+        None => latest_metadata_file(store, &metadata_dir).await?,
+    };
+    let bytes = store.get(&path).await?.bytes().await?;
+    let raw: serde_json::Value = serde_json::from_slice(&bytes)?;
+    let metadata = TableMetadata::deserialize(&raw)?;
+
+    let mut metadata_location = location.location().clone();
+    metadata_location.set_path(&format!("/{}", path));
+    Ok(IcebergTable {
+        metadata_location: metadata_location.to_string(),
+        metadata,
+        raw,
+    })
+}
+
+async fn read_version_hint(store: &DynObjectStore, metadata_dir: &Path) -> Result<Option<Path>> {
+    let hint = match store.get(&metadata_dir.child(VERSION_HINT)).await {
+        Ok(result) => result.bytes().await?,
+        Err(object_store::Error::NotFound { .. }) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let version = String::from_utf8_lossy(&hint).trim().to_string();
+    if version.is_empty() {
+        return Ok(None);
+    }
+    // hints written by hadoop catalogs contain the version number only.
+    let file_name = if version.ends_with(METADATA_SUFFIX) {
+        version
+    } else {
+        format!("v{}{}", version, METADATA_SUFFIX)
+    };
+    Ok(Some(metadata_dir.child(file_name)))
+}
+
+/// Find the metadata file with the highest version.
+///
+/// The metadata directory also holds manifests and manifest lists, so only
+/// `*.metadata.json` files are considered, without buffering the listing.
+async fn latest_metadata_file(store: &DynObjectStore, metadata_dir: &Path) -> Result<Path> {
+    let latest = store
+        .list(Some(metadata_dir))
+        .try_filter_map(|meta| async move {
+            let version = meta.location.filename().and_then(metadata_version);
+            Ok(version.map(|version| (version, meta.last_modified, meta.location)))
+        })
+        .try_fold(None, |latest: Option<(u64, _, Path)>, file| async move {
+            Ok(match latest {
+                Some(latest) if (latest.0, latest.1) >= (file.0, file.1) => Some(latest),
+                _ => Some(file),
+            })
+        })
+        .await?;
+    latest.map(|(_, _, location)| location).ok_or_else(|| {
+        Error::invalid_argument(format!("no iceberg metadata found at '{}'", metadata_dir))
+    })
+}
+
+/// Parse the version of a metadata file name.
+///
+/// Metadata files are either named `v<version>.metadata.json` or
+/// `<version>-<uuid>.metadata.json`, optionally with a compression codec before the suffix.
+fn metadata_version(file_name: &str) -> Option<u64> {
+    let stem = file_name.strip_suffix(METADATA_SUFFIX)?;
+    let stem = stem.strip_prefix('v').unwrap_or(stem);
+    stem.split(['-', '.']).next()?.parse().ok()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct TableMetadata {
+    format_version: i32,
+    #[serde(default)]
+    table_uuid: String,
+    #[serde(default)]
+    current_schema_id: Option<i32>,
+    #[serde(default)]
+    schemas: Vec<Schema>,
+    /// Schema of format version 1 tables written without a schema list.
+    #[serde(default)]
+    schema: Option<Schema>,
+    #[serde(default)]
+    default_spec_id: i32,
+    #[serde(default)]
+    partition_specs: Vec<PartitionSpec>,
+    /// Partition spec of format version 1 tables written without a spec list.
+    #[serde(default)]
+    partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    properties: HashMap<String, String>,
+}
+
+impl TableMetadata {
+    fn current_schema(&self) -> Result<&Schema> {
+        let schema = match self.current_schema_id {
+            Some(id) => self.schemas.iter().find(|s| s.schema_id == id),
+            None => self.schema.as_ref().or_else(|| self.schemas.last()),
+        };
+        schema.ok_or_else(|| Error::invalid_argument("iceberg metadata has no current schema"))
+    }
+
+    fn default_spec(&self) -> &[PartitionField] {
+        self.partition_specs
+            .iter()
+            .find(|s| s.spec_id == self.default_spec_id)
+            .map(|s| s.fields.as_slice())
+            .or(self.partition_spec.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Schema {
+    #[serde(default)]
+    schema_id: i32,
+    fields: Vec<NestedField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PartitionSpec {
+    spec_id: i32,
+    fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PartitionField {
+    name: String,
+    source_id: i32,
+    transform: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct NestedField {
+    id: i32,
+    name: String,
+    required: bool,
+    #[serde(rename = "type")]
+    field_type: IcebergType,
+}
+
+impl NestedField {
+    fn to_struct_field(&self) -> Result<StructField> {
+        Ok(StructField::new(
+            self.name.clone(),
+            self.field_type.to_data_type()?,
+            !self.required,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum IcebergType {
+    Primitive(String),
+    Nested(NestedType),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum NestedType {
+    Struct {
+        fields: Vec<NestedField>,
+    },
+    List {
+        element: Box<IcebergType>,
+        #[serde(rename = "element-required")]
+        element_required: bool,
+    },
+    Map {
+        key: Box<IcebergType>,
+        value: Box<IcebergType>,
+        #[serde(rename = "value-required")]
+        value_required: bool,
+    },
+}
+
+impl IcebergType {
+    fn to_data_type(&self) -> Result<DataType> {
+        match self {
+            IcebergType::Primitive(name) => primitive_type(name),
+            IcebergType::Nested(NestedType::Struct { fields }) => {
+                let fields: Vec<StructField> = fields
+                    .iter()
+                    .map(NestedField::to_struct_field)
+                    .collect::<Result<_>>()?;
+                Ok(StructType::new(fields).into())
+            }
+            IcebergType::Nested(NestedType::List {
+                element,
+                element_required,
+            }) => Ok(ArrayType::new(element.to_data_type()?, !element_required).into()),
+            IcebergType::Nested(NestedType::Map {
+                key,
+                value,
+                value_required,
+            }) => Ok(
+                MapType::new(key.to_data_type()?, value.to_data_type()?, !value_required).into(),
+            ),
+        }
+    }
+}
+
+fn primitive_type(name: &str) -> Result<DataType> {
+    let data_type = match name {
+        "boolean" => DataType::BOOLEAN,
+        "int" => DataType::INTEGER,
+        "long" => DataType::LONG,
+        "float" => DataType::FLOAT,
+        "double" => DataType::DOUBLE,
+        "date" => DataType::DATE,
+        "timestamp" => DataType::TIMESTAMP_NTZ,
+        "timestamptz" => DataType::TIMESTAMP,
+        "timestamp_ns" | "timestamptz_ns" => {
+            return Err(Error::invalid_argument(format!(
+                "unsupported iceberg type: {}, delta tables only store microsecond timestamps",
+                name
+            )));
+        }
+        "time" => {
+            return Err(Error::invalid_argument(
+                "unsupported iceberg type: time, delta tables have no time of day type",
+            ));
+        }
+        "string" | "uuid" => DataType::STRING,
+        "binary" => DataType::BINARY,
+        name if name.starts_with("fixed[") => DataType::BINARY,
+        name if name.starts_with("decimal(") => {
+            let args = name
+                .strip_prefix("decimal(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|args| args.split_once(','));
+            let Some((precision, scale)) =
+                args.and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)))
+            else {
+                return Err(Error::invalid_argument(format!(
+                    "invalid iceberg decimal type: {}",
+                    name
+                )));
+            };
+            DataType::decimal(precision, scale)?
+        }
+        other => {
+            return Err(Error::invalid_argument(format!(
+                "unsupported iceberg type: {}",
+                other
+            )));
+        }
+    };
+    Ok(data_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_version() {
+        assert_eq!(metadata_version("v3.metadata.json"), Some(3));
+        assert_eq!(
+            metadata_version("00012-6b3a9c1e-0000-4d8e-b8a2-1c6f0e2d7a10.metadata.json"),
+            Some(12)
+        );
+        assert_eq!(metadata_version("v2.gz.metadata.json"), Some(2));
+        assert_eq!(metadata_version("snap-1.avro"), None);
+        assert_eq!(metadata_version("version-hint.text"), None);
+    }
+
+    #[test]
+    fn test_table_metadata() {
+        let metadata = r#"{
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "s3://bucket/events",
+            "current-schema-id": 1,
+            "schemas": [
+                {"type": "struct", "schema-id": 0, "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"}
+                ]},
+                {"type": "struct", "schema-id": 1, "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"},
+                    {"id": 2, "name": "ts", "required": false, "type": "timestamptz"},
+                    {"id": 3, "name": "amount", "required": false, "type": "decimal(10, 2)"},
+                    {"id": 4, "name": "tags", "required": false, "type": {
+                        "type": "list", "element-id": 5, "element": "string", "element-required": true
+                    }},
+                    {"id": 6, "name": "region", "required": false, "type": "string"},
+                    {"id": 7, "name": "device", "required": false, "type": {
+                        "type": "struct", "fields": [
+                            {"id": 8, "name": "os", "required": false, "type": "string"}
+                        ]
+                    }}
+                ]}
+            ],
+            "default-spec-id": 1,
+            "partition-specs": [
+                {"spec-id": 0, "fields": []},
+                {"spec-id": 1, "fields": [
+                    {"name": "region", "transform": "identity", "source-id": 6, "field-id": 1000},
+                    {"name": "ts_day", "transform": "day", "source-id": 2, "field-id": 1001},
+                    {"name": "device_os", "transform": "identity", "source-id": 8, "field-id": 1002}
+                ]}
+            ],
+            "current-snapshot-id": 3051729675574597004,
+            "properties": {"owner": "analytics"}
+        }"#;
+        let raw: serde_json::Value = serde_json::from_str(metadata).unwrap();
+        let table = IcebergTable {
+            metadata_location: "s3://bucket/events/metadata/v3.metadata.json".to_string(),
+            metadata: TableMetadata::deserialize(&raw).unwrap(),
+            raw,
+        };
+
+        let schema = table.schema().unwrap();
+        let fields: Vec<_> = schema
+            .fields()
+            .map(|f| (f.name().as_str(), f.data_type().clone(), f.is_nullable()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("id", DataType::LONG, false),
+                ("ts", DataType::TIMESTAMP, true),
+                ("amount", DataType::decimal(10, 2).unwrap(), true),
+                ("tags", ArrayType::new(DataType::STRING, false).into(), true),
+                ("region", DataType::STRING, true),
+                (
+                    "device",
+                    StructType::new([StructField::nullable("os", DataType::STRING)]).into(),
+                    true
+                ),
+            ]
+        );
+        assert_eq!(
+            table.partition_columns().unwrap(),
+            vec!["region", "device.os"]
+        );
+
+        let summary = table.table_metadata().unwrap();
+        assert_eq!(summary.current_snapshot_id, Some(3051729675574597004));
+        assert_eq!(summary.current_schema_id, 1);
+        let transforms: Vec<_> = summary
+            .partition_fields
+            .iter()
+            .map(|f| (f.source_column.as_str(), f.transform.as_str()))
+            .collect();
+        assert_eq!(
+            transforms,
+            vec![
+                ("region", "identity"),
+                ("ts", "day"),
+                ("device.os", "identity")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_types() {
+        for name in ["time", "timestamp_ns", "timestamptz_ns", "variant"] {
+            let err = primitive_type(name).unwrap_err();
+            assert!(err.to_string().contains(name), "{err}");
+        }
+    }
+}
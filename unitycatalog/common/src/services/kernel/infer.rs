@@ -0,0 +1,118 @@
+//! Schema inference for external tables stored as plain data files.
+use std::sync::Arc;
+
+use datafusion::datasource::file_format::FileFormat;
+use datafusion::datasource::file_format::csv::CsvFormat;
+use datafusion::datasource::file_format::json::JsonFormat;
+use datafusion::datasource::file_format::parquet::ParquetFormat;
+use datafusion::datasource::listing::{ListingOptions, ListingTableUrl};
+use datafusion::prelude::SessionContext;
+use delta_kernel::engine::arrow_conversion::TryFromArrow as _;
+use delta_kernel::schema::StructType;
+use object_store::DynObjectStore;
+
+use crate::services::location::StorageLocationUrl;
+use crate::tables::v1::DataSourceFormat;
+use crate::{Error, Result};
+
+/// Infer the schema of the data files below `location`.
+///
+/// The location is treated as a directory and only files with the default extension
+/// of the format are considered, so marker files like `_SUCCESS` are skipped.
+///
+/// The store carries the credentials of the location, so it is only registered with a
+/// session created for this call and never shared with other requests.
+pub(crate) async fn infer_schema(
+    store: Arc<DynObjectStore>,
+    location: &StorageLocationUrl,
+    format: &DataSourceFormat,
+) -> Result<StructType> {
+    let file_format: Arc<dyn FileFormat> = match format {
+        DataSourceFormat::Parquet => Arc::new(ParquetFormat::default()),
+        DataSourceFormat::Csv => Arc::new(CsvFormat::default().with_has_header(true)),
+        DataSourceFormat::Json => Arc::new(JsonFormat::default()),
+        _ => {
+            return Err(Error::invalid_argument(format!(
+                "cannot infer schema for data source format: {:?}",
+                format
+            )));
+        }
+    };
+
+    let table_url = ListingTableUrl::parse(location.table_root().as_str())?;
+    let ctx = SessionContext::new();
+    ctx.register_object_store(table_url.object_store().as_ref(), store);
+
+    let options =
+        ListingOptions::new(file_format.clone()).with_file_extension(file_format.get_ext());
+    let schema = options.infer_schema(&ctx.state(), &table_url).await?;
+    if schema.fields().is_empty() {
+        return Err(Error::invalid_argument(format!(
+            "no {:?} files found at '{}'",
+            format,
+            location.location()
+        )));
+    }
+    // not all arrow types have a delta equivalent.
+    StructType::try_from_arrow(schema.as_ref()).map_err(|e| Error::invalid_argument(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{Int64Array, RecordBatch, StringArray};
+    use datafusion::arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+    use datafusion::parquet::arrow::ArrowWriter;
+    use delta_kernel::schema::DataType;
+    use object_store::ObjectStore as _;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_infer_parquet_schema() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", ArrowDataType::Int64, false),
+            Field::new("name", ArrowDataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(vec![1])),
+                Arc::new(StringArray::from(vec![Some("a")])),
+            ],
+        )
+        .unwrap();
+        let mut data = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut data, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let store = Arc::new(InMemory::new());
+        store
+            .put(&Path::from("landing/part-0.parquet"), data.into())
+            .await
+            .unwrap();
+        store
+            .put(&Path::from("landing/_SUCCESS"), Vec::new().into())
+            .await
+            .unwrap();
+
+        let location = StorageLocationUrl::parse("memory:///landing").unwrap();
+        let schema = infer_schema(store.clone(), &location, &DataSourceFormat::Parquet)
+            .await
+            .unwrap();
+        let fields: Vec<_> = schema
+            .fields()
+            .map(|f| (f.name().as_str(), f.data_type().clone()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![("id", DataType::LONG), ("name", DataType::STRING)]
+        );
+
+        let location = StorageLocationUrl::parse("memory:///missing").unwrap();
+        let result = infer_schema(store, &location, &DataSourceFormat::Parquet).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+}
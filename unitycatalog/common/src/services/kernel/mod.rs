@@ -1,17 +1,34 @@
 use std::sync::Arc;
 
 use delta_kernel::Version;
+use delta_kernel::schema::StructType;
 use delta_kernel_datafusion::TableSnapshot;
 
 use crate::Result;
 use crate::services::location::StorageLocationUrl;
-use crate::tables::v1::DataSourceFormat;
+use crate::tables::v1::{DataSourceFormat, TableCommit, TableDetail};
 
+pub use iceberg::IcebergTable;
 pub use predicate::json_predicate_to_expression;
+pub(crate) use predicate::{filter_to_json_predicate, filters_to_json_predicate};
 
 mod conversion;
+#[cfg(feature = "tokio")]
+pub(crate) mod create;
 pub(crate) mod engine;
+pub(crate) mod history;
+pub(crate) mod iceberg;
+pub(crate) mod infer;
 mod predicate;
+#[cfg(feature = "tokio")]
+pub(crate) mod stats;
+
+/// Number and total size of the data files in a table version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileStats {
+    pub num_files: i64,
+    pub size_in_bytes: i64,
+}
 
 #[async_trait::async_trait]
 pub trait TableManager: Send + Sync + 'static {
@@ -21,4 +38,55 @@ pub trait TableManager: Send + Sync + 'static {
         format: &DataSourceFormat,
         version: Option<Version>,
     ) -> Result<Arc<dyn TableSnapshot>>;
+
+    /// Infer the schema of a table stored as plain data files.
+    ///
+    /// Supports parquet, csv and json tables. Delta tables carry their schema
+    /// in the delta log and are read via [`read_snapshot`](Self::read_snapshot).
+    async fn infer_schema(
+        &self,
+        location: &StorageLocationUrl,
+        format: &DataSourceFormat,
+    ) -> Result<StructType>;
+
+    /// Read the current metadata of an iceberg table.
+    async fn read_iceberg_table(&self, location: &StorageLocationUrl) -> Result<IcebergTable>;
+
+    /// Create a new, empty delta table at the given location.
+    ///
+    /// Returns the snapshot of the newly created table.
+    async fn initialize_delta_table(
+        &self,
+        location: &StorageLocationUrl,
+        schema: &StructType,
+        partition_columns: &[String],
+    ) -> Result<Arc<dyn TableSnapshot>>;
+
+    /// Delete all data stored below the given table location.
+    async fn delete_table_data(&self, location: &StorageLocationUrl) -> Result<()>;
+
+    /// Count the data files of a delta table version and sum up their sizes.
+    async fn read_file_stats(
+        &self,
+        location: &StorageLocationUrl,
+        version: Version,
+    ) -> Result<FileStats>;
+
+    /// Read the commits that created the given versions of a delta table.
+    ///
+    /// Versions whose commit file was removed from the delta log are skipped.
+    async fn read_commits(
+        &self,
+        location: &StorageLocationUrl,
+        versions: &[Version],
+    ) -> Result<Vec<TableCommit>>;
+
+    /// Compute detailed statistics about the data files of a delta table version.
+    ///
+    /// Implementations may cache the result, as table versions are immutable.
+    async fn read_table_detail(
+        &self,
+        location: &StorageLocationUrl,
+        version: Version,
+    ) -> Result<TableDetail>;
 }
@@ -1,4 +1,9 @@
-//! Conversions of delta sharing json predicates to delta kernel expressions.
+//! Conversions of delta sharing json predicates to delta kernel expressions,
+//! and of DataFusion filters to json predicates.
+use chrono::{Days, NaiveDate};
+use datafusion::arrow::datatypes::{DataType as ArrowDataType, Schema as ArrowSchema};
+use datafusion::logical_expr::{BinaryExpr, Expr, Operator};
+use datafusion::scalar::ScalarValue;
 use delta_kernel::expressions::{
     BinaryOperator, ColumnName, Expression, JunctionOperator, UnaryOperator,
 };
@@ -21,7 +26,7 @@ pub fn json_predicate_to_expression(predicate: &JsonPredicate) -> Result<Express
         | "greaterthan"
         | "greater_than_or_equal"
         | "greaterthanorequal" => parse_binary(predicate),
-        "not" | "is_null" | "isNull" => parse_unary(predicate),
+        "not" | "is_null" | "isnull" => parse_unary(predicate),
         "and" | "or" => parse_variadics(predicate),
         _ => Err(Error::invalid_predicate(format!(
             "Invalid operator: {}",
@@ -164,6 +169,139 @@ fn parse_data_type(val: impl AsRef<str>) -> Result<DataType> {
     }
 }
 
+/// Combine the DataFusion filters of a scan into a single `JsonPredicate`.
+///
+/// Filters that cannot be expressed as json predicate are left out, so the result may
+/// match more rows than the filters. It must only be used to skip data, never to drop
+/// the filters themselves. Returns `None` if none of the filters can be expressed.
+pub(crate) fn filters_to_json_predicate(
+    filters: &[Expr],
+    schema: &ArrowSchema,
+) -> Option<JsonPredicate> {
+    let mut children: Vec<_> = filters
+        .iter()
+        .filter_map(|filter| filter_to_json_predicate(filter, schema))
+        .collect();
+    match children.len() {
+        0 => None,
+        1 => children.pop(),
+        _ => Some(JsonPredicate {
+            op: "and".to_string(),
+            children,
+            ..Default::default()
+        }),
+    }
+}
+
+/// Convert a DataFusion filter to a `JsonPredicate`.
+///
+/// Returns `None` if the filter uses expressions or types json predicates cannot express.
+pub(crate) fn filter_to_json_predicate(
+    filter: &Expr,
+    schema: &ArrowSchema,
+) -> Option<JsonPredicate> {
+    let predicate = |op: &str, children: Vec<JsonPredicate>| JsonPredicate {
+        op: op.to_string(),
+        children,
+        ..Default::default()
+    };
+    match filter {
+        Expr::Column(column) => {
+            let field = schema.field_with_name(&column.name).ok()?;
+            Some(JsonPredicate {
+                op: "column".to_string(),
+                name: Some(column.name.clone()),
+                value_type: Some(json_value_type(field.data_type())?.to_string()),
+                ..Default::default()
+            })
+        }
+        Expr::Literal(value) => {
+            let (value_type, value) = json_literal(value)?;
+            Some(JsonPredicate {
+                op: "literal".to_string(),
+                value: Some(value),
+                value_type: Some(value_type.to_string()),
+                ..Default::default()
+            })
+        }
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
+            let left = filter_to_json_predicate(left, schema)?;
+            let right = filter_to_json_predicate(right, schema)?;
+            let op = match op {
+                Operator::Eq => "equal",
+                Operator::NotEq => {
+                    return Some(predicate(
+                        "not",
+                        vec![predicate("equal", vec![left, right])],
+                    ));
+                }
+                Operator::Lt => "lessThan",
+                Operator::LtEq => "lessThanOrEqual",
+                Operator::Gt => "greaterThan",
+                Operator::GtEq => "greaterThanOrEqual",
+                Operator::And => "and",
+                Operator::Or => "or",
+                _ => return None,
+            };
+            Some(predicate(op, vec![left, right]))
+        }
+        Expr::Not(inner) => Some(predicate(
+            "not",
+            vec![filter_to_json_predicate(inner, schema)?],
+        )),
+        Expr::IsNull(inner) => Some(predicate(
+            "isNull",
+            vec![filter_to_json_predicate(inner, schema)?],
+        )),
+        Expr::IsNotNull(inner) => Some(predicate(
+            "not",
+            vec![predicate(
+                "isNull",
+                vec![filter_to_json_predicate(inner, schema)?],
+            )],
+        )),
+        _ => None,
+    }
+}
+
+/// The json predicate value type of an arrow data type.
+fn json_value_type(data_type: &ArrowDataType) -> Option<&'static str> {
+    match data_type {
+        ArrowDataType::Boolean => Some("boolean"),
+        ArrowDataType::Int32 => Some("int"),
+        ArrowDataType::Int64 => Some("long"),
+        ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View => Some("string"),
+        ArrowDataType::Date32 => Some("date"),
+        ArrowDataType::Float32 => Some("float"),
+        ArrowDataType::Float64 => Some("double"),
+        _ => None,
+    }
+}
+
+/// The value type and string representation of a non-null literal.
+fn json_literal(value: &ScalarValue) -> Option<(&'static str, String)> {
+    match value {
+        ScalarValue::Boolean(Some(v)) => Some(("boolean", v.to_string())),
+        ScalarValue::Int32(Some(v)) => Some(("int", v.to_string())),
+        ScalarValue::Int64(Some(v)) => Some(("long", v.to_string())),
+        ScalarValue::Utf8(Some(v))
+        | ScalarValue::LargeUtf8(Some(v))
+        | ScalarValue::Utf8View(Some(v)) => Some(("string", v.clone())),
+        ScalarValue::Date32(Some(days)) => {
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            let date = if *days >= 0 {
+                epoch.checked_add_days(Days::new(*days as u64))?
+            } else {
+                epoch.checked_sub_days(Days::new(days.unsigned_abs() as u64))?
+            };
+            Some(("date", date.format("%Y-%m-%d").to_string()))
+        }
+        ScalarValue::Float32(Some(v)) => Some(("float", v.to_string())),
+        ScalarValue::Float64(Some(v)) => Some(("double", v.to_string())),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -281,4 +419,57 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_filters_to_json_predicate() {
+        use datafusion::arrow::datatypes::Field;
+        use datafusion::logical_expr::{col, lit};
+
+        let schema = ArrowSchema::new(vec![
+            Field::new("id", ArrowDataType::Int64, false),
+            Field::new("day", ArrowDataType::Date32, true),
+            Field::new("tags", ArrowDataType::Binary, true),
+        ]);
+        let filters = vec![
+            col("day").gt_eq(lit(ScalarValue::Date32(Some(19724)))),
+            col("id").not_eq(lit(1_i64)),
+            // binary columns have no json predicate type, so the filter is left out.
+            col("tags").is_null(),
+        ];
+        let predicate = filters_to_json_predicate(&filters, &schema).unwrap();
+        assert_eq!(predicate.op, "and");
+        assert_eq!(predicate.children.len(), 2);
+        let day = &predicate.children[0];
+        assert_eq!(day.op, "greaterThanOrEqual");
+        assert_eq!(day.children[0].value_type.as_deref(), Some("date"));
+        assert_eq!(day.children[1].value.as_deref(), Some("2024-01-02"));
+
+        let expr = json_predicate_to_expression(&predicate).unwrap();
+        assert_eq!(
+            expr,
+            Expression::junction(
+                JunctionOperator::And,
+                vec![
+                    Expression::binary(
+                        BinaryOperator::GreaterThanOrEqual,
+                        Expression::Column(ColumnName::from_naive_str_split("day")),
+                        Expression::literal(
+                            PrimitiveType::Date.parse_scalar("2024-01-02").unwrap()
+                        ),
+                    ),
+                    Expression::unary(
+                        UnaryOperator::Not,
+                        Expression::binary(
+                            BinaryOperator::Equal,
+                            Expression::Column(ColumnName::from_naive_str_split("id")),
+                            Expression::literal(1_i64),
+                        ),
+                    ),
+                ]
+            )
+        );
+
+        let filters = vec![col("tags").is_null()];
+        assert!(filters_to_json_predicate(&filters, &schema).is_none());
+    }
 }
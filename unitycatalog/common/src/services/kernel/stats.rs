@@ -0,0 +1,166 @@
+//! Statistics about the data files of delta tables.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use delta_kernel::engine::default::DefaultEngine;
+use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+use delta_kernel::expressions::ExpressionRef;
+use delta_kernel::scan::state::{DvInfo, Stats};
+use delta_kernel::{Snapshot, Version};
+use object_store::DynObjectStore;
+use url::Url;
+
+use super::FileStats;
+use crate::models::tables::v1::{FileSizeBucket, TableDetail};
+use crate::{Error, Result};
+
+const MIB: i64 = 1024 * 1024;
+
+/// Size below which data files are considered small.
+///
+/// Files below this size are typically merged by compaction.
+pub(crate) const SMALL_FILE_THRESHOLD: i64 = 32 * MIB;
+
+/// Lower bounds of the buckets of the file size histogram.
+const FILE_SIZE_BUCKETS: [i64; 7] = [0, MIB, 8 * MIB, 32 * MIB, 128 * MIB, 512 * MIB, 1024 * MIB];
+
+/// Collect detailed statistics about the data files of a delta table version.
+///
+/// Log replay is blocking, so the scan runs on a dedicated blocking thread.
+pub(crate) async fn read_table_detail(
+    store: Arc<DynObjectStore>,
+    table_root: Url,
+    version: Version,
+) -> Result<TableDetail> {
+    tokio::task::spawn_blocking(move || -> Result<TableDetail> {
+        let engine = DefaultEngine::new(store, Arc::new(TokioBackgroundExecutor::new()));
+        let snapshot = Snapshot::try_new(table_root, &engine, Some(version))?;
+        let scan = snapshot.into_scan_builder().build()?;
+        let mut collector = DetailCollector::default();
+        for metadata in scan.scan_metadata(&engine)? {
+            collector = metadata?.visit_scan_files(collector, collect_file)?;
+        }
+        Ok(collector.finish(version))
+    })
+    .await
+    .map_err(|e| Error::generic(e.to_string()))?
+}
+
+#[derive(Debug, Default)]
+struct DetailCollector {
+    files: FileStats,
+    small_files: i64,
+    files_with_deletion_vectors: i64,
+    buckets: [(i64, i64); FILE_SIZE_BUCKETS.len()],
+    partitions: HashMap<Vec<(String, String)>, i64>,
+}
+
+impl DetailCollector {
+    fn add(&mut self, size: i64, has_deletion_vector: bool, partition: Vec<(String, String)>) {
+        self.files.num_files += 1;
+        self.files.size_in_bytes += size;
+        if size < SMALL_FILE_THRESHOLD {
+            self.small_files += 1;
+        }
+        if has_deletion_vector {
+            self.files_with_deletion_vectors += 1;
+        }
+        let bucket = FILE_SIZE_BUCKETS
+            .iter()
+            .rposition(|lower| size >= *lower)
+            .unwrap_or_default();
+        self.buckets[bucket].0 += 1;
+        self.buckets[bucket].1 += size;
+        if !partition.is_empty() {
+            *self.partitions.entry(partition).or_default() += size;
+        }
+    }
+
+    fn finish(self, version: Version) -> TableDetail {
+        let num_files = self.files.num_files;
+        let partition_skew = if num_files == 0 {
+            0.0
+        } else if self.partitions.is_empty() {
+            1.0
+        } else {
+            let max = self.partitions.values().copied().max().unwrap_or_default();
+            let mean = self.files.size_in_bytes as f64 / self.partitions.len() as f64;
+            if mean > 0.0 { max as f64 / mean } else { 1.0 }
+        };
+        let file_size_histogram = FILE_SIZE_BUCKETS
+            .iter()
+            .enumerate()
+            .map(|(idx, lower)| FileSizeBucket {
+                min_size_in_bytes: *lower,
+                max_size_in_bytes: FILE_SIZE_BUCKETS.get(idx + 1).copied(),
+                num_files: self.buckets[idx].0,
+                size_in_bytes: self.buckets[idx].1,
+            })
+            .collect();
+        TableDetail {
+            version: version as i64,
+            num_files,
+            size_in_bytes: self.files.size_in_bytes,
+            file_size_histogram,
+            num_partitions: self.partitions.len() as i64,
+            partition_skew,
+            small_file_ratio: if num_files == 0 {
+                0.0
+            } else {
+                self.small_files as f64 / num_files as f64
+            },
+            small_file_threshold_bytes: SMALL_FILE_THRESHOLD,
+            num_files_with_deletion_vectors: self.files_with_deletion_vectors,
+            ..Default::default()
+        }
+    }
+}
+
+fn collect_file(
+    collector: &mut DetailCollector,
+    _path: &str,
+    size: i64,
+    _stats: Option<Stats>,
+    dv_info: DvInfo,
+    _transform: Option<ExpressionRef>,
+    partition_values: HashMap<String, String>,
+) {
+    let mut partition: Vec<_> = partition_values.into_iter().collect();
+    partition.sort();
+    collector.add(size, dv_info.has_vector(), partition);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detail_collector() {
+        let partition = |day: &str| vec![("day".to_string(), day.to_string())];
+        let mut collector = DetailCollector::default();
+        collector.add(MIB / 2, false, partition("2024-01-01"));
+        collector.add(2 * MIB, true, partition("2024-01-01"));
+        collector.add(MIB / 2, false, partition("2024-01-02"));
+        collector.add(2048 * MIB, false, partition("2024-01-03"));
+        let detail = collector.finish(3);
+
+        assert_eq!(detail.version, 3);
+        assert_eq!(detail.num_files, 4);
+        assert_eq!(detail.num_partitions, 3);
+        assert_eq!(detail.small_file_ratio, 0.75);
+        assert_eq!(detail.num_files_with_deletion_vectors, 1);
+        let counts: Vec<_> = detail
+            .file_size_histogram
+            .iter()
+            .map(|b| b.num_files)
+            .collect();
+        assert_eq!(counts, vec![2, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(detail.file_size_histogram[6].max_size_in_bytes, None);
+        let mean = detail.size_in_bytes as f64 / 3.0;
+        assert_eq!(detail.partition_skew, (2048 * MIB) as f64 / mean);
+
+        let detail = DetailCollector::default().finish(0);
+        assert_eq!(detail.partition_skew, 0.0);
+        assert_eq!(detail.small_file_ratio, 0.0);
+    }
+}
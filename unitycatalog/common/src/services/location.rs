@@ -1,6 +1,10 @@
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use delta_kernel::object_store::ObjectStoreScheme;
 use itertools::Itertools;
+use object_store::memory::InMemory;
 use url::Url;
 
 use crate::{Error, Result};
@@ -77,6 +81,18 @@ impl StorageLocationUrl {
         &self.location
     }
 
+    /// The location with a trailing slash.
+    ///
+    /// Readers resolve paths like the delta log relative to the table root, which
+    /// requires the root to be a directory URL.
+    pub fn table_root(&self) -> Url {
+        let mut url = self.location.clone();
+        if !url.path().ends_with('/') {
+            url.set_path(&format!("{}/", url.path()));
+        }
+        url
+    }
+
     pub fn store_url(&self) -> &ObjectStoreUrl {
         &self.store_url
     }
@@ -84,6 +100,137 @@ impl StorageLocationUrl {
     pub fn scheme(&self) -> &StorageLocationScheme {
         &self.scheme
     }
+
+    /// Check if the other location is equal to or nested below this location.
+    ///
+    /// Paths are compared on segment boundaries, so `s3://bucket/a` contains
+    /// `s3://bucket/a/b` but not `s3://bucket/ab`.
+    pub fn contains(&self, other: &StorageLocationUrl) -> bool {
+        if self.store_url != other.store_url {
+            return false;
+        }
+        let segments = |url: &Url| {
+            url.path_segments()
+                .map(|s| s.filter(|s| !s.is_empty()).map(String::from).collect_vec())
+                .unwrap_or_default()
+        };
+        segments(&other.location).starts_with(&segments(&self.location))
+    }
+
+    /// Check if either location contains the other.
+    pub fn overlaps(&self, other: &StorageLocationUrl) -> bool {
+        self.contains(other) || other.contains(self)
+    }
+
+    /// All urls that contain this location, longest first.
+    ///
+    /// The urls are cut on path segment boundaries and normalized with
+    /// [`normalize_location_url`], the form used to index external locations.
+    pub fn prefixes(&self) -> Vec<String> {
+        let root = &self.url[..url::Position::AfterPort];
+        let segments = self
+            .url
+            .path_segments()
+            .map(|s| s.filter(|s| !s.is_empty()).collect_vec())
+            .unwrap_or_default();
+        (0..=segments.len())
+            .rev()
+            .map(|n| match n {
+                0 => normalize_location_url(root).to_string(),
+                _ => format!("{}/{}", root, segments[..n].join("/")),
+            })
+            .collect()
+    }
+}
+
+/// Normalize an external location url for prefix lookups.
+///
+/// Trailing slashes are removed, so the root of a store, e.g. `memory:///`,
+/// is indexed as `memory:`. Stores index urls the same way, see
+/// [`StorageLocationUrl::prefixes`].
+pub fn normalize_location_url(url: &str) -> &str {
+    url.trim_end_matches('/')
+}
+
+/// Settings for storage locations that are accessed without credentials.
+///
+/// Local file system locations are only resolved when they are contained in one
+/// of the allowed base directories. An empty allowlist rejects all `file://` locations.
+/// All `memory://` locations share a single in-memory store per server.
+#[derive(Clone, Default)]
+pub struct LocalStorageConfig {
+    allowed_roots: Vec<PathBuf>,
+    memory: Arc<InMemory>,
+}
+
+impl LocalStorageConfig {
+    pub fn new(allowed_roots: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        Self {
+            allowed_roots: allowed_roots.into_iter().map(Into::into).collect(),
+            memory: Arc::new(InMemory::new()),
+        }
+    }
+
+    pub fn allowed_roots(&self) -> &[PathBuf] {
+        &self.allowed_roots
+    }
+
+    /// Check if a `file://` url is contained in one of the allowed base directories.
+    ///
+    /// Paths are compared after resolving `..` segments and symbolic links, so links
+    /// inside an allowed directory cannot point to locations outside of it.
+    pub fn is_allowed(&self, url: &Url) -> bool {
+        let Ok(path) = url.to_file_path() else {
+            return false;
+        };
+        let path = canonical_path(&path);
+        self.allowed_roots
+            .iter()
+            .any(|root| path.starts_with(canonical_path(root)))
+    }
+
+    pub(crate) fn memory_store(&self) -> Arc<InMemory> {
+        self.memory.clone()
+    }
+}
+
+/// Resolve `.` and `..` segments and symbolic links in a path.
+///
+/// Locations may not exist yet, so links are only resolved for the longest
+/// existing ancestor and the remaining segments are appended as is.
+fn canonical_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    let mut existing = normalized.as_path();
+    let mut missing = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            return missing
+                .iter()
+                .rev()
+                .fold(canonical, |path, name| path.join(name));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return normalized,
+        }
+    }
+}
+
+/// Auxiliary trait for structs that hold a [`LocalStorageConfig`].
+pub trait ProvidesLocalStorage: Send + Sync + 'static {
+    fn local_storage(&self) -> &LocalStorageConfig;
 }
 
 fn is_azurite(url: &Url) -> bool {
@@ -130,7 +277,7 @@ fn get_store_url(url: &url::Url) -> Result<(ObjectStoreUrl, StorageLocationSchem
         StorageLocationScheme::ObjectStore(_) => {
             let store: &Url = store_url.as_ref();
             let store = store.clone();
-            
+
             store.join(url.path())?
         }
         StorageLocationScheme::Azurite if url.scheme() != "azurite" => {
@@ -145,3 +292,102 @@ fn get_store_url(url: &url::Url) -> Result<(ObjectStoreUrl, StorageLocationSchem
     };
     Ok((store_url, scheme, location))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_storage_allowlist() {
+        let config = LocalStorageConfig::new(["/data/uc"]);
+        let allowed = Url::parse("file:///data/uc/catalog/table").unwrap();
+        assert!(config.is_allowed(&allowed));
+
+        let sibling = Url::parse("file:///data/uc2/table").unwrap();
+        assert!(!config.is_allowed(&sibling));
+
+        let escaped = Url::parse("file:///data/uc/../secrets").unwrap();
+        assert!(!config.is_allowed(&escaped));
+
+        let empty = LocalStorageConfig::default();
+        assert!(!empty.is_allowed(&allowed));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn local_storage_allowlist_symlinks() {
+        let base = std::env::temp_dir().join(format!("uc-allowlist-{}", uuid::Uuid::now_v7()));
+        let root = base.join("root");
+        let outside = base.join("outside");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+        std::os::unix::fs::symlink(&root, base.join("root_link")).unwrap();
+
+        let config = LocalStorageConfig::new([&root]);
+        let file_url = |path: PathBuf| Url::from_file_path(path).unwrap();
+        assert!(config.is_allowed(&file_url(root.join("new/table"))));
+        assert!(!config.is_allowed(&file_url(root.join("link/table"))));
+        assert!(!config.is_allowed(&file_url(root.join("link"))));
+
+        // allowed roots may be given via links as well.
+        let config = LocalStorageConfig::new([base.join("root_link")]);
+        assert!(config.is_allowed(&file_url(root.join("table"))));
+        assert!(!config.is_allowed(&file_url(outside.join("table"))));
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn location_containment() {
+        let parent = StorageLocationUrl::parse("s3://bucket/data/").unwrap();
+        let child = StorageLocationUrl::parse("s3://bucket/data/table").unwrap();
+        let sibling = StorageLocationUrl::parse("s3://bucket/data2").unwrap();
+        let other_bucket = StorageLocationUrl::parse("s3://other/data/table").unwrap();
+
+        assert!(parent.contains(&child));
+        assert!(parent.contains(&parent));
+        assert!(!child.contains(&parent));
+        assert!(!parent.contains(&sibling));
+        assert!(!parent.contains(&other_bucket));
+
+        assert!(child.overlaps(&parent));
+        assert!(!sibling.overlaps(&parent));
+    }
+
+    #[test]
+    fn location_table_root() {
+        let location = StorageLocationUrl::parse("s3://bucket/data/table").unwrap();
+        assert_eq!(location.table_root().as_str(), "s3://bucket/data/table/");
+        let location = StorageLocationUrl::parse("s3://bucket/data/table/").unwrap();
+        assert_eq!(location.table_root().as_str(), "s3://bucket/data/table/");
+    }
+
+    #[test]
+    fn location_prefixes() {
+        let location = StorageLocationUrl::parse("s3://bucket/data/table/").unwrap();
+        assert_eq!(
+            location.prefixes(),
+            vec!["s3://bucket/data/table", "s3://bucket/data", "s3://bucket"]
+        );
+        assert!(
+            location
+                .prefixes()
+                .contains(&normalize_location_url("s3://bucket/data/").to_string())
+        );
+
+        let memory = StorageLocationUrl::parse("memory:///usage").unwrap();
+        assert_eq!(memory.prefixes(), vec!["memory:///usage", "memory:"]);
+
+        // locations at the root of a store
+        for root in ["memory:///", "file:///", "s3://bucket/"] {
+            let location = StorageLocationUrl::parse(root).unwrap();
+            assert_eq!(location.prefixes(), vec![normalize_location_url(root)]);
+        }
+        assert!(
+            memory
+                .prefixes()
+                .contains(&normalize_location_url("memory:///").to_string())
+        );
+    }
+}
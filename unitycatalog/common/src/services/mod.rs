@@ -3,21 +3,31 @@ use std::sync::Arc;
 use datafusion_common::{DataFusionError, Result as DFResult};
 use delta_kernel::Version;
 use delta_kernel::object_store::DynObjectStore;
+use delta_kernel::schema::StructType;
 use delta_kernel_datafusion::{ObjectStoreFactory, TableSnapshot};
 use url::Url;
 
-use self::kernel::TableManager;
+use self::kernel::engine::{ObjectStoreCache, ProvidesObjectStoreCache};
+use self::kernel::{FileStats, IcebergTable, TableManager};
 use crate::api::{RequestContext, SharingQueryHandler};
 use crate::models::sharing::v1::*;
-use crate::models::tables::v1::{DataSourceFormat, TableInfo};
+use crate::models::tables::v1::{DataSourceFormat, TableCommit, TableDetail, TableInfo};
 use crate::resources::ResourceStore;
 use crate::{ProvidesResourceStore, ResourceIdent, ResourceName, Result, ShareInfo};
 
+#[cfg(feature = "tokio")]
+pub mod catalog;
+pub mod delta_sharing;
 pub mod kernel;
 mod location;
 pub mod policy;
+#[cfg(feature = "tokio")]
+mod query;
 pub mod secrets;
 pub mod session;
+pub(crate) mod sql;
+pub mod system;
+pub(crate) mod validation;
 
 pub use location::*;
 pub use policy::*;
@@ -45,6 +55,30 @@ impl ServerHandler {
         let session = Arc::new(KernelSession::new(handler.clone()));
         Ok(Self { handler, session })
     }
+
+    /// Configure how storage locations without credentials are resolved.
+    pub fn with_local_storage(self, local_storage: LocalStorageConfig) -> Self {
+        let mut inner = self.handler.as_ref().clone();
+        inner.local_storage = local_storage;
+        let handler = Arc::new(inner);
+        let session = Arc::new(KernelSession::new(handler.clone()));
+        Self { handler, session }
+    }
+
+    /// Create a DataFusion session to query the catalogs the recipient can read.
+    ///
+    /// Tables are addressed by their full name, e.g. `SELECT * FROM catalog.schema.table`,
+    /// or relative to the default catalog and schema of the session config.
+    #[cfg(feature = "tokio")]
+    pub fn session_context(
+        &self,
+        config: datafusion::prelude::SessionConfig,
+        recipient: &Recipient,
+    ) -> datafusion::prelude::SessionContext {
+        let ctx = datafusion::prelude::SessionContext::new_with_config(config);
+        catalog::register_catalogs(&ctx, self.handler.clone(), recipient);
+        ctx
+    }
 }
 
 #[derive(Clone)]
@@ -52,6 +86,8 @@ pub struct ServerHandlerInner {
     policy: Arc<dyn Policy>,
     store: Arc<dyn ResourceStore>,
     secrets: Arc<dyn SecretManager>,
+    local_storage: LocalStorageConfig,
+    object_stores: ObjectStoreCache,
 }
 
 impl ServerHandlerInner {
@@ -64,6 +100,8 @@ impl ServerHandlerInner {
             policy,
             store,
             secrets,
+            local_storage: LocalStorageConfig::default(),
+            object_stores: ObjectStoreCache::default(),
         }
     }
 }
@@ -104,6 +142,30 @@ impl ProvidesSecretManager for ServerHandler {
     }
 }
 
+impl ProvidesLocalStorage for ServerHandlerInner {
+    fn local_storage(&self) -> &LocalStorageConfig {
+        &self.local_storage
+    }
+}
+
+impl ProvidesLocalStorage for ServerHandler {
+    fn local_storage(&self) -> &LocalStorageConfig {
+        &self.handler.local_storage
+    }
+}
+
+impl ProvidesObjectStoreCache for ServerHandlerInner {
+    fn object_store_cache(&self) -> &ObjectStoreCache {
+        &self.object_stores
+    }
+}
+
+impl ProvidesObjectStoreCache for ServerHandler {
+    fn object_store_cache(&self) -> &ObjectStoreCache {
+        &self.handler.object_stores
+    }
+}
+
 #[async_trait::async_trait]
 impl ObjectStoreFactory for ServerHandlerInner {
     async fn create_object_store(&self, location: &Url) -> DFResult<Arc<DynObjectStore>> {
@@ -126,6 +188,57 @@ impl TableManager for ServerHandler {
     ) -> Result<Arc<dyn TableSnapshot>> {
         self.session.read_snapshot(location, format, version).await
     }
+
+    async fn infer_schema(
+        &self,
+        location: &StorageLocationUrl,
+        format: &DataSourceFormat,
+    ) -> Result<StructType> {
+        self.session.infer_schema(location, format).await
+    }
+
+    async fn read_iceberg_table(&self, location: &StorageLocationUrl) -> Result<IcebergTable> {
+        self.session.read_iceberg_table(location).await
+    }
+
+    async fn initialize_delta_table(
+        &self,
+        location: &StorageLocationUrl,
+        schema: &StructType,
+        partition_columns: &[String],
+    ) -> Result<Arc<dyn TableSnapshot>> {
+        self.session
+            .initialize_delta_table(location, schema, partition_columns)
+            .await
+    }
+
+    async fn delete_table_data(&self, location: &StorageLocationUrl) -> Result<()> {
+        self.session.delete_table_data(location).await
+    }
+
+    async fn read_file_stats(
+        &self,
+        location: &StorageLocationUrl,
+        version: Version,
+    ) -> Result<FileStats> {
+        self.session.read_file_stats(location, version).await
+    }
+
+    async fn read_commits(
+        &self,
+        location: &StorageLocationUrl,
+        versions: &[Version],
+    ) -> Result<Vec<TableCommit>> {
+        self.session.read_commits(location, versions).await
+    }
+
+    async fn read_table_detail(
+        &self,
+        location: &StorageLocationUrl,
+        version: Version,
+    ) -> Result<TableDetail> {
+        self.session.read_table_detail(location, version).await
+    }
 }
 
 #[async_trait::async_trait]
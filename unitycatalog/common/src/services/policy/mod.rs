@@ -36,6 +36,14 @@ impl Recipient {
     pub fn custom(data: Bytes) -> Self {
         Self::Custom(data)
     }
+
+    /// The name of the user, if the recipient is a user.
+    pub fn user_name(&self) -> Option<&str> {
+        match self {
+            Self::User(name) => Some(name),
+            _ => None,
+        }
+    }
 }
 
 /// Permission that a policy can authorize.
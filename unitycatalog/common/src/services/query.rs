@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::dataframe::DataFrame;
+use datafusion::error::DataFusionError;
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::prelude::SessionConfig;
+use futures_util::StreamExt;
+use tokio::time::{Instant, timeout_at};
+
+use super::ServerHandler;
+use super::catalog::preview_delta_table;
+use super::sql::SqlQuery;
+use crate::api::query::{PreviewTableRequest, QueryHandler, QueryRequest};
+use crate::api::tables::get_active_table;
+use crate::api::{RequestContext, SecuredAction};
+use crate::resources::ResourceIdent;
+use crate::services::policy::{Permission, Policy};
+use crate::services::system::is_system_catalog;
+use crate::{Error, Result};
+
+#[async_trait::async_trait]
+impl QueryHandler for ServerHandler {
+    async fn execute_query(
+        &self,
+        request: QueryRequest,
+        context: RequestContext,
+    ) -> Result<SendableRecordBatchStream> {
+        let timeout = request.time_limit();
+        let deadline = Instant::now() + timeout;
+        let execute = async {
+            let frame = self.plan_query(&request, &context).await?;
+            Ok::<_, Error>(frame.execute_stream().await.map_err(query_error)?)
+        };
+        let stream = timeout_at(deadline, execute)
+            .await
+            .map_err(|_| timeout_error(timeout))??;
+
+        // the deadline also bounds reading the results. Dropping the stream once it passes
+        // cancels all running scans, as table reads stop as soon as their receiver is gone.
+        let schema = stream.schema();
+        let stream = futures_util::stream::unfold(Some(stream), move |stream| async move {
+            let mut stream = stream?;
+            match timeout_at(deadline, stream.next()).await {
+                Ok(Some(batch)) => Some((batch, Some(stream))),
+                Ok(None) => None,
+                Err(_) => Some((
+                    Err(DataFusionError::External(Box::new(timeout_error(timeout)))),
+                    None,
+                )),
+            }
+        });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
+    }
+
+    async fn query_schema(
+        &self,
+        request: QueryRequest,
+        context: RequestContext,
+    ) -> Result<SchemaRef> {
+        let timeout = request.time_limit();
+        let frame = timeout_at(
+            Instant::now() + timeout,
+            self.plan_query(&request, &context),
+        )
+        .await
+        .map_err(|_| timeout_error(timeout))??;
+        Ok(Arc::new(frame.schema().as_arrow().clone()))
+    }
+
+    async fn preview_table(
+        &self,
+        request: PreviewTableRequest,
+        context: RequestContext,
+    ) -> Result<SendableRecordBatchStream> {
+        self.check_required(&request, context.as_ref()).await?;
+        let info = get_active_table(self, &request.resource()).await?;
+        preview_delta_table(
+            self.handler.as_ref(),
+            &info,
+            (!request.columns.is_empty()).then_some(request.columns.as_slice()),
+            request.row_limit(),
+        )
+        .await
+    }
+}
+
+impl ServerHandler {
+    /// Check access to all tables referenced in a query and plan its execution.
+    async fn plan_query(
+        &self,
+        request: &QueryRequest,
+        context: &RequestContext,
+    ) -> Result<DataFrame> {
+        let catalog_name = request.catalog_name.as_deref().unwrap_or_default();
+        let schema_name = request.schema_name.as_deref().unwrap_or_default();
+        let query = SqlQuery::parse(&request.statement, catalog_name, schema_name)?;
+
+        // check all tables up front, so denied access is reported as such.
+        for name in query.table_references()? {
+            if name.iter().any(|segment| segment.is_empty()) {
+                return Err(Error::invalid_argument(format!(
+                    "table '{name}' must be fully qualified if no default catalog and schema are set"
+                )));
+            }
+            // system tables only contain resources the recipient can read.
+            if name.path().first().is_some_and(|c| is_system_catalog(c)) {
+                continue;
+            }
+            self.authorize_checked(
+                &ResourceIdent::table(name),
+                &Permission::Select,
+                context.as_ref(),
+            )
+            .await?;
+        }
+
+        let config =
+            SessionConfig::new().with_default_catalog_and_schema(catalog_name, schema_name);
+        let ctx = self.session_context(config, context.as_ref());
+        let plan = ctx
+            .state()
+            .statement_to_plan(query.statement().clone())
+            .await
+            .map_err(query_error)?;
+        Ok(ctx
+            .execute_logical_plan(plan)
+            .await?
+            .limit(0, Some(request.row_limit()))?)
+    }
+}
+
+fn timeout_error(timeout: std::time::Duration) -> Error {
+    Error::deadline_exceeded(format!(
+        "query exceeded the time limit of {}s",
+        timeout.as_secs()
+    ))
+}
+
+/// Surface errors raised by the catalog as is, and planning errors as invalid queries.
+///
+/// Other errors, e.g. from reading data via object stores, are failures of the server.
+fn query_error(error: DataFusionError) -> Error {
+    match error {
+        DataFusionError::External(inner) => match inner.downcast::<Error>() {
+            Ok(error) => *error,
+            Err(inner) => Error::DataFusion(DataFusionError::External(inner)),
+        },
+        DataFusionError::Context(_, inner) | DataFusionError::Diagnostic(_, inner) => {
+            query_error(*inner)
+        }
+        DataFusionError::Plan(_)
+        | DataFusionError::SQL(..)
+        | DataFusionError::SchemaError(..)
+        | DataFusionError::NotImplemented(_) => {
+            Error::invalid_argument(format!("invalid query: {error}"))
+        }
+        error => Error::DataFusion(error),
+    }
+}
@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::Result;
+use crate::models::ObjectLabel;
+use crate::models::credentials::v1::CredentialInfo;
+use crate::resources::ResourceStore;
+
+mod vault;
+
+pub use vault::VaultSecretManager;
+
+/// A trait for managing secrets.
+///
+/// All sensitive data that needs to be stored in the system should be stored as a secret.
+///
+/// The secret manager is responsible for fetching the secret value from the secret store.
+/// The secret store can be a key-value store, a secret manager service, or any other secret store.
+#[async_trait::async_trait]
+pub trait SecretManager: Send + Sync + 'static {
+    /// Get the secret value for the given secret name.
+    ///
+    /// Secrets are identified by a unique name. The secret manager should return the secret value
+    /// and the version of the secret. The version is used to ensure that the secret value has not
+    /// changed since the last time it was fetched.
+    ///
+    /// The secret manager should return an error if the secret does not exist
+    async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)>;
+
+    /// Get the secret value for the given secret name and version.
+    /// This method is used to fetch a specific version of the secret.
+    /// The secret manager should return an error if the secret does not exist
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes>;
+
+    /// Create a new secret with the given name and value.
+    /// The secret manager should return an error if the secret already exists
+    /// or if the secret value is invalid.
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid>;
+
+    /// Update the secret value for the given secret name.
+    /// The secret manager should return an error if the secret does not exist
+    /// or if the secret value is invalid.
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid>;
+
+    /// Delete the secret with the given name.
+    /// The secret manager should return an error if the secret does not exist
+    async fn delete_secret(&self, secret_name: &str) -> Result<()>;
+
+    /// List all versions of the secret with the given name, newest first.
+    /// The secret manager should return an error if the secret does not exist
+    async fn list_secret_versions(&self, secret_name: &str) -> Result<Vec<SecretVersion>>;
+
+    /// Delete a single version of the secret with the given name.
+    /// The secret manager should return an error if the version does not exist
+    /// or if it is the current version of the secret.
+    async fn delete_secret_version(&self, secret_name: &str, version: Uuid) -> Result<()>;
+
+    /// Delete all versions of the secret that expired according to the retention policy.
+    ///
+    /// Returns the number of deleted versions.
+    async fn prune_secret_versions(
+        &self,
+        secret_name: &str,
+        retention: &SecretRetention,
+    ) -> Result<usize> {
+        let versions = self.list_secret_versions(secret_name).await?;
+        let expired = retention.expired(&versions, Utc::now());
+        for version in &expired {
+            self.delete_secret_version(secret_name, *version).await?;
+        }
+        Ok(expired.len())
+    }
+}
+
+/// Metadata of a single secret version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretVersion {
+    /// The identifier of the version.
+    pub version: Uuid,
+
+    /// The time at which the version was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Retention policy for secret versions.
+///
+/// The current version of a secret is always retained. Previous versions are
+/// deleted once they exceed any of the configured limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecretRetention {
+    keep_last: Option<usize>,
+    max_age: Option<Duration>,
+}
+
+impl SecretRetention {
+    /// Create a policy that retains all versions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retain at most the given number of versions, including the current version.
+    pub fn with_keep_last(mut self, keep_last: usize) -> Self {
+        self.keep_last = Some(keep_last);
+        self
+    }
+
+    /// Retain previous versions only while they are younger than the given age.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Returns `true` if the policy never deletes any versions.
+    pub fn is_unbounded(&self) -> bool {
+        self.keep_last.is_none() && self.max_age.is_none()
+    }
+
+    /// Select the versions that should be deleted at the given time.
+    pub fn expired(&self, versions: &[SecretVersion], now: DateTime<Utc>) -> Vec<Uuid> {
+        let mut versions = versions.iter().collect::<Vec<_>>();
+        versions.sort_by_key(|v| std::cmp::Reverse((v.created_at, v.version)));
+        versions
+            .into_iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(idx, version)| {
+                self.keep_last.is_some_and(|keep| *idx >= keep)
+                    || self
+                        .max_age
+                        .is_some_and(|max_age| now - version.created_at > max_age)
+            })
+            .map(|(_, version)| version.version)
+            .collect()
+    }
+}
+
+/// Auxiliary trait for implementing [`SecretManager`] for structs that contain a [`SecretManager`].
+pub trait ProvidesSecretManager: Send + Sync + 'static {
+    fn secret_manager(&self) -> &dyn SecretManager;
+}
+
+#[async_trait::async_trait]
+impl<T: SecretManager> SecretManager for Arc<T> {
+    async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)> {
+        T::get_secret(self, secret_name).await
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes> {
+        T::get_secret_version(self, secret_name, version).await
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        T::create_secret(self, secret_name, secret_value).await
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        T::update_secret(self, secret_name, secret_value).await
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> Result<()> {
+        T::delete_secret(self, secret_name).await
+    }
+
+    async fn list_secret_versions(&self, secret_name: &str) -> Result<Vec<SecretVersion>> {
+        T::list_secret_versions(self, secret_name).await
+    }
+
+    async fn delete_secret_version(&self, secret_name: &str, version: Uuid) -> Result<()> {
+        T::delete_secret_version(self, secret_name, version).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: ProvidesSecretManager> SecretManager for T {
+    async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)> {
+        self.secret_manager().get_secret(secret_name).await
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes> {
+        self.secret_manager()
+            .get_secret_version(secret_name, version)
+            .await
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        self.secret_manager()
+            .create_secret(secret_name, secret_value)
+            .await
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        self.secret_manager()
+            .update_secret(secret_name, secret_value)
+            .await
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> Result<()> {
+        self.secret_manager().delete_secret(secret_name).await
+    }
+
+    async fn list_secret_versions(&self, secret_name: &str) -> Result<Vec<SecretVersion>> {
+        self.secret_manager()
+            .list_secret_versions(secret_name)
+            .await
+    }
+
+    async fn delete_secret_version(&self, secret_name: &str, version: Uuid) -> Result<()> {
+        self.secret_manager()
+            .delete_secret_version(secret_name, version)
+            .await
+    }
+}
+
+/// Delete expired versions of the secrets backing all credentials.
+///
+/// Failures for individual secrets are logged and do not abort the cleanup.
+///
+/// # Returns
+/// The number of deleted secret versions.
+pub async fn prune_credential_secrets<T: ResourceStore + SecretManager>(
+    handler: &T,
+    retention: &SecretRetention,
+) -> Result<usize> {
+    let mut deleted = 0;
+    let mut page_token = None;
+    loop {
+        let (credentials, next_page_token) = handler
+            .list(&ObjectLabel::CredentialInfo, None, None, page_token)
+            .await?;
+        for credential in credentials {
+            let credential: CredentialInfo = credential.try_into()?;
+            match handler
+                .prune_secret_versions(&credential.name, retention)
+                .await
+            {
+                Ok(count) => deleted += count,
+                Err(e) => tracing::warn!(
+                    "failed to prune secret versions for credential '{}': {e}",
+                    credential.name
+                ),
+            }
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(deleted),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(now: DateTime<Utc>, ages: &[i64]) -> Vec<SecretVersion> {
+        ages.iter()
+            .enumerate()
+            .map(|(idx, age)| SecretVersion {
+                version: Uuid::from_u128(idx as u128),
+                created_at: now - Duration::days(*age),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn secret_retention() {
+        let now = Utc::now();
+        let versions = versions(now, &[30, 20, 10, 1]);
+
+        assert!(SecretRetention::new().expired(&versions, now).is_empty());
+
+        let expired = SecretRetention::new()
+            .with_keep_last(2)
+            .expired(&versions, now);
+        assert_eq!(expired, vec![Uuid::from_u128(1), Uuid::from_u128(0)]);
+
+        let expired = SecretRetention::new()
+            .with_max_age(Duration::days(15))
+            .expired(&versions, now);
+        assert_eq!(expired, vec![Uuid::from_u128(1), Uuid::from_u128(0)]);
+
+        // the current version is retained even if it is too old
+        let expired = SecretRetention::new()
+            .with_keep_last(0)
+            .with_max_age(Duration::hours(1))
+            .expired(&versions, now);
+        assert_eq!(expired.len(), 3);
+        assert!(!expired.contains(&Uuid::from_u128(3)));
+    }
+}
@@ -0,0 +1,689 @@
+//! Secret manager backed by the HashiCorp Vault KV version 2 secrets engine.
+//!
+//! Each secret is stored as a Vault secret holding the base64 encoded value and the
+//! version id assigned by the catalog. Vault numbers secret versions sequentially,
+//! so the mapping from version ids to Vault versions is kept in the custom metadata
+//! of the secret.
+
+use std::collections::HashMap;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use cloud_client::{CloudClient, CloudRequestBuilder};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+use super::{SecretManager, SecretVersion};
+use crate::{Error, Result};
+
+const TOKEN_HEADER: &str = "X-Vault-Token";
+const NAMESPACE_HEADER: &str = "X-Vault-Namespace";
+const DEFAULT_MOUNT: &str = "secret";
+const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+
+/// Number of attempts to write a new secret version while other writers race for it.
+const MAX_CAS_ATTEMPTS: usize = 5;
+
+/// A [`SecretManager`] storing secrets in a Vault KV version 2 secrets engine.
+#[derive(Clone)]
+pub struct VaultSecretManager {
+    client: CloudClient,
+    address: Url,
+    token: String,
+    mount: String,
+    prefix: Option<String>,
+    namespace: Option<String>,
+}
+
+impl VaultSecretManager {
+    /// Create a new secret manager for the Vault server at `address`.
+    ///
+    /// Secrets are stored in the KV secrets engine mounted at `secret`.
+    pub fn new(address: Url, token: impl Into<String>) -> Self {
+        Self {
+            client: CloudClient::new_unauthenticated(),
+            address,
+            token: token.into(),
+            mount: DEFAULT_MOUNT.to_string(),
+            prefix: None,
+            namespace: None,
+        }
+    }
+
+    /// Use the KV secrets engine mounted at the given path.
+    pub fn with_mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into();
+        self
+    }
+
+    /// Store all secrets below the given path.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Send all requests to the given Vault namespace.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    fn url(&self, kind: &str, secret_name: &str) -> Result<Url> {
+        let mut url = self.address.clone();
+        url.path_segments_mut()
+            .map_err(|_| Error::invalid_argument("invalid vault address"))?
+            .pop_if_empty()
+            .push("v1")
+            .extend(self.mount.split('/').filter(|s| !s.is_empty()))
+            .push(kind)
+            .extend(
+                self.prefix
+                    .iter()
+                    .flat_map(|prefix| prefix.split('/'))
+                    .filter(|s| !s.is_empty()),
+            )
+            .push(secret_name);
+        Ok(url)
+    }
+
+    fn request(&self, method: Method, url: Url) -> CloudRequestBuilder {
+        let builder = self
+            .client
+            .request(method, url)
+            .header(TOKEN_HEADER, self.token.as_str());
+        match &self.namespace {
+            Some(namespace) => builder.header(NAMESPACE_HEADER, namespace.as_str()),
+            None => builder,
+        }
+    }
+
+    async fn read_metadata(&self, secret_name: &str) -> Result<SecretMetadata> {
+        let response = self
+            .request(Method::GET, self.url("metadata", secret_name)?)
+            .send()
+            .await?;
+        let metadata: VaultResponse<SecretMetadata> =
+            check_response(response).await?.json().await?;
+        Ok(metadata.data)
+    }
+
+    /// Write a new version of the secret and record its version id in the secret metadata.
+    ///
+    /// The write only succeeds if `cas` matches the current Vault version of the secret.
+    async fn write_version(
+        &self,
+        secret_name: &str,
+        cas: u64,
+        secret_value: &Bytes,
+        metadata: Option<&SecretMetadata>,
+    ) -> Result<Uuid> {
+        let data = SecretData {
+            value: STANDARD.encode(secret_value),
+            version_id: Uuid::now_v7(),
+        };
+        let request = WriteRequest {
+            data: &data,
+            options: WriteOptions { cas },
+        };
+        let response = self
+            .request(Method::POST, self.url("data", secret_name)?)
+            .json(&request)
+            .send()
+            .await?;
+        let written: VaultResponse<WriteResponse> = check_response(response).await?.json().await?;
+
+        let mut changes = metadata
+            .map(SecretMetadata::stale_versions)
+            .unwrap_or_default();
+        changes.insert(
+            data.version_id.to_string(),
+            Some(written.data.version.to_string()),
+        );
+        self.patch_versions(secret_name, changes).await?;
+
+        Ok(data.version_id)
+    }
+
+    /// Add or remove (`None`) version id mappings in the secret metadata.
+    ///
+    /// The changes are sent as a JSON merge patch, so mappings written concurrently
+    /// by other writers are retained rather than overwritten with a stale copy.
+    async fn patch_versions(
+        &self,
+        secret_name: &str,
+        custom_metadata: HashMap<String, Option<String>>,
+    ) -> Result<()> {
+        let body = serde_json::to_vec(&MetadataPatch { custom_metadata })?;
+        let response = self
+            .request(Method::PATCH, self.url("metadata", secret_name)?)
+            .header(CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE)
+            .body(body)
+            .send()
+            .await?;
+        check_response(response).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManager for VaultSecretManager {
+    async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)> {
+        let response = self
+            .request(Method::GET, self.url("data", secret_name)?)
+            .send()
+            .await?;
+        let secret: VaultResponse<ReadResponse> = check_response(response).await?.json().await?;
+        let secret = secret.data.data;
+        Ok((secret.version_id, secret.decode()?))
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes> {
+        let vault_version = self
+            .read_metadata(secret_name)
+            .await?
+            .custom_metadata
+            .and_then(|versions| versions.get(&version.to_string()).cloned())
+            .ok_or(Error::NotFound)?;
+        let response = self
+            .request(Method::GET, self.url("data", secret_name)?)
+            .query(&[("version", vault_version)])
+            .send()
+            .await?;
+        let secret: VaultResponse<ReadResponse> = check_response(response).await?.json().await?;
+        let secret = secret.data.data;
+        if secret.version_id != version {
+            return Err(Error::NotFound);
+        }
+        secret.decode()
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        // a check-and-set value of 0 only allows the write if the secret does not exist.
+        self.write_version(secret_name, 0, &secret_value, None)
+            .await
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        // a concurrent write makes the check-and-set fail, so we retry on the new version.
+        for _ in 0..MAX_CAS_ATTEMPTS {
+            let metadata = self.read_metadata(secret_name).await?;
+            let cas = metadata.current_version;
+            match self
+                .write_version(secret_name, cas, &secret_value, Some(&metadata))
+                .await
+            {
+                Err(Error::AlreadyExists) => continue,
+                result => return result,
+            }
+        }
+        Err(Error::generic(format!(
+            "secret '{secret_name}' was modified concurrently"
+        )))
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> Result<()> {
+        let response = self
+            .request(Method::DELETE, self.url("metadata", secret_name)?)
+            .send()
+            .await?;
+        check_response(response).await?;
+        Ok(())
+    }
+
+    async fn list_secret_versions(&self, secret_name: &str) -> Result<Vec<SecretVersion>> {
+        let metadata = self.read_metadata(secret_name).await?;
+        let mut versions = metadata
+            .custom_metadata
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(version_id, version)| {
+                let meta = metadata.versions.get(&version)?;
+                let vault_version = version.parse::<u64>().ok()?;
+                (!meta.destroyed).then_some((vault_version, version_id, meta.created_time))
+            })
+            .map(|(vault_version, version_id, created_at)| {
+                Ok((
+                    vault_version,
+                    SecretVersion {
+                        version: version_id.parse()?,
+                        created_at,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        versions.sort_by_key(|(vault_version, _)| std::cmp::Reverse(*vault_version));
+        Ok(versions.into_iter().map(|(_, version)| version).collect())
+    }
+
+    async fn delete_secret_version(&self, secret_name: &str, version: Uuid) -> Result<()> {
+        let metadata = self.read_metadata(secret_name).await?;
+        let version_id = version.to_string();
+        let vault_version = metadata
+            .custom_metadata
+            .as_ref()
+            .and_then(|versions| versions.get(&version_id))
+            .and_then(|version| version.parse::<u64>().ok())
+            .ok_or(Error::NotFound)?;
+        if vault_version == metadata.current_version {
+            return Err(Error::invalid_argument(
+                "the current version of a secret cannot be deleted",
+            ));
+        }
+
+        let response = self
+            .request(Method::POST, self.url("destroy", secret_name)?)
+            .json(&DestroyRequest {
+                versions: vec![vault_version],
+            })
+            .send()
+            .await?;
+        check_response(response).await?;
+
+        let mut changes = metadata.stale_versions();
+        changes.insert(version_id, None);
+        self.patch_versions(secret_name, changes).await
+    }
+}
+
+/// Map unsuccessful Vault responses to errors.
+async fn check_response(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if status == StatusCode::NOT_FOUND {
+        return Err(Error::NotFound);
+    }
+    let errors = response
+        .json::<ErrorResponse>()
+        .await
+        .map(|e| e.errors)
+        .unwrap_or_default();
+    if status == StatusCode::BAD_REQUEST && errors.iter().any(|e| e.contains("check-and-set")) {
+        return Err(Error::AlreadyExists);
+    }
+    Err(Error::generic(format!(
+        "vault request failed with status {status}: {}",
+        errors.join(", ")
+    )))
+}
+
+#[derive(Deserialize)]
+struct VaultResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+/// The data stored in each version of a Vault secret.
+#[derive(Serialize, Deserialize)]
+struct SecretData {
+    value: String,
+    version_id: Uuid,
+}
+
+impl SecretData {
+    fn decode(&self) -> Result<Bytes> {
+        let value = STANDARD
+            .decode(&self.value)
+            .map_err(|e| Error::generic(format!("invalid secret value: {e}")))?;
+        Ok(value.into())
+    }
+}
+
+#[derive(Deserialize)]
+struct ReadResponse {
+    data: SecretData,
+}
+
+#[derive(Serialize)]
+struct WriteRequest<'a> {
+    data: &'a SecretData,
+    options: WriteOptions,
+}
+
+#[derive(Serialize)]
+struct WriteOptions {
+    cas: u64,
+}
+
+#[derive(Deserialize)]
+struct WriteResponse {
+    version: u64,
+}
+
+#[derive(Serialize)]
+struct DestroyRequest {
+    versions: Vec<u64>,
+}
+
+#[derive(Serialize)]
+struct MetadataPatch {
+    custom_metadata: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize)]
+struct SecretMetadata {
+    current_version: u64,
+    #[serde(default)]
+    versions: HashMap<String, VersionMetadata>,
+    #[serde(default)]
+    custom_metadata: Option<HashMap<String, String>>,
+}
+
+impl SecretMetadata {
+    /// Removals of the version id mappings for versions no longer retained by Vault.
+    ///
+    /// Vault limits the number of custom metadata keys, so mappings for versions
+    /// that have been destroyed or pruned are dropped.
+    fn stale_versions(&self) -> HashMap<String, Option<String>> {
+        self.custom_metadata
+            .iter()
+            .flatten()
+            .filter(|(_, version)| self.versions.get(*version).is_none_or(|v| v.destroyed))
+            .map(|(version_id, _)| (version_id.clone(), None))
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionMetadata {
+    created_time: DateTime<Utc>,
+    #[serde(default)]
+    destroyed: bool,
+}
+
+#[cfg(all(test, feature = "axum"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::extract::{Path, Query, State};
+    use axum::http::HeaderMap;
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    const TOKEN: &str = "test-token";
+
+    /// A secret as stored by the mock Vault server.
+    #[derive(Default)]
+    struct KvSecret {
+        versions: Vec<KvVersion>,
+        custom_metadata: HashMap<String, String>,
+    }
+
+    struct KvVersion {
+        /// The secret data, `None` once the version is destroyed.
+        data: Option<Value>,
+        created_time: DateTime<Utc>,
+    }
+
+    type Secrets = Arc<Mutex<HashMap<String, KvSecret>>>;
+    type MockResult = std::result::Result<Json<Value>, (StatusCode, Json<Value>)>;
+
+    fn vault_error(status: StatusCode, message: &str) -> (StatusCode, Json<Value>) {
+        (status, Json(json!({ "errors": [message] })))
+    }
+
+    fn authorize(headers: &HeaderMap) -> std::result::Result<(), (StatusCode, Json<Value>)> {
+        match headers.get(TOKEN_HEADER) {
+            Some(token) if token == TOKEN => Ok(()),
+            _ => Err(vault_error(StatusCode::FORBIDDEN, "permission denied")),
+        }
+    }
+
+    async fn read_data(
+        State(secrets): State<Secrets>,
+        headers: HeaderMap,
+        Path(path): Path<String>,
+        Query(query): Query<HashMap<String, String>>,
+    ) -> MockResult {
+        authorize(&headers)?;
+        let secrets = secrets.lock().unwrap();
+        let not_found = || vault_error(StatusCode::NOT_FOUND, "");
+        let secret = secrets.get(&path).ok_or_else(not_found)?;
+        let version = match query.get("version") {
+            Some(version) => version.parse().unwrap(),
+            None => secret.versions.len(),
+        };
+        let data = version
+            .checked_sub(1)
+            .and_then(|idx| secret.versions.get(idx))
+            .and_then(|version| version.data.as_ref())
+            .ok_or_else(not_found)?;
+        Ok(Json(
+            json!({ "data": { "data": data, "metadata": { "version": version } } }),
+        ))
+    }
+
+    async fn write_data(
+        State(secrets): State<Secrets>,
+        headers: HeaderMap,
+        Path(path): Path<String>,
+        Json(body): Json<Value>,
+    ) -> MockResult {
+        authorize(&headers)?;
+        let mut secrets = secrets.lock().unwrap();
+        let secret = secrets.entry(path).or_default();
+        let current = secret.versions.len() as u64;
+        if body["options"]["cas"]
+            .as_u64()
+            .is_some_and(|cas| cas != current)
+        {
+            return Err(vault_error(
+                StatusCode::BAD_REQUEST,
+                "check-and-set parameter did not match the current version",
+            ));
+        }
+        secret.versions.push(KvVersion {
+            data: Some(body["data"].clone()),
+            created_time: Utc::now(),
+        });
+        Ok(Json(
+            json!({ "data": { "version": secret.versions.len() } }),
+        ))
+    }
+
+    async fn read_metadata(
+        State(secrets): State<Secrets>,
+        headers: HeaderMap,
+        Path(path): Path<String>,
+    ) -> MockResult {
+        authorize(&headers)?;
+        let secrets = secrets.lock().unwrap();
+        let secret = secrets
+            .get(&path)
+            .ok_or_else(|| vault_error(StatusCode::NOT_FOUND, ""))?;
+        let versions: serde_json::Map<String, Value> = secret
+            .versions
+            .iter()
+            .enumerate()
+            .map(|(idx, version)| {
+                let metadata = json!({
+                    "created_time": version.created_time,
+                    "destroyed": version.data.is_none(),
+                });
+                ((idx + 1).to_string(), metadata)
+            })
+            .collect();
+        Ok(Json(json!({
+            "data": {
+                "current_version": secret.versions.len(),
+                "versions": versions,
+                "custom_metadata": secret.custom_metadata,
+            }
+        })))
+    }
+
+    async fn patch_metadata(
+        State(secrets): State<Secrets>,
+        headers: HeaderMap,
+        Path(path): Path<String>,
+        Json(body): Json<Value>,
+    ) -> MockResult {
+        authorize(&headers)?;
+        let mut secrets = secrets.lock().unwrap();
+        let custom_metadata = &mut secrets.entry(path).or_default().custom_metadata;
+        let patch = body["custom_metadata"].as_object().unwrap();
+        for (key, value) in patch {
+            match value.as_str() {
+                Some(value) => custom_metadata.insert(key.clone(), value.to_string()),
+                None => custom_metadata.remove(key),
+            };
+        }
+        Ok(Json(Value::Null))
+    }
+
+    async fn delete_metadata(
+        State(secrets): State<Secrets>,
+        headers: HeaderMap,
+        Path(path): Path<String>,
+    ) -> MockResult {
+        authorize(&headers)?;
+        secrets.lock().unwrap().remove(&path);
+        Ok(Json(Value::Null))
+    }
+
+    async fn destroy_versions(
+        State(secrets): State<Secrets>,
+        headers: HeaderMap,
+        Path(path): Path<String>,
+        Json(body): Json<Value>,
+    ) -> MockResult {
+        authorize(&headers)?;
+        let mut secrets = secrets.lock().unwrap();
+        let secret = secrets.entry(path).or_default();
+        for version in body["versions"].as_array().unwrap() {
+            let idx = version.as_u64().unwrap() as usize - 1;
+            if let Some(version) = secret.versions.get_mut(idx) {
+                version.data = None;
+            }
+        }
+        Ok(Json(Value::Null))
+    }
+
+    /// Start a mock Vault server and return its address.
+    async fn mock_vault(secrets: Secrets) -> Url {
+        let router = Router::new()
+            .route("/v1/secret/data/{*path}", get(read_data).post(write_data))
+            .route(
+                "/v1/secret/metadata/{*path}",
+                get(read_metadata)
+                    .patch(patch_metadata)
+                    .delete(delete_metadata),
+            )
+            .route("/v1/secret/destroy/{*path}", post(destroy_versions))
+            .with_state(secrets);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        address.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_vault_secret_versions() {
+        let secrets = Secrets::default();
+        let address = mock_vault(secrets.clone()).await;
+        let manager = VaultSecretManager::new(address, TOKEN).with_prefix("unitycatalog");
+
+        let v1 = manager
+            .create_secret("cred", Bytes::from_static(b"secret-1"))
+            .await
+            .unwrap();
+        assert!(secrets.lock().unwrap().contains_key("unitycatalog/cred"));
+        let (version, value) = manager.get_secret("cred").await.unwrap();
+        assert_eq!(version, v1);
+        assert_eq!(value, Bytes::from_static(b"secret-1"));
+
+        let err = manager
+            .create_secret("cred", Bytes::from_static(b"other"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::AlreadyExists));
+
+        let v2 = manager
+            .update_secret("cred", Bytes::from_static(b"secret-2"))
+            .await
+            .unwrap();
+        assert_ne!(v1, v2);
+        let (version, value) = manager.get_secret("cred").await.unwrap();
+        assert_eq!(version, v2);
+        assert_eq!(value, Bytes::from_static(b"secret-2"));
+
+        let value = manager.get_secret_version("cred", v1).await.unwrap();
+        assert_eq!(value, Bytes::from_static(b"secret-1"));
+        let versions = manager.list_secret_versions("cred").await.unwrap();
+        let ids = versions.iter().map(|v| v.version).collect::<Vec<_>>();
+        assert_eq!(ids, vec![v2, v1]);
+        let err = manager
+            .get_secret_version("cred", Uuid::from_u128(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound));
+
+        let err = manager.delete_secret_version("cred", v2).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        manager.delete_secret_version("cred", v1).await.unwrap();
+        let err = manager.get_secret_version("cred", v1).await.unwrap_err();
+        assert!(matches!(err, Error::NotFound));
+        let versions = manager.list_secret_versions("cred").await.unwrap();
+        assert_eq!(versions.len(), 1);
+
+        manager.delete_secret("cred").await.unwrap();
+        let err = manager.get_secret("cred").await.unwrap_err();
+        assert!(matches!(err, Error::NotFound));
+        let err = manager
+            .update_secret("cred", Bytes::from_static(b"secret-3"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound));
+    }
+
+    #[tokio::test]
+    async fn test_vault_concurrent_updates() {
+        let address = mock_vault(Secrets::default()).await;
+        let manager = VaultSecretManager::new(address, TOKEN);
+        let v1 = manager
+            .create_secret("cred", Bytes::from_static(b"secret-1"))
+            .await
+            .unwrap();
+
+        let (v2, v3) = tokio::join!(
+            manager.update_secret("cred", Bytes::from_static(b"secret-2")),
+            manager.update_secret("cred", Bytes::from_static(b"secret-3")),
+        );
+        let (v2, v3) = (v2.unwrap(), v3.unwrap());
+        let versions = manager.list_secret_versions("cred").await.unwrap();
+        let ids = versions.iter().map(|v| v.version).collect::<Vec<_>>();
+        assert_eq!(ids.len(), 3);
+        assert!([v1, v2, v3].iter().all(|id| ids.contains(id)));
+
+        manager.delete_secret_version("cred", v1).await.unwrap();
+        let versions = manager.list_secret_versions("cred").await.unwrap();
+        assert_eq!(versions.len(), 2);
+        assert!(manager.get_secret_version("cred", v2).await.is_ok());
+        assert!(manager.get_secret_version("cred", v3).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_vault_invalid_token() {
+        let address = mock_vault(Secrets::default()).await;
+        let manager = VaultSecretManager::new(address, "invalid");
+        let err = manager
+            .create_secret("cred", Bytes::from_static(b"secret"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Generic(msg) if msg.contains("permission denied")));
+    }
+}
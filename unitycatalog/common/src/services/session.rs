@@ -1,26 +1,49 @@
-use std::sync::Arc;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
 
 use datafusion::prelude::SessionContext;
 use delta_kernel::Version;
+use delta_kernel::schema::StructType;
 use delta_kernel_datafusion::{
     KernelContextExt as _, KernelExtensionConfig, ObjectStoreFactory, TableSnapshot,
 };
+use futures_util::{StreamExt, TryStreamExt};
+use lru::LruCache;
+use object_store::path::Path;
+use url::Url;
 
-use super::kernel::TableManager;
+#[cfg(feature = "tokio")]
+use super::kernel::create::create_delta_table;
+use super::kernel::history::read_commits;
+use super::kernel::iceberg::load_table;
+use super::kernel::infer::infer_schema;
+#[cfg(feature = "tokio")]
+use super::kernel::stats::read_table_detail;
+use super::kernel::{FileStats, IcebergTable, TableManager};
 use crate::services::location::StorageLocationUrl;
-use crate::tables::v1::DataSourceFormat;
+use crate::tables::v1::{DataSourceFormat, TableCommit, TableDetail};
 use crate::{Error, Result};
 
+/// Number of tables whose latest details are kept in memory.
+const TABLE_DETAIL_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
+
 pub struct KernelSession {
     ctx: SessionContext,
+    object_stores: Arc<dyn ObjectStoreFactory>,
+    /// Latest computed table details, keyed by table location.
+    table_details: Mutex<LruCache<Url, TableDetail>>,
 }
 
 impl KernelSession {
     pub fn new(object_store_factory: Arc<dyn ObjectStoreFactory>) -> Self {
-        let config =
-            KernelExtensionConfig::default().with_object_store_factory(object_store_factory);
+        let config = KernelExtensionConfig::default()
+            .with_object_store_factory(object_store_factory.clone());
         let ctx = SessionContext::new().enable_delta_kernel(config);
-        Self { ctx }
+        Self {
+            ctx,
+            object_stores: object_store_factory,
+            table_details: Mutex::new(LruCache::new(TABLE_DETAIL_CACHE_SIZE)),
+        }
     }
 }
 
@@ -43,4 +66,146 @@ impl TableManager for KernelSession {
             ))),
         }
     }
+
+    async fn infer_schema(
+        &self,
+        location: &StorageLocationUrl,
+        format: &DataSourceFormat,
+    ) -> Result<StructType> {
+        let store = self
+            .object_stores
+            .create_object_store(location.location())
+            .await?;
+        infer_schema(store, location, format).await
+    }
+
+    async fn read_iceberg_table(&self, location: &StorageLocationUrl) -> Result<IcebergTable> {
+        let store = self
+            .object_stores
+            .create_object_store(location.location())
+            .await?;
+        load_table(store.as_ref(), location).await
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn initialize_delta_table(
+        &self,
+        location: &StorageLocationUrl,
+        schema: &StructType,
+        partition_columns: &[String],
+    ) -> Result<Arc<dyn TableSnapshot>> {
+        let store = self
+            .object_stores
+            .create_object_store(location.location())
+            .await?;
+        create_delta_table(
+            store,
+            location.table_root(),
+            schema.clone(),
+            partition_columns.to_vec(),
+        )
+        .await?;
+        self.read_snapshot(location, &DataSourceFormat::Delta, None)
+            .await
+    }
+
+    #[cfg(not(feature = "tokio"))]
+    async fn initialize_delta_table(
+        &self,
+        _location: &StorageLocationUrl,
+        _schema: &StructType,
+        _partition_columns: &[String],
+    ) -> Result<Arc<dyn TableSnapshot>> {
+        Err(Error::generic(
+            "creating delta tables requires the tokio feature",
+        ))
+    }
+
+    async fn delete_table_data(&self, location: &StorageLocationUrl) -> Result<()> {
+        let prefix = Path::from_url_path(location.location().path())
+            .map_err(|e| Error::invalid_argument(e.to_string()))?;
+        // never wipe an entire bucket or container.
+        if prefix.parts().count() == 0 {
+            return Err(Error::invalid_argument(
+                "refusing to delete data at the root of a storage location",
+            ));
+        }
+        let store = self
+            .object_stores
+            .create_object_store(location.location())
+            .await?;
+        let locations = store
+            .list(Some(&prefix))
+            .map_ok(|meta| meta.location)
+            .boxed();
+        store
+            .delete_stream(locations)
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(())
+    }
+
+    async fn read_file_stats(
+        &self,
+        location: &StorageLocationUrl,
+        version: Version,
+    ) -> Result<FileStats> {
+        // the details include the file counts, and are cached per table version.
+        let detail = self.read_table_detail(location, version).await?;
+        Ok(FileStats {
+            num_files: detail.num_files,
+            size_in_bytes: detail.size_in_bytes,
+        })
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn read_table_detail(
+        &self,
+        location: &StorageLocationUrl,
+        version: Version,
+    ) -> Result<TableDetail> {
+        let cached = self
+            .table_details
+            .lock()
+            .map_err(|e| Error::generic(e.to_string()))?
+            .get(location.location())
+            .filter(|detail| detail.version == version as i64)
+            .cloned();
+        if let Some(detail) = cached {
+            return Ok(detail);
+        }
+        let store = self
+            .object_stores
+            .create_object_store(location.location())
+            .await?;
+        let detail = read_table_detail(store, location.table_root(), version).await?;
+        self.table_details
+            .lock()
+            .map_err(|e| Error::generic(e.to_string()))?
+            .put(location.location().clone(), detail.clone());
+        Ok(detail)
+    }
+
+    #[cfg(not(feature = "tokio"))]
+    async fn read_table_detail(
+        &self,
+        _location: &StorageLocationUrl,
+        _version: Version,
+    ) -> Result<TableDetail> {
+        Err(Error::generic(
+            "reading table details requires the tokio feature",
+        ))
+    }
+
+    async fn read_commits(
+        &self,
+        location: &StorageLocationUrl,
+        versions: &[Version],
+    ) -> Result<Vec<TableCommit>> {
+        let store = self
+            .object_stores
+            .create_object_store(location.location())
+            .await?;
+        read_commits(store, location, versions).await
+    }
 }
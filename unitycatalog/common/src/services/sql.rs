@@ -0,0 +1,188 @@
+//! Parsing and validation of SQL queries, e.g. the definitions of views.
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::Schema as ArrowSchema;
+use datafusion::catalog::{
+    CatalogProvider, MemoryCatalogProvider, MemorySchemaProvider, SchemaProvider,
+};
+use datafusion::datasource::empty::EmptyTable;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion::sql::parser::Statement;
+use datafusion::sql::sqlparser::ast::Statement as SqlStatement;
+use delta_kernel::engine::arrow_conversion::{TryFromArrow as _, TryFromKernel as _};
+use delta_kernel::schema::StructType;
+use itertools::Itertools;
+
+use crate::resources::ResourceName;
+use crate::{Error, Result};
+
+/// A parsed SQL query.
+///
+/// Table references in the query are resolved against a default catalog and schema,
+/// so `events` in a view `main.default.recent` refers to `main.default.events`.
+pub(crate) struct SqlQuery {
+    statement: Statement,
+    catalog_name: String,
+    schema_name: String,
+}
+
+impl SqlQuery {
+    /// Parse a query with the given default catalog and schema.
+    ///
+    /// Only read-only queries are accepted, other statements are rejected.
+    pub(crate) fn parse(sql: &str, catalog_name: &str, schema_name: &str) -> Result<Self> {
+        let ctx = SessionContext::new_with_config(session_config(catalog_name, schema_name));
+        let statement = ctx
+            .state()
+            .sql_to_statement(sql, "generic")
+            .map_err(|e| Error::invalid_argument(format!("invalid query: {e}")))?;
+        let is_query = match &statement {
+            Statement::Statement(s) => matches!(s.as_ref(), SqlStatement::Query(_)),
+            _ => false,
+        };
+        if !is_query {
+            return Err(Error::invalid_argument("statement must be a single query"));
+        }
+        Ok(Self {
+            statement,
+            catalog_name: catalog_name.to_string(),
+            schema_name: schema_name.to_string(),
+        })
+    }
+
+    pub(crate) fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// The fully qualified names of all tables referenced in the query.
+    pub(crate) fn table_references(&self) -> Result<Vec<ResourceName>> {
+        let ctx = SessionContext::new_with_config(self.config());
+        let references = ctx
+            .state()
+            .resolve_table_references(&self.statement)
+            .map_err(|e| Error::invalid_argument(format!("invalid query: {e}")))?;
+        Ok(references
+            .into_iter()
+            .map(|r| {
+                let r = r.resolve(&self.catalog_name, &self.schema_name);
+                ResourceName::new([r.catalog.as_ref(), r.schema.as_ref(), r.table.as_ref()])
+            })
+            .unique()
+            .collect())
+    }
+
+    /// Plan the query against the given tables and return the schema of its result.
+    ///
+    /// `tables` must contain the schemas of all tables returned by
+    /// [`table_references`](Self::table_references).
+    pub(crate) async fn output_schema(
+        &self,
+        tables: &[(ResourceName, StructType)],
+    ) -> Result<StructType> {
+        let ctx = SessionContext::new_with_config(self.config());
+        for (name, schema) in tables {
+            let [catalog_name, schema_name, table_name] = name.path() else {
+                return Err(Error::invalid_argument(format!(
+                    "invalid table reference: {name}"
+                )));
+            };
+            let catalog = match ctx.catalog(catalog_name) {
+                Some(catalog) => catalog,
+                None => {
+                    let catalog: Arc<dyn CatalogProvider> = Arc::new(MemoryCatalogProvider::new());
+                    ctx.register_catalog(catalog_name, catalog.clone());
+                    catalog
+                }
+            };
+            let schema_provider = match catalog.schema(schema_name) {
+                Some(schema) => schema,
+                None => {
+                    let schema: Arc<dyn SchemaProvider> = Arc::new(MemorySchemaProvider::new());
+                    catalog.register_schema(schema_name, schema.clone())?;
+                    schema
+                }
+            };
+            let arrow_schema = ArrowSchema::try_from_kernel(schema)
+                .map_err(|e| Error::invalid_argument(e.to_string()))?;
+            schema_provider.register_table(
+                table_name.clone(),
+                Arc::new(EmptyTable::new(Arc::new(arrow_schema))),
+            )?;
+        }
+        let plan = ctx
+            .state()
+            .statement_to_plan(self.statement.clone())
+            .await
+            .map_err(|e| Error::invalid_argument(format!("invalid query: {e}")))?;
+        StructType::try_from_arrow(plan.schema().as_arrow())
+            .map_err(|e| Error::invalid_argument(e.to_string()))
+    }
+
+    fn config(&self) -> SessionConfig {
+        session_config(&self.catalog_name, &self.schema_name)
+    }
+}
+
+fn session_config(catalog_name: &str, schema_name: &str) -> SessionConfig {
+    // only the tables referenced by the view should be visible when planning.
+    SessionConfig::new()
+        .with_create_default_catalog_and_schema(false)
+        .with_default_catalog_and_schema(catalog_name, schema_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use delta_kernel::schema::{DataType, StructField};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_view() {
+        let view = SqlQuery::parse(
+            "SELECT e.id, u.name FROM events e JOIN other.users u ON e.user_id = u.id",
+            "main",
+            "default",
+        )
+        .unwrap();
+        let references: HashSet<_> = view.table_references().unwrap().into_iter().collect();
+        assert_eq!(
+            references,
+            HashSet::from([
+                ResourceName::new(["main", "default", "events"]),
+                ResourceName::new(["main", "other", "users"]),
+            ])
+        );
+
+        assert!(SqlQuery::parse("DROP TABLE events", "main", "default").is_err());
+        assert!(SqlQuery::parse("SELEC id FROM events", "main", "default").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_output_schema() {
+        let view = SqlQuery::parse(
+            "SELECT id, count(*) AS total FROM events GROUP BY id",
+            "main",
+            "default",
+        )
+        .unwrap();
+        let events = StructType::new([
+            StructField::nullable("id", DataType::LONG),
+            StructField::nullable("name", DataType::STRING),
+        ]);
+        let tables = [(ResourceName::new(["main", "default", "events"]), events)];
+        let schema = view.output_schema(&tables).await.unwrap();
+        let fields: Vec<_> = schema
+            .fields()
+            .map(|f| (f.name().as_str(), f.data_type().clone()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![("id", DataType::LONG), ("total", DataType::LONG)]
+        );
+
+        let view = SqlQuery::parse("SELECT missing FROM events", "main", "default").unwrap();
+        assert!(view.output_schema(&tables).await.is_err());
+    }
+}
@@ -0,0 +1,241 @@
+//! The read-only `system` catalog.
+//!
+//! The tables in its `information_schema` are not stored in the resource store, but
+//! generated on the fly from the catalogs, schemas and tables the recipient can read.
+use delta_kernel::schema::{DataType, StructField, StructType};
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::api::tables::schema_to_columns;
+use crate::models::catalogs::v1::{CatalogInfo, CatalogType};
+use crate::models::schemas::v1::SchemaInfo;
+use crate::models::tables::v1::{TableInfo, TableType};
+use crate::{Error, ResourceIdent, ResourceName, ResourceStore, Result};
+
+/// Name of the system catalog.
+pub const SYSTEM_CATALOG: &str = "system";
+
+/// Name of the schema holding the metadata tables in the system catalog.
+pub const INFORMATION_SCHEMA: &str = "information_schema";
+
+/// Check if a catalog name refers to the system catalog.
+pub fn is_system_catalog(name: &str) -> bool {
+    name == SYSTEM_CATALOG
+}
+
+/// The catalog name of a fully qualified schema or table name.
+pub(crate) fn catalog_name(full_name: &str) -> &str {
+    full_name.split('.').next().unwrap_or_default()
+}
+
+/// Reject catalog names that would be hidden by the system catalog.
+///
+/// Names are compared case-insensitively, as stores may treat names that way.
+pub(crate) fn ensure_not_reserved(catalog_name: &str) -> Result<()> {
+    if catalog_name.eq_ignore_ascii_case(SYSTEM_CATALOG) {
+        return Err(Error::invalid_argument(format!(
+            "'{SYSTEM_CATALOG}' is a reserved catalog name"
+        )));
+    }
+    Ok(())
+}
+
+/// Find a stored catalog that is hidden by the system catalog.
+///
+/// Catalogs named `system` could be created before the name was reserved. Their
+/// contents are not reachable through the API until they are renamed in the store.
+pub async fn find_shadowed_catalog<T: ResourceStore>(handler: &T) -> Result<Option<CatalogInfo>> {
+    let ident = ResourceIdent::catalog(ResourceName::new([SYSTEM_CATALOG]));
+    match handler.get(&ident).await {
+        Ok((resource, _)) => Ok(Some(resource.try_into()?)),
+        Err(Error::NotFound) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Reject changes to the system catalog and its contents.
+pub(crate) fn ensure_writable(catalog_name: &str) -> Result<()> {
+    if is_system_catalog(catalog_name) {
+        return Err(Error::invalid_argument(format!(
+            "the '{SYSTEM_CATALOG}' catalog is read-only"
+        )));
+    }
+    Ok(())
+}
+
+/// The tables in the `information_schema` of the system catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AsRefStr, EnumIter, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum InformationSchemaTable {
+    Catalogs,
+    Schemas,
+    Tables,
+    Columns,
+    TablePrivileges,
+}
+
+impl InformationSchemaTable {
+    /// Find a table of the system catalog by its schema and table name.
+    pub fn find(schema_name: &str, table_name: &str) -> Option<Self> {
+        if schema_name != INFORMATION_SCHEMA {
+            return None;
+        }
+        table_name.parse().ok()
+    }
+
+    /// The schema of the rows in the table.
+    pub fn schema(&self) -> StructType {
+        let string = |name: &str| StructField::nullable(name, DataType::STRING);
+        let required = |name: &str| StructField::not_null(name, DataType::STRING);
+        let created = StructField::nullable("created", DataType::TIMESTAMP);
+        match self {
+            Self::Catalogs => StructType::new([
+                required("catalog_name"),
+                string("catalog_type"),
+                string("comment"),
+                string("owner"),
+                created,
+            ]),
+            Self::Schemas => StructType::new([
+                required("catalog_name"),
+                required("schema_name"),
+                string("comment"),
+                string("owner"),
+                created,
+            ]),
+            Self::Tables => StructType::new([
+                required("table_catalog"),
+                required("table_schema"),
+                required("table_name"),
+                string("table_type"),
+                string("data_source_format"),
+                string("storage_location"),
+                string("comment"),
+                string("owner"),
+                created,
+            ]),
+            Self::Columns => StructType::new([
+                required("table_catalog"),
+                required("table_schema"),
+                required("table_name"),
+                required("column_name"),
+                StructField::nullable("ordinal_position", DataType::INTEGER),
+                string("data_type"),
+                StructField::nullable("is_nullable", DataType::BOOLEAN),
+                StructField::nullable("partition_index", DataType::INTEGER),
+                string("comment"),
+            ]),
+            Self::TablePrivileges => StructType::new([
+                string("grantee"),
+                required("table_catalog"),
+                required("table_schema"),
+                required("table_name"),
+                required("privilege_type"),
+            ]),
+        }
+    }
+
+    /// The table as exposed by the tables API.
+    pub fn info(&self) -> Result<TableInfo> {
+        Ok(TableInfo {
+            name: self.as_ref().to_string(),
+            schema_name: INFORMATION_SCHEMA.to_string(),
+            catalog_name: SYSTEM_CATALOG.to_string(),
+            full_name: Some(format!(
+                "{SYSTEM_CATALOG}.{INFORMATION_SCHEMA}.{}",
+                self.as_ref()
+            )),
+            table_type: TableType::View as i32,
+            columns: schema_to_columns(&self.schema(), &[])?,
+            comment: Some(self.comment().to_string()),
+            ..Default::default()
+        })
+    }
+
+    fn comment(&self) -> &'static str {
+        match self {
+            Self::Catalogs => "Catalogs the current user can read.",
+            Self::Schemas => "Schemas the current user can read.",
+            Self::Tables => "Tables and views the current user can read.",
+            Self::Columns => "Columns of the tables the current user can read.",
+            Self::TablePrivileges => "Privileges the current user holds on tables.",
+        }
+    }
+}
+
+/// Find a table of the system catalog by its full name.
+pub fn find_system_table(full_name: &str) -> Option<InformationSchemaTable> {
+    let mut parts = full_name.splitn(3, '.');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(SYSTEM_CATALOG), Some(schema_name), Some(table_name)) => {
+            InformationSchemaTable::find(schema_name, table_name)
+        }
+        _ => None,
+    }
+}
+
+/// The system catalog as exposed by the catalogs API.
+pub fn system_catalog_info() -> CatalogInfo {
+    CatalogInfo {
+        name: SYSTEM_CATALOG.to_string(),
+        comment: Some("System catalog exposing metadata about the catalog.".to_string()),
+        catalog_type: Some(CatalogType::SystemCatalog as i32),
+        ..Default::default()
+    }
+}
+
+/// The information schema as exposed by the schemas API.
+pub fn information_schema_info() -> SchemaInfo {
+    SchemaInfo {
+        name: INFORMATION_SCHEMA.to_string(),
+        catalog_name: SYSTEM_CATALOG.to_string(),
+        full_name: Some(format!("{SYSTEM_CATALOG}.{INFORMATION_SCHEMA}")),
+        comment: Some("Metadata about catalogs, schemas and tables.".to_string()),
+        ..Default::default()
+    }
+}
+
+/// All tables of the system catalog as exposed by the tables API.
+pub fn system_tables() -> Result<Vec<TableInfo>> {
+    InformationSchemaTable::iter()
+        .map(|table| table.info())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_information_schema_tables() {
+        assert_eq!(
+            InformationSchemaTable::find(INFORMATION_SCHEMA, "table_privileges"),
+            Some(InformationSchemaTable::TablePrivileges)
+        );
+        assert_eq!(InformationSchemaTable::find("other", "tables"), None);
+        assert_eq!(
+            InformationSchemaTable::find(INFORMATION_SCHEMA, "views"),
+            None
+        );
+        assert_eq!(
+            find_system_table("system.information_schema.tables"),
+            Some(InformationSchemaTable::Tables)
+        );
+        assert_eq!(find_system_table("main.information_schema.tables"), None);
+
+        let tables = system_tables().unwrap();
+        assert_eq!(tables.len(), 5);
+        let columns = &tables[3];
+        assert_eq!(
+            columns.full_name.as_deref(),
+            Some("system.information_schema.columns")
+        );
+        assert_eq!(columns.columns[3].name, "column_name");
+        assert_eq!(columns.columns[3].nullable, Some(false));
+
+        assert!(ensure_writable("main").is_ok());
+        assert!(ensure_writable(SYSTEM_CATALOG).is_err());
+        assert!(ensure_not_reserved("main").is_ok());
+        assert!(ensure_not_reserved("SYSTEM").is_err());
+        assert_eq!(catalog_name("system.information_schema"), SYSTEM_CATALOG);
+    }
+}
@@ -0,0 +1,130 @@
+use bytes::Bytes;
+use object_store::path::Path;
+use object_store::{DynObjectStore, ObjectStore, PutPayload};
+
+use crate::models::credentials::v1::{
+    CredentialValidationResult, ValidateCredentialResponse, ValidationOperation,
+    ValidationResultStatus,
+};
+use crate::services::location::StorageLocationUrl;
+use crate::{Error, Result};
+
+/// Name prefix of the object written by the write probe.
+const PROBE_PREFIX: &str = "_unitycatalog_validation_";
+
+/// Probe a storage location for the operations required by an external location.
+///
+/// The probes run in order: list, read, write, delete. Read probes the first
+/// object found by the list probe and is skipped for empty locations. Write and delete
+/// are skipped for read-only locations. A failed probe does not abort subsequent probes,
+/// unless they depend on its outcome.
+pub(crate) async fn validate_location(
+    store: &DynObjectStore,
+    location: &StorageLocationUrl,
+    read_only: bool,
+) -> Result<ValidateCredentialResponse> {
+    let prefix = Path::from_url_path(location.location().path())
+        .map_err(|e| Error::invalid_argument(e.to_string()))?;
+    let mut results = Vec::with_capacity(4);
+
+    let (is_dir, first_object) = match store.list_with_delimiter(Some(&prefix)).await {
+        Ok(listing) => {
+            results.push(pass(ValidationOperation::List));
+            let is_dir = !listing.objects.is_empty() || !listing.common_prefixes.is_empty();
+            (Some(is_dir), listing.objects.into_iter().next())
+        }
+        Err(e) => {
+            results.push(fail(ValidationOperation::List, e));
+            (None, None)
+        }
+    };
+
+    match first_object {
+        Some(meta) => match store.get(&meta.location).await {
+            Ok(_) => results.push(pass(ValidationOperation::Read)),
+            Err(e) => results.push(fail(ValidationOperation::Read, e)),
+        },
+        None => results.push(skip(
+            ValidationOperation::Read,
+            "no objects found below the location",
+        )),
+    }
+
+    if read_only {
+        results.push(skip(ValidationOperation::Write, "location is read-only"));
+        results.push(skip(ValidationOperation::Delete, "location is read-only"));
+        return Ok(ValidateCredentialResponse { is_dir, results });
+    }
+
+    let probe = prefix.child(format!(
+        "{}{}",
+        PROBE_PREFIX,
+        chrono::Utc::now().timestamp_micros()
+    ));
+    match store
+        .put(&probe, PutPayload::from(Bytes::from_static(b"ok")))
+        .await
+    {
+        Ok(_) => {
+            results.push(pass(ValidationOperation::Write));
+            match store.delete(&probe).await {
+                Ok(_) => results.push(pass(ValidationOperation::Delete)),
+                Err(e) => results.push(fail(ValidationOperation::Delete, e)),
+            }
+        }
+        Err(e) => {
+            results.push(fail(ValidationOperation::Write, e));
+            results.push(skip(ValidationOperation::Delete, "write probe failed"));
+        }
+    }
+
+    Ok(ValidateCredentialResponse { is_dir, results })
+}
+
+/// Convert a validation response into an error if any of the probes failed.
+pub(crate) fn ensure_valid(response: &ValidateCredentialResponse) -> Result<()> {
+    let failures: Vec<_> = response
+        .results
+        .iter()
+        .filter(|r| r.result() == ValidationResultStatus::Fail)
+        .map(|r| {
+            format!(
+                "{}: {}",
+                r.operation().as_str_name(),
+                r.message.as_deref().unwrap_or_default()
+            )
+        })
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::invalid_argument(format!(
+            "Failed to validate storage location: {}",
+            failures.join("; ")
+        )))
+    }
+}
+
+fn pass(operation: ValidationOperation) -> CredentialValidationResult {
+    CredentialValidationResult {
+        operation: operation as i32,
+        result: ValidationResultStatus::Pass as i32,
+        message: None,
+    }
+}
+
+fn fail(operation: ValidationOperation, error: object_store::Error) -> CredentialValidationResult {
+    CredentialValidationResult {
+        operation: operation as i32,
+        result: ValidationResultStatus::Fail as i32,
+        message: Some(error.to_string()),
+    }
+}
+
+fn skip(operation: ValidationOperation, reason: &str) -> CredentialValidationResult {
+    CredentialValidationResult {
+        operation: operation as i32,
+        result: ValidationResultStatus::Skip as i32,
+        message: Some(reason.to_string()),
+    }
+}
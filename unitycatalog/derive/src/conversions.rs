@@ -26,11 +26,14 @@ impl Parse for ObjectDefs {
 /// ```ignore
 /// TypeName, Label, [Path, Names], optional
 /// ```
+///
+/// Path names are fields of the object, or method calls like `name()` returning
+/// a string for names that are derived from several fields.
 pub struct ObjectDef {
     pub ty: Type,
     pub label: Expr,
     pub name: Ident,
-    pub path_names: Vec<Ident>,
+    pub path_names: Vec<Expr>,
     pub is_optional: bool,
 }
 
@@ -45,7 +48,7 @@ impl Parse for ObjectDef {
 
         let content;
         syn::bracketed!(content in input);
-        let field_defs = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+        let field_defs = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
         let path_names: Vec<_> = field_defs.into_iter().collect();
 
         let is_optional = if input.peek(Token![,]) {
@@ -186,7 +189,7 @@ pub(crate) fn resource_impl(obj: &ObjectDef) -> proc_macro2::TokenStream {
                 &#label
             }
             fn resource_name(&self) -> ResourceName {
-                ResourceName::new([#(&self.#path_names),*])
+                ResourceName::new([#(AsRef::<str>::as_ref(&self.#path_names)),*])
             }
             fn resource_ref(&self) -> ResourceRef {
                 #resource_ref
@@ -55,6 +55,12 @@ pub fn parse_column_name(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 /// Names extracted from path parameters must be specified in hierarchical order, e.g.:
 /// share -> schema -> table
 /// catalog -> schema -> table
+///
+/// Routes that do not follow the resource hierarchy can be declared explicitly
+/// with `at "foos/{name}/bars"`. The route is used to generate the client.
+///
+/// Requests sent as a JSON body may declare the fields identifying the secured
+/// resource with `name: body as String`.
 #[proc_macro]
 pub fn rest_handlers(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as HandlerParams);
@@ -11,15 +11,20 @@ mod kw {
     ::syn::custom_keyword!(with);
     ::syn::custom_keyword!(path);
     ::syn::custom_keyword!(query);
+    ::syn::custom_keyword!(body);
+    ::syn::custom_keyword!(at);
 }
 
 /// Source of a field, e.g. path or query parameters
 pub enum FieldSource {
     Path,
     Query,
+    /// A field of the JSON body, only used to identify the secured resource.
+    Body,
 }
 
 /// Single “with” field definition, e.g.  foo: path as u32  or  bar: query as String
+/// or  baz: body as String
 pub struct FieldDef {
     pub name: Ident,
     pub source: FieldSource,
@@ -36,8 +41,11 @@ impl Parse for FieldDef {
         } else if input.peek(kw::query) {
             input.parse::<kw::query>()?;
             FieldSource::Query
+        } else if input.peek(kw::body) {
+            input.parse::<kw::body>()?;
+            FieldSource::Body
         } else {
-            return Err(input.error("expected `path` or `query`"));
+            return Err(input.error("expected `path`, `query` or `body`"));
         };
         input.parse::<Token![as]>()?;
         let ty = input.parse()?;
@@ -49,12 +57,15 @@ impl Parse for FieldDef {
 ///   CreateFooRequest with [...], FooResponse, FooPermission;
 ///   or
 ///   DeleteFooRequest;
+///   or, for routes that do not follow the resource hierarchy
+///   ListFooBarsRequest, Foo, Read, ListFooBarsResponse with [...] at "foos/{name}/bars";
 pub struct HandlerDef {
     pub request_type: Type,
     pub response_type: Option<Type>,
     pub fields: Vec<FieldDef>,
     pub permission: Type,
     pub resource: Type,
+    pub route: Option<LitStr>,
 }
 
 impl Parse for HandlerDef {
@@ -83,12 +94,20 @@ impl Parse for HandlerDef {
             fields = field_defs.into_iter().collect();
         }
 
+        // Parse optional explicit route
+        let mut route = None;
+        if input.peek(kw::at) {
+            input.parse::<kw::at>()?;
+            route = Some(input.parse()?);
+        }
+
         Ok(HandlerDef {
             request_type,
             response_type,
             fields,
             permission,
             resource,
+            route,
         })
     }
 }
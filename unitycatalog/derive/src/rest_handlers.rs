@@ -97,21 +97,28 @@ pub fn to_client(handler: &HandlerDef, path_segments: &[String]) -> proc_macro2:
         });
     };
 
-    let used_segments: Vec<_> = path_segments
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, it)| {
-            if idx < path_names.len() || idx == 0 {
-                Some(it.clone())
-            } else {
-                None
+    let template = match &handler.route {
+        // path parameters in explicit routes are substituted in order of declaration
+        Some(route) => route_template(&route.value()),
+        None => {
+            let used_segments: Vec<_> = path_segments
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, it)| {
+                    if idx < path_names.len() || idx == 0 {
+                        Some(it.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            let mut template = used_segments.join("/{}/");
+            if !path_names.is_empty() {
+                template.push_str("/{}");
             }
-        })
-        .collect();
-    let mut template = used_segments.join("/{}/");
-    if !path_names.is_empty() {
-        template.push_str("/{}");
-    }
+            template
+        }
+    };
 
     let template = LitStr::new(&template, Span::call_site());
 
@@ -177,6 +184,21 @@ pub fn to_client(handler: &HandlerDef, path_segments: &[String]) -> proc_macro2:
                 }
             }
         }
+        (RequestType::Action, Some(response_type)) => {
+            quote! {
+                pub async fn #fn_name(
+                    &self,
+                    req: &#request_type,
+                ) -> Result<#response_type> {
+                    #url
+                    #(#query_params)*
+                    let result = self.client.post(url).send().await?;
+                    result.error_for_status_ref()?;
+                    let result = result.bytes().await?;
+                    Ok(::serde_json::from_slice(&result)?)
+                }
+            }
+        }
         (RequestType::Delete, None) => {
             quote! {
                 pub async fn #fn_name(
@@ -224,7 +246,7 @@ pub fn to_request_impl(handler: &HandlerDef) -> proc_macro2::TokenStream {
                 }
             }
         }
-        RequestType::Get | RequestType::Delete => {
+        RequestType::Get | RequestType::Delete | RequestType::Action => {
             // Generate path parameter implementation
             generate_path_query_request_impl(request_type, &handler.fields, false)
         }
@@ -304,6 +326,24 @@ pub(crate) fn to_action(handler: &HandlerDef) -> proc_macro2::TokenStream {
     }
 }
 
+/// Converts an axum style route (`foos/{name}/bars`) into a `format!` template.
+fn route_template(route: &str) -> String {
+    let mut template = String::with_capacity(route.len());
+    let mut in_param = false;
+    for c in route.trim_start_matches('/').chars() {
+        match c {
+            '{' => {
+                in_param = true;
+                template.push_str("{}");
+            }
+            '}' => in_param = false,
+            _ if in_param => {}
+            _ => template.push(c),
+        }
+    }
+    template
+}
+
 /// Extracts the final segment of a Type’s path, e.g. SomeModule::FooBar => "FooBar".
 pub fn get_type_name(ty: &Type) -> Option<String> {
     if let Type::Path(type_path) = ty {
@@ -333,6 +373,8 @@ enum RequestType {
     Update,
     Get,
     Delete,
+    /// A state transition on an existing resource, sent as a POST without a body.
+    Action,
 }
 
 fn get_request_type(type_name: &str) -> RequestType {
@@ -342,9 +384,14 @@ fn get_request_type(type_name: &str) -> RequestType {
     //   “UpdateBazRequest” => Update
     //   “GetSomethingRequest” => Get
     //   “DeleteSomethingRequest” => Delete
+    //   “UndropSomethingRequest” => Action
+    //   “ValidateSomethingRequest” => Create
     if type_name.starts_with("List") {
         RequestType::List
-    } else if type_name.starts_with("Create") || type_name.starts_with("Generate") {
+    } else if type_name.starts_with("Create")
+        || type_name.starts_with("Generate")
+        || type_name.starts_with("Validate")
+    {
         RequestType::Create
     } else if type_name.starts_with("Update") {
         RequestType::Update
@@ -352,6 +399,8 @@ fn get_request_type(type_name: &str) -> RequestType {
         RequestType::Get
     } else if type_name.starts_with("Delete") {
         RequestType::Delete
+    } else if type_name.starts_with("Undrop") {
+        RequestType::Action
     } else {
         // Default to Get if pattern doesn't match
         RequestType::Get
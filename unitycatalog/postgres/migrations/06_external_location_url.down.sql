@@ -0,0 +1 @@
+drop index if exists objects_external_location_url_index;
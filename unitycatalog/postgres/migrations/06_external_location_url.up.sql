@@ -0,0 +1,4 @@
+-- index external locations by their normalized url to support longest prefix lookups.
+create index objects_external_location_url_index on objects ((rtrim(properties ->> 'url', '/')))
+where
+  label = 'external_location_info';
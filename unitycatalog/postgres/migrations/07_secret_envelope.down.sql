@@ -0,0 +1,3 @@
+alter table secrets
+drop column if exists key_id,
+drop column if exists data_key;
@@ -0,0 +1,6 @@
+-- envelope encryption: each secret value is encrypted with its own data key,
+-- which is stored encrypted with the key-encryption key identified by key_id.
+-- rows without key_id were written before envelope encryption and need to be re-keyed.
+alter table secrets
+add column key_id Text,
+add column data_key bytea;
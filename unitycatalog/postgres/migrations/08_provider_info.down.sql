@@ -0,0 +1,4 @@
+-- postgres cannot drop values from an enum, so only the providers are removed.
+delete from objects
+where
+  label = 'provider_info';
@@ -0,0 +1,2 @@
+-- providers of remote delta sharing servers.
+alter type object_label add value if not exists 'provider_info';
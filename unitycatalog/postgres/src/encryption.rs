@@ -0,0 +1,246 @@
+//! Envelope encryption for secrets.
+//!
+//! Every secret is encrypted with its own randomly generated data key. The data key
+//! is in turn encrypted with a key-encryption key (KEK) and stored next to the secret,
+//! together with the id of the KEK. Rotating the KEK therefore only requires re-wrapping
+//! the data keys, the encrypted secret values are left untouched.
+//!
+//! Both ciphertexts are authenticated with associated data identifying the secret
+//! they belong to, so ciphertexts cannot be moved to another secret or version.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use crate::{Error, Result};
+
+/// Size of the AES-GCM nonce prepended to every ciphertext.
+const NONCE_SIZE: usize = 12;
+
+/// A named 256-bit key-encryption key.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    id: String,
+    cipher: Aes256Gcm,
+}
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EncryptionKey {
+    /// Create a new key from raw key material.
+    ///
+    /// The key must be exactly 32 bytes long.
+    pub fn try_new(id: impl Into<String>, key: &[u8]) -> Result<Self> {
+        if key.len() != 32 {
+            return Err(Error::encryption("encryption key must be 32 bytes long"));
+        }
+        Ok(Self {
+            id: id.into(),
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        })
+    }
+
+    /// Create a new key from base64 encoded key material.
+    pub fn from_base64(id: impl Into<String>, encoded: &str) -> Result<Self> {
+        let key = STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| Error::encryption(format!("invalid encryption key: {e}")))?;
+        Self::try_new(id, &key)
+    }
+
+    /// Read base64 encoded key material from a file.
+    pub fn from_file(id: impl Into<String>, path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let encoded = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            Error::encryption(format!(
+                "failed to read encryption key from '{}': {e}",
+                path.as_ref().display()
+            ))
+        })?;
+        Self::from_base64(id, &encoded)
+    }
+
+    /// Generate a new random key.
+    pub fn generate(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            cipher: Aes256Gcm::new(&Aes256Gcm::generate_key(OsRng)),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// A secret value encrypted with a data key, which in turn is encrypted with a KEK.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SealedSecret {
+    /// Id of the key-encryption key used to encrypt the data key.
+    pub(crate) key_id: String,
+    /// The encrypted data key.
+    pub(crate) data_key: Vec<u8>,
+    /// The encrypted secret value.
+    pub(crate) value: Vec<u8>,
+}
+
+/// The set of key-encryption keys known to a store.
+///
+/// New secrets are always encrypted with the current key. Previous keys are only
+/// used to decrypt data keys that have not been re-wrapped yet.
+#[derive(Debug, Clone)]
+pub struct KeyRing {
+    current: EncryptionKey,
+    previous: Vec<EncryptionKey>,
+}
+
+impl KeyRing {
+    pub fn new(current: EncryptionKey) -> Self {
+        Self {
+            current,
+            previous: Vec::new(),
+        }
+    }
+
+    /// Add a previous key that may still be referenced by stored secrets.
+    pub fn with_previous(mut self, key: EncryptionKey) -> Self {
+        self.previous.push(key);
+        self
+    }
+
+    /// Id of the key used to encrypt new data keys.
+    pub fn current_id(&self) -> &str {
+        self.current.id()
+    }
+
+    fn key(&self, key_id: &str) -> Result<&EncryptionKey> {
+        std::iter::once(&self.current)
+            .chain(self.previous.iter())
+            .find(|key| key.id == key_id)
+            .ok_or_else(|| Error::encryption(format!("unknown encryption key '{key_id}'")))
+    }
+
+    /// Encrypt a secret value with a new data key.
+    ///
+    /// The associated data `aad` must be passed again to open the secret.
+    pub(crate) fn seal(&self, value: &[u8], aad: &[u8]) -> Result<SealedSecret> {
+        let data_key = Aes256Gcm::generate_key(OsRng);
+        let value = encrypt(&Aes256Gcm::new(&data_key), value, aad)?;
+        let data_key = encrypt(&self.current.cipher, data_key.as_slice(), aad)?;
+        Ok(SealedSecret {
+            key_id: self.current.id.clone(),
+            data_key,
+            value,
+        })
+    }
+
+    /// Decrypt a sealed secret value.
+    pub(crate) fn open(
+        &self,
+        key_id: &str,
+        data_key: &[u8],
+        value: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        let data_key = decrypt(&self.key(key_id)?.cipher, data_key, aad)?;
+        let cipher = Aes256Gcm::new_from_slice(&data_key)
+            .map_err(|_| Error::encryption("invalid data key"))?;
+        decrypt(&cipher, value, aad)
+    }
+
+    /// Re-encrypt a data key with the current key.
+    pub(crate) fn rewrap(&self, key_id: &str, data_key: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let data_key = decrypt(&self.key(key_id)?.cipher, data_key, aad)?;
+        encrypt(&self.current.cipher, &data_key, aad)
+    }
+}
+
+fn encrypt(cipher: &Aes256Gcm, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .map_err(|_| Error::encryption("failed to encrypt secret"))?;
+    let mut data = nonce.to_vec();
+    data.extend(ciphertext);
+    Ok(data)
+}
+
+fn decrypt(cipher: &Aes256Gcm, data: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    if data.len() < NONCE_SIZE {
+        return Err(Error::encryption("encrypted data is too short"));
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+    cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad,
+            },
+        )
+        .map_err(|_| Error::encryption("failed to decrypt secret"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seal_and_open() {
+        let keys = KeyRing::new(EncryptionKey::generate("v1"));
+        let sealed = keys.seal(b"secret", b"row-1").unwrap();
+        assert_eq!(sealed.key_id, "v1");
+        assert_ne!(sealed.value, b"secret");
+        let value = keys
+            .open(&sealed.key_id, &sealed.data_key, &sealed.value, b"row-1")
+            .unwrap();
+        assert_eq!(value, b"secret");
+
+        // ciphertexts are bound to their associated data
+        assert!(
+            keys.open(&sealed.key_id, &sealed.data_key, &sealed.value, b"row-2")
+                .is_err()
+        );
+
+        let other = KeyRing::new(EncryptionKey::generate("v1"));
+        assert!(
+            other
+                .open(&sealed.key_id, &sealed.data_key, &sealed.value, b"row-1")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rewrap_data_key() {
+        let old = EncryptionKey::generate("v1");
+        let sealed = KeyRing::new(old.clone()).seal(b"secret", b"row").unwrap();
+
+        let keys = KeyRing::new(EncryptionKey::generate("v2")).with_previous(old);
+        let data_key = keys
+            .rewrap(&sealed.key_id, &sealed.data_key, b"row")
+            .unwrap();
+        let value = keys.open("v2", &data_key, &sealed.value, b"row").unwrap();
+        assert_eq!(value, b"secret");
+
+        assert!(keys.rewrap("v0", &sealed.data_key, b"row").is_err());
+    }
+
+    #[test]
+    fn key_from_base64() {
+        let encoded = STANDARD.encode([7u8; 32]);
+        assert!(EncryptionKey::from_base64("v1", &encoded).is_ok());
+        assert!(EncryptionKey::from_base64("v1", "c2hvcnQ=").is_err());
+    }
+}
@@ -25,6 +25,9 @@ pub enum Error {
 
     #[error("Already exists: '{0}'")]
     AlreadyExists(String),
+
+    #[error("Encryption error: {0}")]
+    Encryption(String),
 }
 
 impl Error {
@@ -35,6 +38,10 @@ impl Error {
     pub fn generic(msg: impl Into<String>) -> Self {
         Error::Generic(msg.into())
     }
+
+    pub fn encryption(msg: impl Into<String>) -> Self {
+        Error::Encryption(msg.into())
+    }
 }
 
 impl From<sqlx::Error> for Error {
@@ -69,6 +76,7 @@ impl From<Error> for CommonError {
             Error::Generic(e) => CommonError::Generic(e),
             Error::EntityNotFound(_) => CommonError::NotFound,
             Error::AlreadyExists(_) => CommonError::NotAllowed,
+            Error::Encryption(e) => CommonError::Generic(e),
         }
     }
 }
@@ -4,6 +4,8 @@
 //!
 //! [TAO]: https://www.usenix.org/system/files/conference/atc13/atc13-bronson.pdf
 
+use std::sync::Arc;
+
 use sqlx::PgPool;
 use sqlx::migrate::Migrator;
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
@@ -11,6 +13,7 @@ use unitycatalog_common::{ResourceIdent, ResourceRef};
 use uuid::Uuid;
 
 use super::{Association, AssociationLabel, Object, ObjectLabel};
+use crate::KeyRing;
 use crate::constants::MAX_PAGE_SIZE;
 use crate::pagination::V1PaginateToken;
 use crate::resources::IdentRefs as _;
@@ -21,11 +24,31 @@ static MIGRATOR: Migrator = sqlx::migrate!();
 #[derive(Clone)]
 pub struct Store {
     pub(crate) pool: PgPool,
+    keys: Option<Arc<KeyRing>>,
 }
 
 impl Store {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self { pool, keys: None }
+    }
+
+    /// Set the keys used to encrypt secrets.
+    ///
+    /// Without encryption keys, secrets written before envelope encryption can still
+    /// be read, while all other secret operations fail.
+    pub fn with_encryption_keys(mut self, keys: KeyRing) -> Self {
+        self.keys = Some(Arc::new(keys));
+        self
+    }
+
+    pub(crate) fn encryption_keys(&self) -> Result<&KeyRing> {
+        self.keys
+            .as_deref()
+            .ok_or_else(|| crate::Error::encryption("no encryption key configured for secrets"))
+    }
+
+    pub(crate) fn optional_encryption_keys(&self) -> Option<&KeyRing> {
+        self.keys.as_deref()
     }
 
     pub async fn connect(url: impl AsRef<str>) -> Result<Self> {
@@ -169,6 +192,8 @@ impl Store {
     /// ## Parameters
     /// - `label`: The label of the objects.
     /// - `namespace`: The namespace of the objects.
+    /// - `name_patterns`: `ILIKE` patterns for the name segments at the same positions.
+    ///   Segments without a pattern match any name.
     /// - `page_token`: The page token.
     /// - `max_page_size`: The maximum page size.
     ///
@@ -178,6 +203,7 @@ impl Store {
         &self,
         label: &ObjectLabel,
         namespace: &[String],
+        name_patterns: &[Option<String>],
         page_token: Option<&str>,
         max_page_size: Option<usize>,
     ) -> Result<(Vec<Object>, Option<String>)> {
@@ -190,7 +216,29 @@ impl Store {
             .map(|PaginateToken::V1(V1PaginateToken { created_at, id })| (created_at, id))
             .unzip();
         let mut conn = self.pool.acquire().await?;
-        list_objects(label, namespace, max_page_size, token_id, &mut conn).await
+        list_objects(
+            label,
+            namespace,
+            name_patterns,
+            max_page_size,
+            token_id,
+            &mut conn,
+        )
+        .await
+    }
+
+    /// Find the external location with the longest url among the given urls.
+    ///
+    /// The urls must be normalized, i.e. without trailing slashes.
+    ///
+    /// ## Parameters
+    /// - `urls`: The candidate urls of the external location.
+    ///
+    /// ## Returns
+    /// The matching external location object, if any.
+    pub async fn find_external_location(&self, urls: &[String]) -> Result<Option<Object>> {
+        let mut conn = self.pool.acquire().await?;
+        find_external_location(urls, &mut conn).await
     }
 
     /// Add an association to the store.
@@ -495,6 +543,7 @@ async fn delete_object(
 async fn list_objects(
     label: &ObjectLabel,
     namespace: &[String],
+    name_patterns: &[Option<String>],
     max_page_size: usize,
     token_id: Option<&Uuid>,
     conn: &mut sqlx::pool::PoolConnection<sqlx::Postgres>,
@@ -513,6 +562,11 @@ async fn list_objects(
                 WHERE label = $1
                     AND ( $2 = 0 OR name[1:$2] = $3)
                     AND ( id < $4 OR $4 IS NULL )
+                    AND NOT EXISTS (
+                        SELECT 1
+                        FROM unnest(name, $6::text[]) AS p(segment, pattern)
+                        WHERE segment NOT ILIKE pattern
+                    )
                 ORDER BY id DESC
                 LIMIT $5
                 "#,
@@ -520,7 +574,8 @@ async fn list_objects(
         namespace.len() as i32,
         namespace,
         token_id,
-        max_page_size as i64
+        max_page_size as i64,
+        name_patterns as &[Option<String>]
     )
     .fetch_all(&mut **conn)
     .await?;
@@ -540,6 +595,33 @@ async fn list_objects(
     Ok((objects, next))
 }
 
+async fn find_external_location(
+    urls: &[String],
+    conn: &mut sqlx::pool::PoolConnection<sqlx::Postgres>,
+) -> Result<Option<Object>, crate::Error> {
+    let obj = sqlx::query_as!(
+        Object,
+        r#"
+                SELECT
+                    id,
+                    label AS "label: ObjectLabel",
+                    name,
+                    properties,
+                    created_at,
+                    updated_at
+                FROM objects
+                WHERE label = 'external_location_info'
+                  AND rtrim(properties ->> 'url', '/') = ANY($1)
+                ORDER BY length(rtrim(properties ->> 'url', '/')) DESC
+                LIMIT 1
+                "#,
+        urls
+    )
+    .fetch_optional(&mut **conn)
+    .await?;
+    Ok(obj)
+}
+
 async fn get_associations(
     from_id: &Uuid,
     label: &AssociationLabel,
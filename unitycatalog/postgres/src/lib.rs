@@ -1,7 +1,9 @@
+pub use crate::encryption::{EncryptionKey, KeyRing};
 pub use crate::error::{Error, Result};
 pub use graph::*;
 
 mod constants;
+mod encryption;
 mod error;
 mod graph;
 mod pagination;
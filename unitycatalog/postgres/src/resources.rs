@@ -1,8 +1,9 @@
 use itertools::Itertools;
 use unitycatalog_common::models::PropertyMap;
+use unitycatalog_common::services::StorageLocationUrl;
 use unitycatalog_common::{
-    AssociationLabel, EMPTY_RESOURCE_NAME, Error, Object, ObjectLabel, Resource, ResourceIdent,
-    ResourceName, ResourceRef, ResourceStore, ResourceStoreReader, Result,
+    AssociationLabel, EMPTY_RESOURCE_NAME, Error, NamePattern, Object, ObjectLabel, Resource,
+    ResourceIdent, ResourceName, ResourceRef, ResourceStore, ResourceStoreReader, Result,
 };
 
 use crate::GraphStore;
@@ -57,7 +58,7 @@ impl ResourceStoreReader for GraphStore {
     ) -> Result<(Vec<Resource>, Option<String>)> {
         let namespace = namespace.unwrap_or_else(|| &EMPTY_RESOURCE_NAME);
         let objects = self
-            .list_objects(label, namespace, page_token.as_deref(), max_results)
+            .list_objects(label, namespace, &[], page_token.as_deref(), max_results)
             .await?;
         Ok((
             objects
@@ -68,6 +69,55 @@ impl ResourceStoreReader for GraphStore {
             objects.1,
         ))
     }
+
+    /// List resources with names matching the given patterns.
+    ///
+    /// The patterns are applied as `ILIKE` filters on the name segments, so pages
+    /// only contain matching resources.
+    async fn list_matching(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        patterns: &[Option<NamePattern>],
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        let namespace = namespace.unwrap_or_else(|| &EMPTY_RESOURCE_NAME);
+        let patterns = patterns
+            .iter()
+            .map(|p| p.as_ref().map(|p| p.as_like().to_string()))
+            .collect_vec();
+        let (objects, next_page_token) = self
+            .list_objects(
+                label,
+                namespace,
+                &patterns,
+                page_token.as_deref(),
+                max_results,
+            )
+            .await?;
+        Ok((
+            objects
+                .into_iter()
+                .map(|object| object.try_into())
+                .try_collect()?,
+            next_page_token,
+        ))
+    }
+
+    /// Find the external location that contains a storage location.
+    ///
+    /// Uses the url index on external locations to look up all candidate prefixes at once.
+    async fn find_external_location(
+        &self,
+        location: &StorageLocationUrl,
+    ) -> Result<(Resource, ResourceRef)> {
+        let object = GraphStore::find_external_location(self, &location.prefixes())
+            .await?
+            .ok_or(Error::NotFound)?;
+        let id = ResourceRef::Uuid(object.id);
+        Ok((object.try_into()?, id))
+    }
 }
 
 #[async_trait::async_trait]
@@ -1,73 +1,129 @@
 use bytes::Bytes;
 use unitycatalog_common::Result;
-use unitycatalog_common::services::SecretManager;
+use unitycatalog_common::services::{SecretManager, SecretVersion};
 use uuid::Uuid;
 
-use crate::GraphStore;
+use crate::{GraphStore, KeyRing};
 
-const DUMMY: &str = "dummy";
+/// Symmetric key used to encrypt secrets before envelope encryption was introduced.
+///
+/// Such secrets remain readable until they are migrated by [`GraphStore::rekey_secrets`].
+const LEGACY_KEY: &str = "dummy";
+
+/// Associated data that binds an encrypted secret to its name and version.
+fn secret_aad(secret_name: &str, version: &Uuid) -> Vec<u8> {
+    // names are compared case insensitively by the database.
+    let mut aad = version.as_bytes().to_vec();
+    aad.extend(secret_name.to_lowercase().as_bytes());
+    aad
+}
+
+#[derive(sqlx::FromRow)]
+struct Secret {
+    id: Uuid,
+    name: String,
+    key_id: Option<String>,
+    data_key: Option<Vec<u8>>,
+    value: Vec<u8>,
+    /// The decrypted value of secrets written before envelope encryption.
+    legacy_value: Option<String>,
+}
+
+impl Secret {
+    /// Decrypt the secret value.
+    ///
+    /// Legacy secrets can be read without encryption keys.
+    fn open(self, keys: Option<&KeyRing>) -> crate::Result<Bytes> {
+        match (&self.key_id, &self.data_key, self.legacy_value) {
+            (Some(key_id), Some(data_key), _) => {
+                let keys = keys.ok_or_else(|| {
+                    crate::Error::encryption("no encryption key configured for secrets")
+                })?;
+                let aad = secret_aad(&self.name, &self.id);
+                Ok(keys.open(key_id, data_key, &self.value, &aad)?.into())
+            }
+            (None, _, Some(value)) => Ok(value.into()),
+            _ => Err(crate::Error::encryption(format!(
+                "secret '{}' has no data key",
+                self.name
+            ))),
+        }
+    }
+}
 
 #[async_trait::async_trait]
 impl SecretManager for GraphStore {
     async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)> {
+        let keys = self.optional_encryption_keys();
         let mut conn = self.pool.acquire().await.map_err(crate::Error::from)?;
-        #[derive(sqlx::FromRow)]
-        struct Secret {
-            id: Uuid,
-            value: Option<String>,
-        }
-        let res = sqlx::query_as!(
+        let secret = sqlx::query_as!(
             Secret,
             r#"
-            SELECT id, pgp_sym_decrypt(value, $1) as value FROM secrets
-            WHERE name = $2
+            SELECT
+                id,
+                name,
+                key_id,
+                data_key,
+                value,
+                CASE WHEN key_id IS NULL THEN pgp_sym_decrypt(value, $2) END AS legacy_value
+            FROM secrets
+            WHERE name = $1
             ORDER BY id DESC
             LIMIT 1
             "#,
-            DUMMY,
             secret_name,
+            LEGACY_KEY,
         )
         .fetch_one(&mut *conn)
         .await
         .map_err(crate::Error::from)?;
-        if res.value.is_none() {
-            return Err(crate::Error::entity_not_found(secret_name).into());
-        }
-        Ok((res.id, bytes::Bytes::from(res.value.unwrap())))
+        Ok((secret.id, secret.open(keys)?))
     }
 
     async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes> {
+        let keys = self.optional_encryption_keys();
         let mut conn = self.pool.acquire().await.map_err(crate::Error::from)?;
-        let value: Option<String> = sqlx::query_scalar!(
+        let secret = sqlx::query_as!(
+            Secret,
             r#"
-            SELECT pgp_sym_decrypt(value, $2) FROM secrets
-            WHERE name = $1 AND id = $3
+            SELECT
+                id,
+                name,
+                key_id,
+                data_key,
+                value,
+                CASE WHEN key_id IS NULL THEN pgp_sym_decrypt(value, $3) END AS legacy_value
+            FROM secrets
+            WHERE name = $1 AND id = $2
             "#,
             secret_name,
-            DUMMY,
             version,
+            LEGACY_KEY,
         )
         .fetch_one(&mut *conn)
         .await
         .map_err(crate::Error::from)?;
-        if let Some(value) = value {
-            return Ok(bytes::Bytes::from(value));
-        }
-        Err(crate::Error::entity_not_found(secret_name).into())
+        Ok(secret.open(keys)?)
     }
 
     async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        // the version is generated up front to bind the ciphertext to it.
+        let id = Uuid::now_v7();
+        let sealed = self
+            .encryption_keys()?
+            .seal(&secret_value, &secret_aad(secret_name, &id))?;
         let mut txn = self.pool.begin().await.map_err(crate::Error::from)?;
-        let value = std::str::from_utf8(&secret_value).unwrap();
         let query_result = sqlx::query_scalar!(
             r#"
-            INSERT INTO secrets(name, value)
-            VALUES ($1, pgp_sym_encrypt($2, $3, 'cipher-algo=aes256'))
+            INSERT INTO secrets(id, name, key_id, data_key, value)
+            VALUES ($1, $2, $3, $4, $5)
             RETURNING id
             "#,
+            id,
             secret_name,
-            value,
-            DUMMY,
+            sealed.key_id,
+            sealed.data_key,
+            sealed.value,
         )
         .fetch_one(&mut *txn)
         .await;
@@ -102,6 +158,141 @@ impl SecretManager for GraphStore {
         .execute(&mut *txn)
         .await
         .map_err(crate::Error::from)?;
+        txn.commit().await.map_err(crate::Error::from)?;
         Ok(())
     }
+
+    async fn list_secret_versions(&self, secret_name: &str) -> Result<Vec<SecretVersion>> {
+        let mut conn = self.pool.acquire().await.map_err(crate::Error::from)?;
+        let versions = sqlx::query_as!(
+            SecretVersion,
+            r#"
+            SELECT id AS version, created_at FROM secrets
+            WHERE name = $1
+            ORDER BY id DESC
+            "#,
+            secret_name,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(crate::Error::from)?;
+        if versions.is_empty() {
+            return Err(crate::Error::entity_not_found(secret_name).into());
+        }
+        Ok(versions)
+    }
+
+    async fn delete_secret_version(&self, secret_name: &str, version: Uuid) -> Result<()> {
+        let mut txn = self.pool.begin().await.map_err(crate::Error::from)?;
+        let current = sqlx::query_scalar!(
+            r#"
+            SELECT id FROM secrets
+            WHERE name = $1
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+            secret_name,
+        )
+        .fetch_optional(&mut *txn)
+        .await
+        .map_err(crate::Error::from)?;
+        if current == Some(version) {
+            return Err(unitycatalog_common::Error::invalid_argument(
+                "the current version of a secret cannot be deleted",
+            ));
+        }
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM secrets
+            WHERE name = $1 AND id = $2
+            "#,
+            secret_name,
+            version,
+        )
+        .execute(&mut *txn)
+        .await
+        .map_err(crate::Error::from)?;
+        if result.rows_affected() == 0 {
+            return Err(crate::Error::entity_not_found(secret_name).into());
+        }
+        txn.commit().await.map_err(crate::Error::from)?;
+        Ok(())
+    }
+}
+
+impl GraphStore {
+    /// Re-encrypt the data keys of all secrets with the current encryption key.
+    ///
+    /// Data keys encrypted with a previous key are re-wrapped, the secret values
+    /// themselves are not touched. Secrets written before envelope encryption was
+    /// introduced stay readable, but are encrypted with a new data key here.
+    /// All previous keys that are still referenced by secrets must be part of
+    /// the configured [`KeyRing`].
+    ///
+    /// # Returns
+    /// The number of re-keyed secret versions.
+    pub async fn rekey_secrets(&self) -> crate::Result<usize> {
+        let keys = self.encryption_keys()?;
+        let mut txn = self.pool.begin().await?;
+
+        let legacy = sqlx::query!(
+            r#"
+            SELECT id, name, pgp_sym_decrypt(value, $1) AS "value!" FROM secrets
+            WHERE key_id IS NULL
+            FOR UPDATE
+            "#,
+            LEGACY_KEY,
+        )
+        .fetch_all(&mut *txn)
+        .await?;
+        let mut count = legacy.len();
+        for secret in legacy {
+            let aad = secret_aad(&secret.name, &secret.id);
+            let sealed = keys.seal(secret.value.as_bytes(), &aad)?;
+            sqlx::query!(
+                r#"
+                UPDATE secrets
+                SET key_id = $2, data_key = $3, value = $4
+                WHERE id = $1
+                "#,
+                secret.id,
+                sealed.key_id,
+                sealed.data_key,
+                sealed.value,
+            )
+            .execute(&mut *txn)
+            .await?;
+        }
+
+        let outdated = sqlx::query!(
+            r#"
+            SELECT id, name, key_id AS "key_id!", data_key AS "data_key!" FROM secrets
+            WHERE key_id <> $1
+            FOR UPDATE
+            "#,
+            keys.current_id(),
+        )
+        .fetch_all(&mut *txn)
+        .await?;
+        count += outdated.len();
+        for secret in outdated {
+            let aad = secret_aad(&secret.name, &secret.id);
+            let data_key = keys.rewrap(&secret.key_id, &secret.data_key, &aad)?;
+            sqlx::query!(
+                r#"
+                UPDATE secrets
+                SET key_id = $2, data_key = $3
+                WHERE id = $1
+                "#,
+                secret.id,
+                keys.current_id(),
+                data_key,
+            )
+            .execute(&mut *txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+        Ok(count)
+    }
 }